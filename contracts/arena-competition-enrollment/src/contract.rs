@@ -124,8 +124,7 @@ pub fn migrate(mut deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response,
                     if let Some(mut enrollment) = x {
                         match &mut enrollment.competition_type {
                             CompetitionType::Tournament {
-                                elimination_type,
-                                distribution: _,
+                                elimination_type, ..
                             } if matches!(
                                 elimination_type,
                                 EliminationType::SingleElimination { .. }
@@ -1,3 +1,5 @@
+use arena_ffa_module::msg::FfaInstantiateExt;
+use arena_hybrid_module::msg::HybridInstantiateExt;
 use arena_interface::{
     competition::msg::EscrowContractInfo,
     core::{CompetitionModuleQuery, CompetitionModuleResponse},
@@ -6,7 +8,9 @@ use arena_interface::{
     group::{self, GroupContractInfo, MemberMsg},
     helpers::is_expired,
 };
+use arena_ladder_module::msg::LadderInstantiateExt;
 use arena_league_module::msg::LeagueInstantiateExt;
+use arena_swiss_module::msg::SwissInstantiateExt;
 use arena_tournament_module::{msg::TournamentInstantiateExt, state::EliminationType};
 use arena_wager_module::msg::WagerInstantiateExt;
 use cosmwasm_std::{
@@ -400,7 +404,11 @@ pub fn finalize(
                         rules: rules.clone(),
                         rulesets: rulesets.clone(),
                         banner: banner.clone(),
-                        instantiate_extension: WagerInstantiateExt {},
+                        instantiate_extension: WagerInstantiateExt {
+                            stakes: None,
+                            sides: None,
+                            refund_grace_period: None,
+                        },
                         group_contract: group_info.clone(),
                     })?
                 }
@@ -409,6 +417,10 @@ pub fn finalize(
                     match_draw_points,
                     match_lose_points,
                     distribution,
+                    round_duration,
+                    legs,
+                    tiebreakers,
+                    playoffs,
                 } => to_json_binary(&arena_league_module::msg::ExecuteMsg::CreateCompetition {
                     host: Some(enrollment.host.to_string()),
                     category_id: enrollment.category_id,
@@ -425,12 +437,17 @@ pub fn finalize(
                         match_draw_points: *match_draw_points,
                         match_lose_points: *match_lose_points,
                         distribution: distribution.clone(),
+                        round_duration: *round_duration,
+                        legs: *legs,
+                        tiebreakers: tiebreakers.clone(),
+                        playoffs: playoffs.clone(),
                     },
                     group_contract: group_info.clone(),
                 })?,
                 CompetitionType::Tournament {
                     elimination_type,
                     distribution,
+                    bracket_reset,
                 } => to_json_binary(
                     &arena_tournament_module::msg::ExecuteMsg::CreateCompetition {
                         host: Some(enrollment.host.to_string()),
@@ -444,12 +461,125 @@ pub fn finalize(
                         rulesets: rulesets.clone(),
                         banner: banner.clone(),
                         instantiate_extension: TournamentInstantiateExt {
-                            elimination_type: *elimination_type,
+                            elimination_type: elimination_type.clone(),
                             distribution: distribution.clone(),
+                            series: None,
+                            carried_distribution: None,
+                            seeding: None,
+                            placements: None,
+                            bracket_reset: *bracket_reset,
                         },
                         group_contract: group_info,
                     },
                 )?,
+                CompetitionType::Swiss {
+                    match_win_points,
+                    match_draw_points,
+                    match_lose_points,
+                    rounds,
+                    distribution,
+                    tiebreakers,
+                } => to_json_binary(&arena_swiss_module::msg::ExecuteMsg::CreateCompetition {
+                    host: Some(enrollment.host.to_string()),
+                    category_id: enrollment.category_id,
+                    escrow: escrow_info.clone(),
+                    name: name.clone(),
+                    description: description.clone(),
+                    date: *date,
+                    duration: *duration,
+                    rules: rules.clone(),
+                    rulesets: rulesets.clone(),
+                    banner: banner.clone(),
+                    instantiate_extension: SwissInstantiateExt {
+                        match_win_points: *match_win_points,
+                        match_draw_points: *match_draw_points,
+                        match_lose_points: *match_lose_points,
+                        rounds: *rounds,
+                        distribution: distribution.clone(),
+                        tiebreakers: tiebreakers.clone(),
+                    },
+                    group_contract: group_info.clone(),
+                })?,
+                CompetitionType::Hybrid {
+                    match_win_points,
+                    match_draw_points,
+                    match_lose_points,
+                    group_count,
+                    advance_per_group,
+                    elimination_type,
+                    distribution,
+                } => to_json_binary(&arena_hybrid_module::msg::ExecuteMsg::CreateCompetition {
+                    host: Some(enrollment.host.to_string()),
+                    category_id: enrollment.category_id,
+                    escrow: escrow_info.clone(),
+                    name: name.clone(),
+                    description: description.clone(),
+                    date: *date,
+                    duration: *duration,
+                    rules: rules.clone(),
+                    rulesets: rulesets.clone(),
+                    banner: banner.clone(),
+                    instantiate_extension: HybridInstantiateExt {
+                        match_win_points: *match_win_points,
+                        match_draw_points: *match_draw_points,
+                        match_lose_points: *match_lose_points,
+                        group_count: *group_count,
+                        advance_per_group: *advance_per_group,
+                        elimination_type: elimination_type.clone(),
+                        distribution: distribution.clone(),
+                    },
+                    group_contract: group_info.clone(),
+                })?,
+                CompetitionType::FreeForAll {
+                    placement_points,
+                    kill_points,
+                    matches,
+                    distribution,
+                } => to_json_binary(&arena_ffa_module::msg::ExecuteMsg::CreateCompetition {
+                    host: Some(enrollment.host.to_string()),
+                    category_id: enrollment.category_id,
+                    escrow: escrow_info.clone(),
+                    name: name.clone(),
+                    description: description.clone(),
+                    date: *date,
+                    duration: *duration,
+                    rules: rules.clone(),
+                    rulesets: rulesets.clone(),
+                    banner: banner.clone(),
+                    instantiate_extension: FfaInstantiateExt {
+                        placement_points: placement_points.clone(),
+                        kill_points: *kill_points,
+                        matches: *matches,
+                        distribution: distribution.clone(),
+                    },
+                    group_contract: group_info.clone(),
+                })?,
+                CompetitionType::Ladder {
+                    challenge_range,
+                    accept_window,
+                    snapshot_interval,
+                    snapshots,
+                    distribution,
+                } => to_json_binary(&arena_ladder_module::msg::ExecuteMsg::CreateCompetition {
+                    host: Some(enrollment.host.to_string()),
+                    category_id: enrollment.category_id,
+                    escrow: escrow_info.clone(),
+                    name: name.clone(),
+                    description: description.clone(),
+                    date: *date,
+                    duration: *duration,
+                    rules: rules.clone(),
+                    rulesets: rulesets.clone(),
+                    banner: banner.clone(),
+                    instantiate_extension: LadderInstantiateExt {
+                        challenge_range: *challenge_range,
+                        accept_window: *accept_window,
+                        snapshot_interval: *snapshot_interval,
+                        snapshots: *snapshots,
+                        distribution: distribution.clone(),
+                    },
+                    group_contract: group_info.clone(),
+                })?,
             }
         }
         CompetitionInfo::Existing { .. } => return Err(ContractError::AlreadyFinalized {}),
@@ -728,6 +858,7 @@ fn get_min_min_members(competition_type: &CompetitionType) -> Uint64 {
         CompetitionType::Tournament {
             elimination_type,
             distribution,
+            ..
         } => match elimination_type {
             EliminationType::SingleElimination {
                 play_third_place_match,
@@ -738,7 +869,35 @@ fn get_min_min_members(competition_type: &CompetitionType) -> Uint64 {
             EliminationType::DoubleElimination => {
                 Uint64::new(std::cmp::max(3, distribution.len()) as u64)
             }
+            // Every group must have more members than it advances
+            EliminationType::RoundRobinGroups {
+                advance_per_group, ..
+            } => Uint64::new(std::cmp::max(
+                advance_per_group.u64() + 1,
+                std::cmp::max(3, distribution.len()) as u64,
+            )),
         },
+        CompetitionType::Swiss {
+            rounds,
+            distribution,
+            ..
+        } => Uint64::new(std::cmp::max(
+            std::cmp::max(distribution.len(), 2) as u64,
+            // The fewest members that can play every round without a rematch
+            rounds.u64() + 1,
+        )),
+        CompetitionType::Hybrid {
+            group_count,
+            advance_per_group,
+            ..
+        } => {
+            // Every group must have more members than it advances
+            *group_count * (*advance_per_group + Uint64::one())
+        }
+        CompetitionType::FreeForAll { distribution, .. }
+        | CompetitionType::Ladder { distribution, .. } => {
+            Uint64::new(std::cmp::max(distribution.len(), 2) as u64)
+        }
     }
 }
 
@@ -1,6 +1,8 @@
 use std::fmt;
 
 use arena_interface::{competition::state::CompetitionResponse, fees::FeeInformation, group};
+use arena_league_module::state::{LeaguePlayoffs, LeagueTiebreaker};
+use arena_swiss_module::state::SwissTiebreaker;
 use arena_tournament_module::state::EliminationType;
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Coin, Decimal, Deps, Empty, StdResult, Timestamp, Uint128, Uint64};
@@ -105,10 +107,50 @@ pub enum CompetitionType {
         match_draw_points: Uint64,
         match_lose_points: Uint64,
         distribution: Vec<Decimal>,
+        #[serde(default)]
+        round_duration: Option<u64>,
+        #[serde(default)]
+        legs: Option<u8>,
+        #[serde(default)]
+        tiebreakers: Option<Vec<LeagueTiebreaker>>,
+        #[serde(default)]
+        playoffs: Option<LeaguePlayoffs>,
     },
     Tournament {
         elimination_type: EliminationType,
         distribution: Vec<Decimal>,
+        #[serde(default)]
+        bracket_reset: Option<bool>,
+    },
+    Swiss {
+        match_win_points: Uint64,
+        match_draw_points: Uint64,
+        match_lose_points: Uint64,
+        rounds: Uint64,
+        distribution: Vec<Decimal>,
+        tiebreakers: Vec<SwissTiebreaker>,
+    },
+    Hybrid {
+        match_win_points: Uint64,
+        match_draw_points: Uint64,
+        match_lose_points: Uint64,
+        group_count: Uint64,
+        advance_per_group: Uint64,
+        elimination_type: EliminationType,
+        distribution: Vec<Decimal>,
+    },
+    FreeForAll {
+        placement_points: Vec<Uint64>,
+        kill_points: Uint64,
+        matches: Uint64,
+        distribution: Vec<Decimal>,
+    },
+    Ladder {
+        challenge_range: Uint64,
+        accept_window: u64,
+        snapshot_interval: u64,
+        snapshots: Uint64,
+        distribution: Vec<Decimal>,
     },
 }
 
@@ -119,6 +161,10 @@ impl fmt::Display for CompetitionType {
             CompetitionType::Wager {} => write!(f, "Wagers"),
             CompetitionType::League { .. } => write!(f, "Leagues"),
             CompetitionType::Tournament { .. } => write!(f, "Tournaments"),
+            CompetitionType::Swiss { .. } => write!(f, "Swiss"),
+            CompetitionType::Hybrid { .. } => write!(f, "Hybrid"),
+            CompetitionType::FreeForAll { .. } => write!(f, "FFA"),
+            CompetitionType::Ladder { .. } => write!(f, "Ladders"),
         }
     }
 }
@@ -254,12 +254,20 @@ pub fn propose(
         },
         msgs: vec![CosmosMsg::Wasm(WasmMsg::Execute {
             contract_addr: info.sender.to_string(),
-            msg: to_json_binary(
-                &arena_interface::competition::msg::ExecuteBase::ProcessCompetition::<Empty, Empty> {
-                    competition_id: msg.competition_id,
-                    distribution: msg.distribution,
-                },
-            )?,
+            msg: match msg.msg {
+                Some(msg) => msg,
+                None => to_json_binary(
+                    &arena_interface::competition::msg::ExecuteBase::ProcessCompetition::<
+                        Empty,
+                        Empty,
+                    > {
+                        competition_id: msg.competition_id,
+                        distribution: msg.distribution,
+                        nft_allocations: None,
+                        vesting: None,
+                    },
+                )?,
+            },
             funds: vec![],
         })],
         proposer: Some(originator.to_string()),
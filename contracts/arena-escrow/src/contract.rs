@@ -1,6 +1,6 @@
 use crate::{
     execute, migrate, query,
-    state::{self, DUE, ENROLLMENT_CONTRACT, INITIAL_DUE, IS_LOCKED},
+    state::{self, CLAIM_DEADLINE, DUE, ENROLLMENT_CONTRACT, INITIAL_DUE, IS_LOCKED},
     ContractError,
 };
 use arena_interface::escrow::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
@@ -22,6 +22,11 @@ pub fn instantiate(
     msg: InstantiateMsg,
 ) -> Result<Response, ContractError> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    if let Some(claim_deadline) = msg.claim_deadline {
+        let claim_deadline = claim_deadline.into_checked(deps.as_ref())?;
+
+        CLAIM_DEADLINE.save(deps.storage, &claim_deadline)?;
+    }
     instantiate_contract(deps, &info, msg.dues, msg.is_enrollment)?;
 
     Ok(Response::default())
@@ -68,10 +73,11 @@ pub fn execute(
 ) -> Result<Response, ContractError> {
     match msg {
         ExecuteMsg::ReceiveNative {} => execute::receive_native(deps, info),
+        ExecuteMsg::ReceiveNativeFor { addr } => execute::receive_native_for(deps, info, addr),
         ExecuteMsg::Withdraw {
             cw20_msg,
             cw721_msg,
-        } => execute::withdraw(deps, info, cw20_msg, cw721_msg),
+        } => execute::withdraw(deps, env, info, cw20_msg, cw721_msg),
         ExecuteMsg::EnrollmentWithdraw { addrs, entry_fee } => {
             execute::enrollment_withdraw(deps, info, addrs, entry_fee)
         }
@@ -81,23 +87,34 @@ pub fn execute(
         ExecuteMsg::ReceiveNft(cw721_receive_msg) => {
             execute::receive_cw721(deps, info, cw721_receive_msg)
         }
+        ExecuteMsg::SponsorNative {} => execute::sponsor_native(deps, info),
+        ExecuteMsg::SponsorWithdraw {
+            cw20_msg,
+            cw721_msg,
+        } => execute::sponsor_withdraw(deps, info, cw20_msg, cw721_msg),
         ExecuteMsg::Distribute {
             distribution,
             layered_fees,
             activation_height,
             group_contract,
+            vesting,
+            nft_allocations,
         } => execute::distribute(
             deps,
+            env,
             info,
             distribution,
             layered_fees,
             activation_height,
             group_contract,
+            vesting,
+            nft_allocations,
         ),
         ExecuteMsg::Lock {
             value,
             transfer_ownership,
         } => execute::lock(deps, info, value, transfer_ownership),
+        ExecuteMsg::SweepUnclaimed {} => execute::sweep_unclaimed(deps, env),
         ExecuteMsg::UpdateOwnership(action) => {
             let ownership = cw_ownable::update_ownership(deps, &env.block, &info.sender, action)?;
             Ok(Response::new().add_attributes(ownership.into_attributes()))
@@ -106,7 +123,7 @@ pub fn execute(
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Balance { addr } => to_json_binary(&query::balance(deps, addr)?),
         QueryMsg::Due { addr } => to_json_binary(&query::due(deps, addr)?),
@@ -125,6 +142,16 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         }
         QueryMsg::Ownership {} => to_json_binary(&cw_ownable::get_ownership(deps.storage)?),
         QueryMsg::DumpState { addr } => to_json_binary(&query::dump_state(deps, addr)?),
+        QueryMsg::Claimable { addr } => to_json_binary(&query::claimable(deps, env, addr)?),
+        QueryMsg::Sponsorship { addr } => to_json_binary(&query::sponsorship(deps, addr)?),
+        QueryMsg::Sponsorships { start_after, limit } => {
+            to_json_binary(&query::sponsorships(deps, start_after, limit)?)
+        }
+        QueryMsg::TotalSponsorship {} => to_json_binary(&query::total_sponsorship(deps)?),
+        QueryMsg::ClaimExpiration {} => to_json_binary(&query::claim_expiration(deps)?),
+        QueryMsg::Swept { start_after, limit } => {
+            to_json_binary(&query::swept(deps, start_after, limit)?)
+        }
     }
 }
 
@@ -46,4 +46,13 @@ pub enum ContractError {
 
     #[error("Cannot withdraw directly from enrollment contract")]
     EnrollmentWithdraw {},
+
+    #[error("The claim deadline has passed")]
+    ClaimDeadlinePassed {},
+
+    #[error("The claim deadline has not passed")]
+    ClaimDeadlineNotPassed {},
+
+    #[error("There is no claim deadline")]
+    NoClaimDeadline {},
 }
@@ -1,26 +1,28 @@
-use std::iter;
+use std::collections::BTreeMap;
 
 use arena_interface::{
-    escrow::TransferEscrowOwnershipMsg,
+    escrow::{ReceiveMsg, TransferEscrowOwnershipMsg, Vesting},
     fees::FeeInformation,
     group::{self, MemberMsg},
 };
 use cosmwasm_std::{
-    ensure, to_json_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, Decimal, DepsMut, Empty,
-    MessageInfo, Response, StdResult, Uint128,
+    ensure, from_json, to_json_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, Decimal, DepsMut,
+    Empty, Env, MessageInfo, Order, Response, StdResult, Storage, Timestamp, Uint128,
 };
 use cw20::{Cw20CoinVerified, Cw20ReceiveMsg};
 use cw721::Cw721ReceiveMsg;
 use cw_balance::{
     BalanceError, BalanceVerified, Cw721CollectionVerified, Distribution, MemberPercentage,
+    NftAllocation,
 };
-use cw_ownable::{assert_owner, get_ownership};
+use cw_ownable::{assert_owner, get_ownership, OwnershipError};
 
 use crate::{
     query::is_locked,
     state::{
-        is_fully_funded, BALANCE, DUE, ENROLLMENT_CONTRACT, HAS_DISTRIBUTED, INITIAL_DUE,
-        IS_LOCKED, TOTAL_BALANCE,
+        is_fully_funded, VestingPosition, BALANCE, CLAIM_DEADLINE, CLAIM_EXPIRATION, DUE,
+        ENROLLMENT_CONTRACT, HAS_DISTRIBUTED, INITIAL_DUE, IS_LOCKED, SPONSORSHIP, SWEPT,
+        TOTAL_BALANCE, TOTAL_SPONSORSHIP, VESTING,
     },
     ContractError,
 };
@@ -93,6 +95,7 @@ pub fn enrollment_withdraw(
 
 pub fn withdraw(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     cw20_msg: Option<Binary>,
     cw721_msg: Option<Binary>,
@@ -100,10 +103,21 @@ pub fn withdraw(
     if is_locked(deps.as_ref()) {
         return Err(ContractError::Locked {});
     }
+    if let Some(claim_expiration) = CLAIM_EXPIRATION.may_load(deps.storage)? {
+        if env.block.time >= claim_expiration {
+            return Err(ContractError::ClaimDeadlinePassed {});
+        }
+    }
 
     let mut msgs = vec![];
-    // Load entire user balance
-    let balance = BALANCE.load(deps.storage, &info.sender)?;
+    // Load entire user balance along with any vested prizes
+    let balance = match release_vested(deps.storage, &info.sender, env.block.time)? {
+        Some(vested) => BALANCE
+            .may_load(deps.storage, &info.sender)?
+            .unwrap_or_default()
+            .checked_add(&vested)?,
+        None => BALANCE.load(deps.storage, &info.sender)?,
+    };
 
     // Load the total balance
     let mut total_balance = TOTAL_BALANCE.may_load(deps.storage)?.unwrap_or_default();
@@ -145,6 +159,61 @@ pub fn withdraw(
         .add_messages(msgs))
 }
 
+/// Marks the vested part of a member's vesting position as claimed, and returns it if the member has a position
+fn release_vested(
+    storage: &mut dyn Storage,
+    addr: &Addr,
+    time: Timestamp,
+) -> Result<Option<BalanceVerified>, ContractError> {
+    let Some(mut position) = VESTING.may_load(storage, addr)? else {
+        return Ok(None);
+    };
+
+    let released = position.releasable(time)?;
+    position.claimed = position.claimed.checked_add(&released)?;
+
+    if position.total.checked_sub(&position.claimed)?.is_empty() {
+        VESTING.remove(storage, addr);
+    } else {
+        VESTING.save(storage, addr, &position)?;
+    }
+
+    Ok(Some(released))
+}
+
+/// Adds a distributed balance to a member, which is held in a vesting position if it follows a schedule
+fn credit_distribution(
+    storage: &mut dyn Storage,
+    env: &Env,
+    vesting: Option<&Vesting<Addr>>,
+    member: &Addr,
+    recipient: &Addr,
+    balance: &BalanceVerified,
+) -> Result<(), ContractError> {
+    match vesting.and_then(|x| x.schedule(member)) {
+        Some(schedule) => {
+            VESTING.update(storage, recipient, |position| -> StdResult<_> {
+                let mut position = position.unwrap_or(VestingPosition {
+                    total: BalanceVerified::default(),
+                    claimed: BalanceVerified::default(),
+                    start: env.block.time,
+                    schedule: schedule.clone(),
+                });
+                position.total = position.total.checked_add(balance)?;
+
+                Ok(position)
+            })?;
+        }
+        None => {
+            BALANCE.update(storage, recipient, |old_balance| -> StdResult<_> {
+                old_balance.unwrap_or_default().checked_add(balance)
+            })?;
+        }
+    }
+
+    Ok(())
+}
+
 // This function receives native tokens and updates the balance
 pub fn receive_native(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
     let balance = BalanceVerified {
@@ -156,6 +225,24 @@ pub fn receive_native(deps: DepsMut, info: MessageInfo) -> Result<Response, Cont
     receive_balance(deps, info.sender, balance)
 }
 
+// This function receives native tokens from the owner on behalf of a member
+pub fn receive_native_for(
+    deps: DepsMut,
+    info: MessageInfo,
+    addr: String,
+) -> Result<Response, ContractError> {
+    assert_owner(deps.storage, &info.sender)?;
+
+    let addr = deps.api.addr_validate(&addr)?;
+    let balance = BalanceVerified {
+        native: Some(info.funds),
+        cw20: None,
+        cw721: None,
+    };
+
+    receive_balance(deps, addr, balance)
+}
+
 // This function receives CW20 tokens and updates the balance
 pub fn receive_cw20(
     deps: DepsMut,
@@ -174,7 +261,11 @@ pub fn receive_cw20(
         cw721: None,
     };
 
-    receive_balance(deps, sender_addr, balance)
+    if is_sponsorship(&cw20_receive_msg.msg) {
+        receive_sponsorship(deps, sender_addr, balance)
+    } else {
+        receive_balance(deps, sender_addr, balance)
+    }
 }
 
 // This function receives CW721 tokens and updates the balance
@@ -195,7 +286,89 @@ pub fn receive_cw721(
         cw721: Some(cw721_balance),
     };
 
-    receive_balance(deps, sender_addr, balance)
+    if is_sponsorship(&cw721_receive_msg.msg) {
+        receive_sponsorship(deps, sender_addr, balance)
+    } else {
+        receive_balance(deps, sender_addr, balance)
+    }
+}
+
+fn is_sponsorship(msg: &Binary) -> bool {
+    matches!(from_json::<ReceiveMsg>(msg), Ok(ReceiveMsg::Sponsor {}))
+}
+
+// This function receives native tokens as a sponsorship
+pub fn sponsor_native(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    let balance = BalanceVerified {
+        native: Some(info.funds),
+        cw20: None,
+        cw721: None,
+    };
+
+    receive_sponsorship(deps, info.sender, balance)
+}
+
+/// Sponsorships do not count towards dues, and are only added to the total balance on distribution
+fn receive_sponsorship(
+    deps: DepsMut,
+    addr: Addr,
+    balance: BalanceVerified,
+) -> Result<Response, ContractError> {
+    if balance.is_empty() {
+        return Err(ContractError::EmptyBalance {});
+    }
+    if HAS_DISTRIBUTED.exists(deps.storage) {
+        return Err(ContractError::AlreadyDistributed {});
+    }
+
+    let updated_sponsorship =
+        SPONSORSHIP.update(deps.storage, &addr, |existing_balance| -> StdResult<_> {
+            existing_balance.unwrap_or_default().checked_add(&balance)
+        })?;
+
+    // Update the total sponsorship in storage
+    if TOTAL_SPONSORSHIP.exists(deps.storage) {
+        TOTAL_SPONSORSHIP.update(deps.storage, |total| total.checked_add(&balance))?;
+    } else {
+        TOTAL_SPONSORSHIP.save(deps.storage, &balance)?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "receive_sponsorship")
+        .add_attribute("sponsorship", updated_sponsorship.to_string()))
+}
+
+pub fn sponsor_withdraw(
+    deps: DepsMut,
+    info: MessageInfo,
+    cw20_msg: Option<Binary>,
+    cw721_msg: Option<Binary>,
+) -> Result<Response, ContractError> {
+    if is_locked(deps.as_ref()) {
+        return Err(ContractError::Locked {});
+    }
+    if HAS_DISTRIBUTED.exists(deps.storage) {
+        return Err(ContractError::AlreadyDistributed {});
+    }
+
+    let sponsorship = SPONSORSHIP.load(deps.storage, &info.sender)?;
+    SPONSORSHIP.remove(deps.storage, &info.sender);
+
+    let total_sponsorship = TOTAL_SPONSORSHIP
+        .load(deps.storage)?
+        .checked_sub(&sponsorship)?;
+    if total_sponsorship.is_empty() {
+        TOTAL_SPONSORSHIP.remove(deps.storage);
+    } else {
+        TOTAL_SPONSORSHIP.save(deps.storage, &total_sponsorship)?;
+    }
+
+    let msgs = sponsorship.transmit_all(deps.as_ref(), &info.sender, cw20_msg, cw721_msg)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "sponsor_withdraw")
+        .add_attribute("addr", info.sender)
+        .add_messages(msgs))
 }
 
 fn receive_balance(
@@ -260,27 +433,56 @@ fn receive_balance(
         .add_messages(msgs))
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn distribute(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     distribution: Option<Distribution<String>>,
     layered_fees: Option<Vec<FeeInformation<String>>>,
     activation_height: Option<u64>,
     group_contract: String,
+    vesting: Option<Vesting<String>>,
+    nft_allocations: Option<Vec<NftAllocation<String>>>,
 ) -> Result<Response, ContractError> {
     // Ensure the sender is the owner
     assert_owner(deps.storage, &info.sender)?;
 
     // Validate the group contract
     let group_contract = deps.api.addr_validate(&group_contract)?;
+    let vesting = vesting.map(|x| x.into_checked(deps.as_ref())).transpose()?;
+    let nft_allocations = nft_allocations
+        .unwrap_or_default()
+        .iter()
+        .map(|x| x.into_checked(deps.as_ref()))
+        .collect::<StdResult<Vec<_>>>()?;
 
     // Load the total balance available for distribution
     let mut total_balance = TOTAL_BALANCE.may_load(deps.storage)?.unwrap_or_default();
 
+    // Sponsorships join the prize pool
+    if let Some(total_sponsorship) = TOTAL_SPONSORSHIP.may_load(deps.storage)? {
+        total_balance = total_balance.checked_add(&total_sponsorship)?;
+        TOTAL_BALANCE.save(deps.storage, &total_balance)?;
+
+        TOTAL_SPONSORSHIP.remove(deps.storage);
+        SPONSORSHIP.clear(deps.storage);
+    }
+
     let mut msgs = vec![];
     let mut attrs = vec![];
 
-    if !total_balance.is_empty() {
+    let has_balance = !total_balance.is_empty();
+
+    // Set aside the allocated NFTs, so they are not subject to fees or the distribution
+    let allocated_balance = NftAllocation::<Addr>::total_balance(&nft_allocations)?;
+    total_balance = total_balance.checked_sub(&allocated_balance).map_err(|e| {
+        ContractError::InvalidDistribution {
+            msg: format!("The NFT allocations must be held by the escrow: {}", e),
+        }
+    })?;
+
+    if has_balance {
         // Process layered fees if provided
         if let Some(layered_fees) = layered_fees.as_ref() {
             // Validate the tax info
@@ -294,10 +496,10 @@ pub fn distribute(
                 let fee_amounts = total_balance.checked_mul_floor(fee.tax)?;
 
                 // Update total balance
-                total_balance = TOTAL_BALANCE
-                    .update(deps.storage, |x| -> Result<_, BalanceError> {
-                        x.checked_sub(&fee_amounts)
-                    })?;
+                total_balance = total_balance.checked_sub(&fee_amounts)?;
+                TOTAL_BALANCE.update(deps.storage, |x| -> Result<_, BalanceError> {
+                    x.checked_sub(&fee_amounts)
+                })?;
 
                 // Add messages for fee transmission if amounts are not empty
                 if !fee_amounts.is_empty() {
@@ -332,6 +534,8 @@ pub fn distribute(
                     })
                     .collect(),
                 remainder_addr,
+                fixed_amounts: vec![],
+                remainder_shares: vec![],
             }
         });
 
@@ -342,10 +546,9 @@ pub fn distribute(
             group_contract.to_string(),
             &group::QueryMsg::IsValidDistribution {
                 addrs: distribution
-                    .member_percentages
-                    .iter()
-                    .map(|x| x.addr.to_string())
-                    .chain(iter::once(distribution.remainder_addr.to_string()))
+                    .addrs()
+                    .chain(nft_allocations.iter().map(|x| &x.addr))
+                    .map(|x| x.to_string())
                     .collect(),
             },
         )? {
@@ -359,6 +562,7 @@ pub fn distribute(
 
         // Clear existing balance storage
         BALANCE.clear(deps.storage);
+        VESTING.clear(deps.storage);
 
         // Query payment registry
         let payment_registry: Option<String> =
@@ -395,15 +599,15 @@ pub fn distribute(
                     has_preset_distribution = true;
 
                     // Update balances based on preset distribution
+                    // The preset recipients follow the member's vesting schedule
                     for new_balance in new_balances {
-                        BALANCE.update(
+                        credit_distribution(
                             deps.storage,
+                            &env,
+                            vesting.as_ref(),
+                            &distributed_amount.addr,
                             &new_balance.addr,
-                            |old_balance| -> StdResult<_> {
-                                old_balance
-                                    .unwrap_or_default()
-                                    .checked_add(&new_balance.balance)
-                            },
+                            &new_balance.balance,
                         )?;
                     }
                 }
@@ -411,17 +615,41 @@ pub fn distribute(
 
             if !has_preset_distribution {
                 // Update balance directly if no preset distribution
-                BALANCE.update(
+                credit_distribution(
                     deps.storage,
+                    &env,
+                    vesting.as_ref(),
+                    &distributed_amount.addr,
                     &distributed_amount.addr,
-                    |old_balance| -> StdResult<_> {
-                        old_balance
-                            .unwrap_or_default()
-                            .checked_add(&distributed_amount.balance)
-                    },
+                    &distributed_amount.balance,
                 )?;
             }
         }
+
+        // Assign the allocated NFTs directly to their recipients
+        for nft_allocation in &nft_allocations {
+            credit_distribution(
+                deps.storage,
+                &env,
+                vesting.as_ref(),
+                &nft_allocation.addr,
+                &nft_allocation.addr,
+                &nft_allocation.balance(),
+            )?;
+        }
+    }
+
+    // Start the claim deadline, which only counts down once every vested balance is released
+    if let Some(claim_deadline) = CLAIM_DEADLINE.may_load(deps.storage)? {
+        let vesting_duration = vesting.as_ref().map(|x| x.duration()).unwrap_or_default();
+
+        CLAIM_EXPIRATION.save(
+            deps.storage,
+            &env.block
+                .time
+                .plus_seconds(vesting_duration)
+                .plus_seconds(claim_deadline.duration),
+        )?;
     }
 
     // Update contract state
@@ -459,3 +687,79 @@ pub fn lock(
 
     Ok(res)
 }
+
+pub fn sweep_unclaimed(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+    let claim_expiration = CLAIM_EXPIRATION
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoClaimDeadline {})?;
+    ensure!(
+        env.block.time >= claim_expiration,
+        ContractError::ClaimDeadlineNotPassed {}
+    );
+
+    // Collect the remaining balances, including anything left in vesting positions
+    let mut unclaimed = BALANCE
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<BTreeMap<_, _>>>()?;
+    for (addr, position) in VESTING
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?
+    {
+        let remaining = position.total.checked_sub(&position.claimed)?;
+        let balance = unclaimed.entry(addr).or_default();
+        *balance = balance.checked_add(&remaining)?;
+    }
+    BALANCE.clear(deps.storage);
+    VESTING.clear(deps.storage);
+
+    // Record the swept balances
+    let mut total_swept = BalanceVerified::new();
+    for (addr, balance) in unclaimed {
+        if balance.is_empty() {
+            continue;
+        }
+
+        SWEPT.update(deps.storage, &addr, |swept| -> StdResult<_> {
+            swept.unwrap_or_default().checked_add(&balance)
+        })?;
+        total_swept = total_swept.checked_add(&balance)?;
+    }
+
+    let total_balance = TOTAL_BALANCE
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        .checked_sub(&total_swept)?;
+    if total_balance.is_empty() {
+        TOTAL_BALANCE.remove(deps.storage);
+    } else {
+        TOTAL_BALANCE.save(deps.storage, &total_balance)?;
+    }
+
+    // Default to the arena DAO of the owner competition module
+    let receiver = match CLAIM_DEADLINE.load(deps.storage)?.receiver {
+        Some(receiver) => receiver,
+        None => {
+            let owner = get_ownership(deps.storage)?
+                .owner
+                .ok_or(ContractError::OwnershipError(OwnershipError::NoOwner))?;
+            let dao: String = deps.querier.query_wasm_smart(
+                owner.to_string(),
+                &arena_interface::competition::msg::QueryBase::DAO::<Empty, Empty, Empty> {},
+            )?;
+
+            deps.api.addr_validate(&dao)?
+        }
+    };
+
+    let msgs = if total_swept.is_empty() {
+        vec![]
+    } else {
+        total_swept.transmit_all(deps.as_ref(), &receiver, None, None)?
+    };
+
+    Ok(Response::new()
+        .add_attribute("action", "sweep_unclaimed")
+        .add_attribute("receiver", receiver)
+        .add_attribute("swept", total_swept.to_string())
+        .add_messages(msgs))
+}
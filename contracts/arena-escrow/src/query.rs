@@ -1,10 +1,13 @@
-use arena_interface::escrow::DumpStateResponse;
-use cosmwasm_std::{Deps, StdResult};
+use arena_interface::escrow::{ClaimableResponse, DumpStateResponse};
+use cosmwasm_std::{Deps, Env, StdError, StdResult, Timestamp};
 use cw_balance::{BalanceVerified, MemberBalanceChecked};
 use cw_storage_plus::Bound;
 use cw_utils::maybe_addr;
 
-use crate::state::{BALANCE, DUE, INITIAL_DUE, IS_LOCKED, TOTAL_BALANCE};
+use crate::state::{
+    BALANCE, CLAIM_EXPIRATION, DUE, INITIAL_DUE, IS_LOCKED, SPONSORSHIP, SWEPT, TOTAL_BALANCE,
+    TOTAL_SPONSORSHIP, VESTING,
+};
 
 pub fn balance(deps: Deps, addr: String) -> StdResult<Option<BalanceVerified>> {
     let addr = deps.api.addr_validate(&addr)?;
@@ -76,6 +79,49 @@ pub fn initial_dues(
     })
 }
 
+pub fn sponsorship(deps: Deps, addr: String) -> StdResult<Option<BalanceVerified>> {
+    let addr = deps.api.addr_validate(&addr)?;
+    SPONSORSHIP.may_load(deps.storage, &addr)
+}
+
+pub fn sponsorships(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<MemberBalanceChecked>> {
+    let binding = maybe_addr(deps.api, start_after)?;
+    let start = binding.as_ref().map(Bound::exclusive);
+    cw_paginate::paginate_map(&SPONSORSHIP, deps.storage, start, limit, |k, v| {
+        Ok(MemberBalanceChecked {
+            addr: k,
+            balance: v,
+        })
+    })
+}
+
+pub fn total_sponsorship(deps: Deps) -> StdResult<Option<BalanceVerified>> {
+    TOTAL_SPONSORSHIP.may_load(deps.storage)
+}
+
+pub fn claim_expiration(deps: Deps) -> StdResult<Option<Timestamp>> {
+    CLAIM_EXPIRATION.may_load(deps.storage)
+}
+
+pub fn swept(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<MemberBalanceChecked>> {
+    let binding = maybe_addr(deps.api, start_after)?;
+    let start = binding.as_ref().map(Bound::exclusive);
+    cw_paginate::paginate_map(&SWEPT, deps.storage, start, limit, |k, v| {
+        Ok(MemberBalanceChecked {
+            addr: k,
+            balance: v,
+        })
+    })
+}
+
 pub fn dump_state(deps: Deps, addr: Option<String>) -> StdResult<DumpStateResponse> {
     let maybe_addr = maybe_addr(deps.api, addr)?;
     let balance = maybe_addr
@@ -95,3 +141,27 @@ pub fn dump_state(deps: Deps, addr: Option<String>) -> StdResult<DumpStateRespon
         balance,
     })
 }
+
+pub fn claimable(deps: Deps, env: Env, addr: String) -> StdResult<ClaimableResponse> {
+    let addr = deps.api.addr_validate(&addr)?;
+    let balance = BALANCE.may_load(deps.storage, &addr)?.unwrap_or_default();
+
+    match VESTING.may_load(deps.storage, &addr)? {
+        Some(position) => {
+            let to_std_err = |e: cw_balance::BalanceError| StdError::generic_err(e.to_string());
+            let vested = position.vested(env.block.time).map_err(to_std_err)?;
+
+            Ok(ClaimableResponse {
+                claimable: balance
+                    .checked_add(&position.releasable(env.block.time).map_err(to_std_err)?)?,
+                unvested: position.total.checked_sub(&vested).map_err(to_std_err)?,
+                vested,
+            })
+        }
+        None => Ok(ClaimableResponse {
+            claimable: balance,
+            vested: Default::default(),
+            unvested: Default::default(),
+        }),
+    }
+}
@@ -1,7 +1,31 @@
-use cosmwasm_std::{Addr, Deps};
-use cw_balance::BalanceVerified;
+use arena_interface::escrow::{ClaimDeadline, VestingSchedule};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Deps, Timestamp};
+use cw_balance::{BalanceError, BalanceVerified};
 use cw_storage_plus::{Item, Map};
 
+/// A member's distributed balance that is released over time
+#[cw_serde]
+pub struct VestingPosition {
+    pub total: BalanceVerified,
+    pub claimed: BalanceVerified,
+    pub start: Timestamp,
+    pub schedule: VestingSchedule,
+}
+
+impl VestingPosition {
+    /// The part of the total released by `time`, including what has been claimed
+    pub fn vested(&self, time: Timestamp) -> Result<BalanceVerified, BalanceError> {
+        self.total
+            .checked_mul_floor(self.schedule.vested_ratio(self.start, time))
+    }
+
+    /// The released part that has not been claimed yet
+    pub fn releasable(&self, time: Timestamp) -> Result<BalanceVerified, BalanceError> {
+        self.vested(time)?.checked_sub(&self.claimed)
+    }
+}
+
 pub const ENROLLMENT_CONTRACT: Item<Addr> = Item::new("enrollment_contract");
 pub const TOTAL_BALANCE: Item<BalanceVerified> = Item::new("total");
 pub const BALANCE: Map<&Addr, BalanceVerified> = Map::new("balance");
@@ -9,6 +33,14 @@ pub const INITIAL_DUE: Map<&Addr, BalanceVerified> = Map::new("initial_due");
 pub const DUE: Map<&Addr, BalanceVerified> = Map::new("due");
 pub const IS_LOCKED: Item<bool> = Item::new("is_locked");
 pub const HAS_DISTRIBUTED: Item<bool> = Item::new("has_distributed");
+pub const VESTING: Map<&Addr, VestingPosition> = Map::new("vesting");
+/// Sponsor contributions are held outside of the total balance until the distribution
+pub const SPONSORSHIP: Map<&Addr, BalanceVerified> = Map::new("sponsorship");
+pub const TOTAL_SPONSORSHIP: Item<BalanceVerified> = Item::new("total_sponsorship");
+pub const CLAIM_DEADLINE: Item<ClaimDeadline<Addr>> = Item::new("claim_deadline");
+pub const CLAIM_EXPIRATION: Item<Timestamp> = Item::new("claim_expiration");
+/// The unclaimed balances that were swept from each member
+pub const SWEPT: Map<&Addr, BalanceVerified> = Map::new("swept");
 
 pub fn is_fully_funded(deps: Deps) -> bool {
     DUE.is_empty(deps.storage)
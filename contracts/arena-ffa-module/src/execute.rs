@@ -0,0 +1,240 @@
+use std::collections::HashSet;
+
+use arena_interface::{group, ratings::MemberResult};
+use cosmwasm_std::{
+    Addr, Decimal, DepsMut, MessageInfo, Response, StdError, SubMsg, Uint128, Uint64,
+};
+use cw_balance::{Distribution, MemberPercentage};
+
+use crate::{
+    contract::CompetitionModule,
+    msg::{Ffa, FfaStanding},
+    query,
+    state::{FfaMatch, MatchStat, MATCHES},
+    ContractError,
+};
+
+/// Processes the placement order of a single match.
+///
+/// # Details
+/// - The placements must contain every member of the competition exactly once.
+/// - Rating updates decompose the placements into pairwise results, where each member beats every member placed below it.
+/// - Once every match has a result, the prize pool is distributed from the final standings.
+pub fn process_match(
+    mut deps: DepsMut,
+    info: MessageInfo,
+    ffa_id: Uint128,
+    match_number: Uint64,
+    placements: Vec<String>,
+    stats: Option<Vec<MatchStat<String>>>,
+) -> Result<Response, ContractError> {
+    let competition_module = CompetitionModule::default();
+    let ffa = competition_module
+        .competitions
+        .load(deps.storage, ffa_id.u128())?;
+    competition_module.inner_validate_auth(&info.sender, &ffa, false)?;
+
+    if match_number.is_zero() || match_number > ffa.extension.matches {
+        return Err(ContractError::StdError(StdError::generic_err(format!(
+            "Match number {} not found",
+            match_number
+        ))));
+    }
+    if placements.len() as u64 != ffa.extension.teams.u64() {
+        return Err(ContractError::StdError(StdError::generic_err(
+            "The placements must contain every member",
+        )));
+    }
+
+    let placements = placements
+        .iter()
+        .map(|x| deps.api.addr_validate(x))
+        .collect::<Result<Vec<_>, _>>()?;
+    let unique: HashSet<&Addr> = placements.iter().collect();
+    if unique.len() != placements.len() {
+        return Err(ContractError::StdError(StdError::generic_err(
+            "The placements cannot contain duplicates",
+        )));
+    }
+    if !deps.querier.query_wasm_smart::<bool>(
+        ffa.group_contract.to_string(),
+        &group::QueryMsg::IsValidDistribution {
+            addrs: placements.iter().map(|x| x.to_string()).collect(),
+        },
+    )? {
+        return Err(ContractError::StdError(StdError::generic_err(
+            "The placements contain a non-member",
+        )));
+    }
+
+    let stats = stats
+        .unwrap_or_default()
+        .into_iter()
+        .map(|x| {
+            let addr = deps.api.addr_validate(&x.addr)?;
+            if !unique.contains(&addr) {
+                return Err(ContractError::StdError(StdError::generic_err(format!(
+                    "Stats provided for non-member {}",
+                    addr
+                ))));
+            }
+
+            Ok(MatchStat {
+                addr,
+                kills: x.kills,
+                bonus_points: x.bonus_points,
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let key = (ffa_id.u128(), match_number.u64());
+    let is_new = !MATCHES.has(deps.storage, key);
+    MATCHES.save(
+        deps.storage,
+        key,
+        &FfaMatch {
+            match_number,
+            placements: placements.clone(),
+            stats,
+        },
+    )?;
+
+    let mut response = Response::new();
+
+    // Rating updates are only handled the first time
+    if is_new {
+        let mut updated_ffa = ffa.clone();
+        updated_ffa.extension.processed_matches += Uint64::one();
+        competition_module.competitions.replace(
+            deps.storage,
+            ffa_id.u128(),
+            Some(&updated_ffa),
+            Some(&ffa),
+        )?;
+
+        let sub_msgs = rating_adjustment(deps.branch(), &ffa, &placements)?;
+
+        if updated_ffa.extension.processed_matches >= updated_ffa.extension.matches {
+            response = trigger_distribution(deps, &updated_ffa)?;
+        }
+        response = response.add_submessages(sub_msgs);
+    }
+
+    Ok(response
+        .add_attribute("action", "process_match")
+        .add_attribute("ffa_id", ffa_id.to_string())
+        .add_attribute("match_number", match_number.to_string()))
+}
+
+/// Distributes the prize pool from the final standings.
+///
+/// Members tied on points, wins and kills share their placements, and unused placements are redistributed evenly.
+fn trigger_distribution(deps: DepsMut, ffa: &Ffa) -> Result<Response, ContractError> {
+    let standings = query::standings(deps.as_ref(), ffa.id)?;
+
+    let is_tied = |a: &FfaStanding, b: &FfaStanding| {
+        a.points == b.points && a.wins == b.wins && a.kills == b.kills
+    };
+
+    let placements = ffa.extension.distribution.len();
+    let mut placement_members: Vec<Vec<Addr>> = vec![];
+
+    // Group members into placements based on their standings
+    for (i, standing) in standings.iter().enumerate() {
+        if i != 0 && is_tied(&standings[i - 1], standing) {
+            placement_members
+                .last_mut()
+                .unwrap()
+                .push(standing.member.clone());
+        } else {
+            if placement_members.len() >= placements {
+                break;
+            }
+            placement_members.push(vec![standing.member.clone()]);
+        }
+    }
+
+    // Calculate the final distribution
+    let summed_extras: Decimal = ffa.extension.distribution[placement_members.len()..placements]
+        .iter()
+        .sum();
+    let redistributed_percentage_share = summed_extras.checked_div(Decimal::from_ratio(
+        placement_members.len() as u128,
+        Uint128::one(),
+    ))?;
+
+    let mut member_percentages = vec![];
+    let mut remainder_percentage = Decimal::one();
+    for (i, members) in placement_members.iter().enumerate() {
+        let placement_percentage = ffa.extension.distribution[i]
+            .checked_add(redistributed_percentage_share)?
+            .checked_div(Decimal::from_ratio(members.len() as u128, Uint128::one()))?;
+        for member in members {
+            remainder_percentage = remainder_percentage.checked_sub(placement_percentage)?;
+            member_percentages.push(MemberPercentage::<Addr> {
+                addr: member.clone(),
+                percentage: placement_percentage,
+            });
+        }
+    }
+
+    if remainder_percentage > Decimal::zero() {
+        member_percentages[0].percentage = member_percentages[0]
+            .percentage
+            .checked_add(remainder_percentage)?;
+    }
+
+    Ok(CompetitionModule::default().inner_process(
+        deps,
+        ffa,
+        Some(Distribution::<Addr> {
+            member_percentages,
+            remainder_addr: standings[0].member.clone(),
+            fixed_amounts: vec![],
+            remainder_shares: vec![],
+        }),
+        None,
+        None,
+    )?)
+}
+
+/// Decomposes the placements into pairwise results for a rating adjustment.
+fn rating_adjustment(
+    deps: DepsMut,
+    ffa: &Ffa,
+    placements: &[Addr],
+) -> Result<Vec<SubMsg>, ContractError> {
+    let mut sub_msgs = vec![];
+
+    if let Some(category_id) = ffa.category_id {
+        if CompetitionModule::default().query_is_dao_member(
+            deps.as_ref(),
+            &ffa.host,
+            ffa.start_height,
+        ) {
+            let mut member_results = vec![];
+            for (i, winner) in placements.iter().enumerate() {
+                for loser in &placements[i + 1..] {
+                    member_results.push((
+                        MemberResult {
+                            addr: winner.clone(),
+                            result: Decimal::one(),
+                        },
+                        MemberResult {
+                            addr: loser.clone(),
+                            result: Decimal::zero(),
+                        },
+                    ));
+                }
+            }
+
+            sub_msgs.push(CompetitionModule::default().trigger_rating_adjustment(
+                deps.storage,
+                category_id,
+                member_results,
+            )?);
+        }
+    }
+
+    Ok(sub_msgs)
+}
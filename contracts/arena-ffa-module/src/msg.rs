@@ -0,0 +1,133 @@
+use crate::state::{FfaExt, FfaMatch, MatchStat};
+use arena_interface::{
+    competition::{
+        msg::{ExecuteBase, InstantiateBase, MigrateBase, QueryBase, ToCompetitionExt},
+        state::{Competition, CompetitionResponse},
+    },
+    group,
+};
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, Decimal, Empty, StdError, StdResult, Uint64};
+
+#[cw_serde]
+#[derive(cw_orch::ExecuteFns)]
+pub enum ExecuteExt {
+    /// Submits the full placement order of a match
+    /// A match can be submitted again to correct it, but ratings are only adjusted the first time
+    ProcessMatch {
+        ffa_id: cosmwasm_std::Uint128,
+        match_number: Uint64,
+        placements: Vec<String>,
+        stats: Option<Vec<MatchStat<String>>>,
+    },
+}
+
+impl From<ExecuteExt> for ExecuteMsg {
+    fn from(msg: ExecuteExt) -> Self {
+        ExecuteMsg::Extension { msg }
+    }
+}
+
+#[cw_serde]
+#[derive(QueryResponses, cw_orch::QueryFns)]
+pub enum FfaQueryExt {
+    #[returns(Vec<FfaStanding>)]
+    Standings { ffa_id: cosmwasm_std::Uint128 },
+    #[returns(FfaMatch)]
+    FfaMatch {
+        ffa_id: cosmwasm_std::Uint128,
+        match_number: Uint64,
+    },
+}
+
+impl From<FfaQueryExt> for QueryMsg {
+    fn from(msg: FfaQueryExt) -> Self {
+        QueryMsg::QueryExtension { msg }
+    }
+}
+
+#[cw_serde]
+#[serde(untagged)]
+pub enum MigrateMsg {
+    Base(MigrateBase),
+}
+
+/// This is used to completely generate schema types
+/// QueryExt response types are hidden by the QueryBase mapping to Binary output
+#[cw_serde]
+pub struct SudoMsg {
+    pub ffa_standing: FfaStanding,
+    pub ffa_match: FfaMatch,
+}
+
+#[cw_serde]
+pub struct FfaInstantiateExt {
+    pub placement_points: Vec<Uint64>,
+    pub kill_points: Uint64,
+    pub matches: Uint64,
+    pub distribution: Vec<Decimal>,
+}
+
+impl ToCompetitionExt<FfaExt> for FfaInstantiateExt {
+    fn to_competition_ext(
+        &self,
+        deps: cosmwasm_std::Deps,
+        group_contract: &Addr,
+    ) -> StdResult<FfaExt> {
+        let team_count: Uint64 = deps.querier.query_wasm_smart(
+            group_contract.to_string(),
+            &group::QueryMsg::MembersCount {},
+        )?;
+        if team_count < Uint64::new(2) {
+            return Err(StdError::GenericErr {
+                msg: "At least 2 teams should be provided".to_string(),
+            });
+        }
+        if self.matches.is_zero() {
+            return Err(StdError::generic_err("At least 1 match should be played"));
+        }
+        if self.placement_points.is_empty() {
+            return Err(StdError::generic_err(
+                "The placement points table cannot be empty",
+            ));
+        }
+        if self.placement_points.windows(2).any(|x| x[0] < x[1]) {
+            return Err(StdError::generic_err(
+                "A placement cannot be awarded more points than the placement before it",
+            ));
+        }
+        if Uint64::new(self.distribution.len() as u64) > team_count {
+            return Err(StdError::GenericErr {
+                msg: "Cannot have a distribution size bigger than the teams size".to_string(),
+            });
+        }
+        if self.distribution.iter().sum::<Decimal>() != Decimal::one() {
+            return Err(StdError::generic_err("The distribution must sum up to 1"));
+        }
+
+        Ok(FfaExt {
+            placement_points: self.placement_points.clone(),
+            kill_points: self.kill_points,
+            matches: self.matches,
+            teams: team_count,
+            processed_matches: Uint64::zero(),
+            distribution: self.distribution.clone(),
+        })
+    }
+}
+
+#[cw_serde]
+pub struct FfaStanding {
+    pub member: Addr,
+    pub points: Uint64,
+    /// The number of first place finishes
+    pub wins: Uint64,
+    pub kills: Uint64,
+    pub matches_played: Uint64,
+}
+
+pub type InstantiateMsg = InstantiateBase<Empty>;
+pub type ExecuteMsg = ExecuteBase<ExecuteExt, FfaInstantiateExt>;
+pub type QueryMsg = QueryBase<Empty, FfaQueryExt, FfaExt>;
+pub type Ffa = Competition<FfaExt>;
+pub type FfaResponse = CompetitionResponse<FfaExt>;
@@ -0,0 +1,96 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    contract::CompetitionModule,
+    msg::FfaStanding,
+    state::{FfaMatch, MATCHES},
+};
+use arena_interface::group::{self, MemberMsg};
+use cosmwasm_std::{Addr, Deps, Order, StdResult, Uint128, Uint64};
+
+/// Calculates the standings of a free-for-all competition.
+///
+/// # Details
+/// - Each match awards the placement points, the kill points for every kill, and any bonus points.
+/// - The standings are sorted by points, then by wins, then by kills.
+/// - Remaining ties keep the group's seed order.
+pub fn standings(deps: Deps, ffa_id: Uint128) -> StdResult<Vec<FfaStanding>> {
+    let ffa = CompetitionModule::default()
+        .competitions
+        .load(deps.storage, ffa_id.u128())?;
+    let members: Vec<Addr> = deps
+        .querier
+        .query_wasm_smart::<Vec<MemberMsg<Addr>>>(
+            ffa.group_contract.to_string(),
+            &group::QueryMsg::Members {
+                start_after: None,
+                limit: None,
+            },
+        )?
+        .into_iter()
+        .map(|x| x.addr)
+        .collect();
+
+    let mut records: BTreeMap<Addr, FfaStanding> = members
+        .iter()
+        .map(|member| {
+            (
+                member.clone(),
+                FfaStanding {
+                    member: member.clone(),
+                    points: Uint64::zero(),
+                    wins: Uint64::zero(),
+                    kills: Uint64::zero(),
+                    matches_played: Uint64::zero(),
+                },
+            )
+        })
+        .collect();
+
+    for m in MATCHES
+        .prefix(ffa_id.u128())
+        .range(deps.storage, None, None, Order::Ascending)
+    {
+        let (_, m) = m?;
+
+        for (i, member) in m.placements.iter().enumerate() {
+            if let Some(record) = records.get_mut(member) {
+                if let Some(points) = ffa.extension.placement_points.get(i) {
+                    record.points = record.points.checked_add(*points)?;
+                }
+                if i == 0 {
+                    record.wins = record.wins.checked_add(Uint64::one())?;
+                }
+                record.matches_played = record.matches_played.checked_add(Uint64::one())?;
+            }
+        }
+
+        for stat in m.stats {
+            if let Some(record) = records.get_mut(&stat.addr) {
+                record.points = record
+                    .points
+                    .checked_add(stat.kills.checked_mul(ffa.extension.kill_points)?)?
+                    .checked_add(stat.bonus_points)?;
+                record.kills = record.kills.checked_add(stat.kills)?;
+            }
+        }
+    }
+
+    // Seed order is preserved for ties because the sort is stable
+    let mut standings: Vec<FfaStanding> = members
+        .iter()
+        .filter_map(|member| records.remove(member))
+        .collect();
+    standings.sort_by(|a, b| {
+        b.points
+            .cmp(&a.points)
+            .then(b.wins.cmp(&a.wins))
+            .then(b.kills.cmp(&a.kills))
+    });
+
+    Ok(standings)
+}
+
+pub fn ffa_match(deps: Deps, ffa_id: Uint128, match_number: Uint64) -> StdResult<FfaMatch> {
+    MATCHES.load(deps.storage, (ffa_id.u128(), match_number.u64()))
+}
@@ -0,0 +1,34 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Decimal, Uint64};
+use cw_storage_plus::Map;
+
+#[cw_serde]
+pub struct FfaMatch {
+    pub match_number: Uint64,
+    /// Every member of the competition, ordered from first to last place
+    pub placements: Vec<Addr>,
+    pub stats: Vec<MatchStat<Addr>>,
+}
+
+#[cw_serde]
+pub struct MatchStat<T> {
+    pub addr: T,
+    pub kills: Uint64,
+    /// Points awarded on top of the placement and kill points
+    pub bonus_points: Uint64,
+}
+
+#[cw_serde]
+pub struct FfaExt {
+    /// The points awarded for each placement, starting from first place
+    /// Placements past the end of the table are awarded no points
+    pub placement_points: Vec<Uint64>,
+    pub kill_points: Uint64,
+    pub matches: Uint64,
+    pub teams: Uint64,
+    pub processed_matches: Uint64,
+    pub distribution: Vec<Decimal>,
+}
+
+/// (FFA Id, Match Number)
+pub const MATCHES: Map<(u128, u64), FfaMatch> = Map::new("ffa_matches");
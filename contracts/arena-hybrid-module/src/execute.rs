@@ -0,0 +1,340 @@
+use arena_interface::{
+    group::{self, MemberMsg},
+    ratings::MemberResult,
+};
+use arena_league_module::state::MatchResult;
+use arena_tournament_module::execute::{
+    advance_matches, bracket_placements, draw_group_schedules, generate_bracket,
+};
+use cosmwasm_std::{
+    ensure_eq, Addr, Decimal, Deps, DepsMut, Env, MessageInfo, Response, StdError, SubMsg, Uint128,
+    Uint64,
+};
+use cw_balance::{Distribution, MemberPercentage};
+
+use crate::{
+    contract::CompetitionModule,
+    msg::{GroupMatchResultMsg, Hybrid, PlayoffMatchResultMsg},
+    query,
+    state::{GroupMatch, HybridStage, GROUPS, GROUP_MATCHES},
+    ContractError,
+};
+
+/// Draws the members into groups and generates each group's round-robin schedule.
+///
+/// Members are drawn in seed order using the tournament module's snake draw, so each group receives a similar spread of seeds.
+pub fn instantiate_groups(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    ensure_eq!(
+        info.sender,
+        env.contract.address,
+        ContractError::Unauthorized {}
+    );
+
+    let competition_module = CompetitionModule::default();
+    let hybrid_id = competition_module.competition_count.load(deps.storage)?;
+    let hybrid = competition_module
+        .competitions
+        .load(deps.storage, hybrid_id.u128())?;
+
+    // Convert teams to addresses
+    let teams: Vec<Addr> = deps
+        .querier
+        .query_wasm_smart::<Vec<MemberMsg<Addr>>>(
+            hybrid.group_contract.to_string(),
+            &group::QueryMsg::Members {
+                start_after: None,
+                limit: None,
+            },
+        )?
+        .into_iter()
+        .map(|x| x.addr)
+        .collect();
+
+    let group_count = hybrid.extension.group_count.u64() as usize;
+    let mut group_matches = 0u128;
+    for (i, (members, pairings)) in draw_group_schedules(teams, group_count)
+        .into_iter()
+        .enumerate()
+    {
+        let group = i as u64 + 1;
+
+        group_matches += pairings.len() as u128;
+        for (match_number, (round_number, team_1, team_2)) in (1u128..).zip(pairings) {
+            GROUP_MATCHES.save(
+                deps.storage,
+                (hybrid_id.u128(), group, match_number),
+                &GroupMatch {
+                    match_number: Uint128::new(match_number),
+                    round_number,
+                    team_1,
+                    team_2,
+                    result: None,
+                },
+            )?;
+        }
+
+        GROUPS.save(deps.storage, (hybrid_id.u128(), group), &members)?;
+    }
+
+    let mut updated_hybrid = hybrid.clone();
+    updated_hybrid.extension.group_matches = Uint128::new(group_matches);
+    competition_module.competitions.replace(
+        deps.storage,
+        hybrid_id.u128(),
+        Some(&updated_hybrid),
+        Some(&hybrid),
+    )?;
+
+    Ok(Response::default()
+        .add_attribute("action", "instantiate_groups")
+        .add_attribute("hybrid_id", hybrid_id.to_string())
+        .add_attribute("groups", group_count.to_string())
+        .add_attribute("group_matches", group_matches.to_string()))
+}
+
+/// Processes group stage match results.
+///
+/// Once every group match has a result, the top members of each group are seeded into the playoff bracket.
+pub fn process_group_matches(
+    mut deps: DepsMut,
+    info: MessageInfo,
+    hybrid_id: Uint128,
+    group: Uint64,
+    match_results: Vec<GroupMatchResultMsg>,
+) -> Result<Response, ContractError> {
+    let competition_module = CompetitionModule::default();
+    let hybrid = competition_module
+        .competitions
+        .load(deps.storage, hybrid_id.u128())?;
+    competition_module.inner_validate_auth(&info.sender, &hybrid, false)?;
+
+    if hybrid.extension.stage != HybridStage::GroupStage {
+        return Err(ContractError::StdError(StdError::generic_err(
+            "The group stage is already complete",
+        )));
+    }
+
+    let mut updated_hybrid = hybrid.clone();
+    let mut member_results = vec![];
+    for match_result in match_results {
+        let mut m = GROUP_MATCHES
+            .may_load(
+                deps.storage,
+                (
+                    hybrid_id.u128(),
+                    group.u64(),
+                    match_result.match_number.u128(),
+                ),
+            )?
+            .ok_or_else(|| {
+                StdError::generic_err(format!(
+                    "Match number {} not found in group {}",
+                    match_result.match_number, group
+                ))
+            })?;
+
+        // Rating updates are only handled the first time
+        if m.result.is_none() {
+            updated_hybrid.extension.processed_group_matches += Uint128::one();
+
+            // A double forfeit was not played, so it does not adjust ratings
+            let results = match match_result.match_result {
+                MatchResult::Team1 => Some((Decimal::one(), Decimal::zero())),
+                MatchResult::Team2 => Some((Decimal::zero(), Decimal::one())),
+                MatchResult::Draw => Some((Decimal::percent(50), Decimal::percent(50))),
+                MatchResult::DoubleForfeit => None,
+            };
+            if let (Some(_), Some((member_result_1, member_result_2))) =
+                (hybrid.category_id, results)
+            {
+                member_results.push((
+                    MemberResult {
+                        addr: m.team_1.clone(),
+                        result: member_result_1,
+                    },
+                    MemberResult {
+                        addr: m.team_2.clone(),
+                        result: member_result_2,
+                    },
+                ));
+            }
+        }
+
+        m.result = Some(match_result.match_result);
+        GROUP_MATCHES.save(
+            deps.storage,
+            (hybrid_id.u128(), group.u64(), m.match_number.u128()),
+            &m,
+        )?;
+    }
+
+    let sub_msgs = rating_adjustment(deps.branch(), &hybrid, member_results)?;
+
+    let mut response = Response::new();
+    if updated_hybrid.extension.processed_group_matches >= updated_hybrid.extension.group_matches {
+        let seeds = seed_playoffs(deps.as_ref(), &updated_hybrid)?;
+        generate_bracket(
+            deps.branch(),
+            &seeds,
+            hybrid_id.u128(),
+            &updated_hybrid.extension.playoffs.elimination_type,
+            updated_hybrid.extension.playoffs.placements,
+        )?;
+
+        updated_hybrid.extension.stage = HybridStage::Playoffs;
+        response = response.add_attribute("stage", "playoffs");
+    }
+
+    competition_module.competitions.replace(
+        deps.storage,
+        hybrid_id.u128(),
+        Some(&updated_hybrid),
+        Some(&hybrid),
+    )?;
+
+    Ok(response
+        .add_attribute("action", "process_group_matches")
+        .add_attribute(
+            "processed_group_matches",
+            updated_hybrid.extension.processed_group_matches.to_string(),
+        )
+        .add_submessages(sub_msgs))
+}
+
+/// Returns the playoff seeds from the final group standings.
+///
+/// Members are ordered by group placement, so every group winner is seeded above every runner-up.
+/// The group order is reversed on every other placement to keep the top seeds of each group apart.
+fn seed_playoffs(deps: Deps, hybrid: &Hybrid) -> Result<Vec<Addr>, ContractError> {
+    let group_count = hybrid.extension.group_count.u64();
+    let advance_per_group = hybrid.extension.advance_per_group.u64() as usize;
+
+    let mut standings = vec![];
+    for group in 1..=group_count {
+        standings.push(query::group_standings(deps, hybrid.id, Uint64::new(group))?);
+    }
+
+    let mut seeds = vec![];
+    for placement in 0..advance_per_group {
+        let mut placed: Vec<Addr> = standings
+            .iter()
+            .filter_map(|x| x.get(placement).map(|y| y.member.clone()))
+            .collect();
+        if placement % 2 == 1 {
+            placed.reverse();
+        }
+
+        seeds.append(&mut placed);
+    }
+
+    Ok(seeds)
+}
+
+/// Processes playoff match results and distributes the prize pool once every match has a result.
+pub fn process_playoff_matches(
+    mut deps: DepsMut,
+    info: MessageInfo,
+    hybrid_id: Uint128,
+    match_results: Vec<PlayoffMatchResultMsg>,
+) -> Result<Response, ContractError> {
+    let competition_module = CompetitionModule::default();
+    let hybrid = competition_module
+        .competitions
+        .load(deps.storage, hybrid_id.u128())?;
+    competition_module.inner_validate_auth(&info.sender, &hybrid, false)?;
+
+    if hybrid.extension.stage != HybridStage::Playoffs {
+        return Err(ContractError::StdError(StdError::generic_err(
+            "The playoffs have not started",
+        )));
+    }
+
+    let mut updated_hybrid = hybrid.clone();
+    let member_results = advance_matches(
+        deps.storage,
+        hybrid_id,
+        &mut updated_hybrid.extension.playoffs,
+        match_results,
+        hybrid.category_id.is_some(),
+    )?;
+
+    let sub_msgs = rating_adjustment(deps.branch(), &hybrid, member_results)?;
+
+    competition_module.competitions.replace(
+        deps.storage,
+        hybrid_id.u128(),
+        Some(&updated_hybrid),
+        Some(&hybrid),
+    )?;
+
+    let response = if updated_hybrid.extension.playoffs.processed_matches
+        >= updated_hybrid.extension.playoffs.total_matches
+    {
+        trigger_distribution(deps, &updated_hybrid)?
+    } else {
+        Response::new()
+    };
+
+    Ok(response
+        .add_attribute("action", "process_playoff_matches")
+        .add_submessages(sub_msgs))
+}
+
+fn trigger_distribution(deps: DepsMut, hybrid: &Hybrid) -> Result<Response, ContractError> {
+    let placements =
+        bracket_placements(deps.storage, hybrid.id.u128(), &hybrid.extension.playoffs)?
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+
+    let member_percentages = hybrid
+        .extension
+        .playoffs
+        .distribution
+        .iter()
+        .zip(placements.iter())
+        .map(|(percentage, placement)| MemberPercentage {
+            addr: placement.clone(),
+            percentage: *percentage,
+        })
+        .collect();
+
+    let distribution = Distribution {
+        member_percentages,
+        remainder_addr: placements[0].clone(),
+        fixed_amounts: vec![],
+        remainder_shares: vec![],
+    };
+
+    Ok(CompetitionModule::default().inner_process(deps, hybrid, Some(distribution), None, None)?)
+}
+
+fn rating_adjustment(
+    deps: DepsMut,
+    hybrid: &Hybrid,
+    member_results: Vec<(MemberResult<Addr>, MemberResult<Addr>)>,
+) -> Result<Vec<SubMsg>, ContractError> {
+    let mut sub_msgs = vec![];
+
+    if let Some(category_id) = hybrid.category_id {
+        if !member_results.is_empty()
+            && CompetitionModule::default().query_is_dao_member(
+                deps.as_ref(),
+                &hybrid.host,
+                hybrid.start_height,
+            )
+        {
+            sub_msgs.push(CompetitionModule::default().trigger_rating_adjustment(
+                deps.storage,
+                category_id,
+                member_results,
+            )?);
+        }
+    }
+
+    Ok(sub_msgs)
+}
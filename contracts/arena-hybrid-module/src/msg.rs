@@ -0,0 +1,186 @@
+use crate::state::{GroupMatch, HybridExt, HybridStage};
+use arena_interface::{
+    competition::{
+        msg::{ExecuteBase, InstantiateBase, MigrateBase, QueryBase, ToCompetitionExt},
+        state::{Competition, CompetitionResponse},
+    },
+    group,
+};
+use arena_tournament_module::state::{EliminationType, Match, TournamentExt};
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, Decimal, Empty, StdError, StdResult, Uint128, Uint64};
+
+pub use arena_league_module::msg::MatchResultMsg as GroupMatchResultMsg;
+pub use arena_tournament_module::msg::{
+    GroupResponse, GroupStanding, MatchResultMsg as PlayoffMatchResultMsg,
+};
+
+#[cw_serde]
+#[derive(cw_orch::ExecuteFns)]
+pub enum ExecuteExt {
+    /// Callable only by the module to draw the groups when creating a competition
+    InstantiateGroups {},
+    /// The playoffs are seeded once every group match has a result
+    ProcessGroupMatch {
+        hybrid_id: Uint128,
+        group: Uint64,
+        match_results: Vec<GroupMatchResultMsg>,
+    },
+    /// The competition is processed once every playoff match has a result
+    ProcessPlayoffMatch {
+        hybrid_id: Uint128,
+        match_results: Vec<PlayoffMatchResultMsg>,
+    },
+}
+
+impl From<ExecuteExt> for ExecuteMsg {
+    fn from(msg: ExecuteExt) -> Self {
+        ExecuteMsg::Extension { msg }
+    }
+}
+
+#[cw_serde]
+#[derive(QueryResponses, cw_orch::QueryFns)]
+pub enum HybridQueryExt {
+    #[returns(Vec<GroupResponse>)]
+    Groups { hybrid_id: Uint128 },
+    #[returns(Vec<GroupStanding>)]
+    GroupStandings { hybrid_id: Uint128, group: Uint64 },
+    #[returns(Vec<GroupMatch>)]
+    GroupMatches {
+        hybrid_id: Uint128,
+        group: Uint64,
+        round_number: Option<Uint64>,
+    },
+    #[returns(Vec<Match>)]
+    Bracket {
+        hybrid_id: Uint128,
+        start_after: Option<Uint128>,
+    },
+}
+
+impl From<HybridQueryExt> for QueryMsg {
+    fn from(msg: HybridQueryExt) -> Self {
+        QueryMsg::QueryExtension { msg }
+    }
+}
+
+#[cw_serde]
+#[serde(untagged)]
+pub enum MigrateMsg {
+    Base(MigrateBase),
+}
+
+/// This is used to completely generate schema types
+/// QueryExt response types are hidden by the QueryBase mapping to Binary output
+#[cw_serde]
+pub struct SudoMsg {
+    pub group_response: GroupResponse,
+    pub group_standing: GroupStanding,
+    pub group_match: GroupMatch,
+    pub playoff_match: Match,
+}
+
+#[cw_serde]
+pub struct HybridInstantiateExt {
+    pub match_win_points: Uint64,
+    pub match_draw_points: Uint64,
+    pub match_lose_points: Uint64,
+    pub group_count: Uint64,
+    pub advance_per_group: Uint64,
+    pub elimination_type: EliminationType,
+    pub distribution: Vec<Decimal>,
+}
+
+impl ToCompetitionExt<HybridExt> for HybridInstantiateExt {
+    fn to_competition_ext(
+        &self,
+        deps: cosmwasm_std::Deps,
+        group_contract: &Addr,
+    ) -> StdResult<HybridExt> {
+        let team_count: Uint64 = deps.querier.query_wasm_smart(
+            group_contract.to_string(),
+            &group::QueryMsg::MembersCount {},
+        )?;
+
+        if self.group_count.is_zero() {
+            return Err(StdError::generic_err("At least 1 group should be provided"));
+        }
+        // Every group needs more members than it advances, so the group stage decides something
+        if self.advance_per_group.is_zero()
+            || team_count < self.group_count * (self.advance_per_group + Uint64::one())
+        {
+            return Err(StdError::generic_err(
+                "Each group must have more members than it advances to the playoffs",
+            ));
+        }
+
+        let qualifiers = self.group_count * self.advance_per_group;
+        if qualifiers < Uint64::new(2) {
+            return Err(StdError::generic_err(
+                "At least 2 members should advance to the playoffs",
+            ));
+        }
+
+        let max_placements = match self.elimination_type {
+            EliminationType::SingleElimination {
+                play_third_place_match,
+            } => {
+                if play_third_place_match {
+                    if qualifiers < Uint64::new(4) {
+                        return Err(StdError::generic_err(
+                            "At least 4 members should advance for a 3rd place match",
+                        ));
+                    }
+
+                    Uint64::new(4)
+                } else {
+                    Uint64::new(2)
+                }
+            }
+            EliminationType::DoubleElimination => Uint64::min(qualifiers, Uint64::new(3)),
+            EliminationType::RoundRobinGroups { .. } => {
+                return Err(StdError::generic_err(
+                    "The playoffs are already played after the group stage",
+                ));
+            }
+        };
+        if Uint64::new(self.distribution.len() as u64) > max_placements {
+            return Err(StdError::generic_err(
+                "Cannot have a distribution size bigger than the possible placements",
+            ));
+        }
+        if self.distribution.iter().sum::<Decimal>() != Decimal::one() {
+            return Err(StdError::generic_err("The distribution must sum up to 1"));
+        }
+
+        Ok(HybridExt {
+            match_win_points: self.match_win_points,
+            match_draw_points: self.match_draw_points,
+            match_lose_points: self.match_lose_points,
+            group_count: self.group_count,
+            advance_per_group: self.advance_per_group,
+            group_matches: Uint128::zero(),
+            processed_group_matches: Uint128::zero(),
+            playoffs: TournamentExt {
+                elimination_type: self.elimination_type.clone(),
+                distribution: self.distribution.clone(),
+                total_matches: self.elimination_type.total_matches(qualifiers).into(),
+                processed_matches: Uint128::zero(),
+                series: None,
+                carried_distribution: None,
+                seeding: None,
+                placements: None,
+                group_stage: None,
+                bracket_reset: None,
+            },
+            stage: HybridStage::GroupStage,
+        })
+    }
+}
+
+pub type InstantiateMsg = InstantiateBase<Empty>;
+pub type ExecuteMsg = ExecuteBase<ExecuteExt, HybridInstantiateExt>;
+pub type QueryMsg = QueryBase<Empty, HybridQueryExt, HybridExt>;
+pub type Hybrid = Competition<HybridExt>;
+pub type HybridResponse = CompetitionResponse<HybridExt>;
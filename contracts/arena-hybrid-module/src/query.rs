@@ -0,0 +1,101 @@
+use crate::{
+    contract::CompetitionModule,
+    msg::{GroupResponse, GroupStanding},
+    state::{GroupMatch, GROUPS, GROUP_MATCHES},
+};
+use arena_league_module::state::MatchResult;
+use arena_tournament_module::query::{empty_standings, group_responses, rank_standings};
+use cosmwasm_std::{Deps, Order, StdResult, Uint128, Uint64};
+
+pub fn groups(deps: Deps, hybrid_id: Uint128) -> StdResult<Vec<GroupResponse>> {
+    group_responses(deps, &GROUPS, hybrid_id)
+}
+
+/// Calculates the standings of a group.
+///
+/// # Details
+/// - Points are awarded from the match results using the competition's point values.
+/// - The standings are sorted by points, then by wins.
+/// - Remaining ties keep the group's seed order.
+pub fn group_standings(
+    deps: Deps,
+    hybrid_id: Uint128,
+    group: Uint64,
+) -> StdResult<Vec<GroupStanding>> {
+    let hybrid = CompetitionModule::default()
+        .competitions
+        .load(deps.storage, hybrid_id.u128())?;
+    let members = GROUPS.load(deps.storage, (hybrid_id.u128(), group.u64()))?;
+
+    let mut records = empty_standings(&members);
+
+    for m in GROUP_MATCHES.prefix((hybrid_id.u128(), group.u64())).range(
+        deps.storage,
+        None,
+        None,
+        Order::Ascending,
+    ) {
+        let (_, m) = m?;
+
+        if let Some(result) = m.result {
+            let (winner, loser) = match result {
+                MatchResult::Team1 => (m.team_1, m.team_2),
+                MatchResult::Team2 => (m.team_2, m.team_1),
+                MatchResult::Draw => {
+                    for team in [m.team_1, m.team_2] {
+                        if let Some(record) = records.get_mut(&team) {
+                            record.points = record
+                                .points
+                                .checked_add(hybrid.extension.match_draw_points)?;
+                            record.draws = record.draws.checked_add(Uint64::one())?;
+                        }
+                    }
+                    continue;
+                }
+                MatchResult::DoubleForfeit => {
+                    for team in [m.team_1, m.team_2] {
+                        if let Some(record) = records.get_mut(&team) {
+                            record.points = record
+                                .points
+                                .checked_add(hybrid.extension.match_lose_points)?;
+                            record.losses = record.losses.checked_add(Uint64::one())?;
+                        }
+                    }
+                    continue;
+                }
+            };
+
+            if let Some(record) = records.get_mut(&winner) {
+                record.points = record
+                    .points
+                    .checked_add(hybrid.extension.match_win_points)?;
+                record.wins = record.wins.checked_add(Uint64::one())?;
+            }
+            if let Some(record) = records.get_mut(&loser) {
+                record.points = record
+                    .points
+                    .checked_add(hybrid.extension.match_lose_points)?;
+                record.losses = record.losses.checked_add(Uint64::one())?;
+            }
+        }
+    }
+
+    Ok(rank_standings(&members, records))
+}
+
+pub fn group_matches(
+    deps: Deps,
+    hybrid_id: Uint128,
+    group: Uint64,
+    round_number: Option<Uint64>,
+) -> StdResult<Vec<GroupMatch>> {
+    GROUP_MATCHES
+        .prefix((hybrid_id.u128(), group.u64()))
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|x| x.map(|y| y.1))
+        .filter(|x| match (x, round_number) {
+            (Ok(m), Some(round_number)) => m.round_number == round_number,
+            _ => true,
+        })
+        .collect()
+}
@@ -0,0 +1,40 @@
+use arena_league_module::state::MatchResult;
+use arena_tournament_module::state::TournamentExt;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Uint128, Uint64};
+use cw_storage_plus::Map;
+
+#[cw_serde]
+pub struct GroupMatch {
+    pub match_number: Uint128,
+    pub round_number: Uint64,
+    pub team_1: Addr,
+    pub team_2: Addr,
+    pub result: Option<MatchResult>,
+}
+
+#[cw_serde]
+pub enum HybridStage {
+    GroupStage,
+    Playoffs,
+}
+
+#[cw_serde]
+pub struct HybridExt {
+    pub match_win_points: Uint64,
+    pub match_draw_points: Uint64,
+    pub match_lose_points: Uint64,
+    pub group_count: Uint64,
+    /// The number of members from each group seeded into the playoffs
+    pub advance_per_group: Uint64,
+    pub group_matches: Uint128,
+    pub processed_group_matches: Uint128,
+    /// The playoff bracket, which also holds the competition's distribution
+    pub playoffs: TournamentExt,
+    pub stage: HybridStage,
+}
+
+/// (Hybrid Id, Group Number)
+pub const GROUPS: Map<(u128, u64), Vec<Addr>> = Map::new("hybrid_groups");
+/// (Hybrid Id, Group Number, Match Number)
+pub const GROUP_MATCHES: Map<(u128, u64, u128), GroupMatch> = Map::new("hybrid_group_matches");
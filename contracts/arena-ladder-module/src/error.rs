@@ -0,0 +1,43 @@
+use cosmwasm_std::{OverflowError, StdError, Timestamp, Uint128};
+use cw_competition_base::error::CompetitionError;
+use cw_ownable::OwnershipError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    StdError(#[from] StdError),
+
+    #[error("{0}")]
+    CompetitionError(#[from] CompetitionError),
+
+    #[error("{0}")]
+    OverflowError(#[from] OverflowError),
+
+    #[error("{0}")]
+    OwnershipError(#[from] OwnershipError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Cannot challenge {opponent}: {msg}")]
+    InvalidChallenge { opponent: String, msg: String },
+
+    #[error("Challenge {challenge_id} not found")]
+    ChallengeNotFound { challenge_id: Uint128 },
+
+    #[error("{addr} already has an open challenge")]
+    ChallengeAlreadyOpen { addr: String },
+
+    #[error("The challenge's acceptance deadline has passed")]
+    AcceptDeadlinePassed {},
+
+    #[error("The challenge cannot be forfeited before {deadline}")]
+    AcceptDeadlineNotPassed { deadline: Timestamp },
+
+    #[error("The challenge has not been accepted")]
+    ChallengeNotAccepted {},
+
+    #[error("The next snapshot cannot be taken before {time}")]
+    SnapshotNotReady { time: Timestamp },
+}
@@ -0,0 +1,452 @@
+use std::collections::BTreeMap;
+
+use arena_interface::{
+    competition::state::CompetitionStatus,
+    group::{self, MemberMsg},
+    ratings::MemberResult,
+};
+use cosmwasm_std::{
+    ensure_eq, Addr, Decimal, DepsMut, Env, MessageInfo, Order, Response, StdResult, Storage,
+    SubMsg, Uint128, Uint64,
+};
+use cw_balance::{Distribution, MemberPercentage};
+
+use crate::{
+    contract::CompetitionModule,
+    msg::Ladder,
+    state::{Challenge, ChallengeStatus, CHALLENGES, CHALLENGE_COUNT, LADDERS, SNAPSHOTS},
+    ContractError,
+};
+
+/// Creates the initial ladder from the group's seed order.
+pub fn instantiate_ladder(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    ensure_eq!(
+        info.sender,
+        env.contract.address,
+        ContractError::Unauthorized {}
+    );
+
+    let competition_module = CompetitionModule::default();
+    let ladder_id = competition_module.competition_count.load(deps.storage)?;
+    let ladder = competition_module
+        .competitions
+        .load(deps.storage, ladder_id.u128())?;
+
+    let members: Vec<Addr> = deps
+        .querier
+        .query_wasm_smart::<Vec<MemberMsg<Addr>>>(
+            ladder.group_contract.to_string(),
+            &group::QueryMsg::Members {
+                start_after: None,
+                limit: None,
+            },
+        )?
+        .into_iter()
+        .map(|x| x.addr)
+        .collect();
+
+    LADDERS.save(deps.storage, ladder_id.u128(), &members)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "instantiate_ladder")
+        .add_attribute("ladder_id", ladder_id.to_string())
+        .add_attribute("teams", members.len().to_string()))
+}
+
+/// Challenges a member up to the challenge range above the sender.
+///
+/// A member can only be part of one open challenge at a time, so ladder positions cannot shift while a challenge is open.
+pub fn issue_challenge(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    ladder_id: Uint128,
+    opponent: String,
+) -> Result<Response, ContractError> {
+    let ladder = load_active_ladder(deps.storage, ladder_id)?;
+    let opponent = deps.api.addr_validate(&opponent)?;
+    let positions = LADDERS.load(deps.storage, ladder_id.u128())?;
+
+    let challenger_position = positions
+        .iter()
+        .position(|x| *x == info.sender)
+        .ok_or(ContractError::Unauthorized {})?;
+    let challenged_position = positions
+        .iter()
+        .position(|x| *x == opponent)
+        .ok_or_else(|| ContractError::InvalidChallenge {
+            opponent: opponent.to_string(),
+            msg: "The opponent is not on the ladder".to_string(),
+        })?;
+
+    if challenged_position >= challenger_position {
+        return Err(ContractError::InvalidChallenge {
+            opponent: opponent.to_string(),
+            msg: "Only members above the challenger can be challenged".to_string(),
+        });
+    }
+    if (challenger_position - challenged_position) as u64 > ladder.extension.challenge_range.u64() {
+        return Err(ContractError::InvalidChallenge {
+            opponent: opponent.to_string(),
+            msg: format!(
+                "Only members up to {} positions above can be challenged",
+                ladder.extension.challenge_range
+            ),
+        });
+    }
+
+    for challenge in
+        CHALLENGES
+            .prefix(ladder_id.u128())
+            .range(deps.storage, None, None, Order::Ascending)
+    {
+        let (_, challenge) = challenge?;
+
+        for addr in [&info.sender, &opponent] {
+            if challenge.challenger == *addr || challenge.challenged == *addr {
+                return Err(ContractError::ChallengeAlreadyOpen {
+                    addr: addr.to_string(),
+                });
+            }
+        }
+    }
+
+    let challenge_id = CHALLENGE_COUNT
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        .checked_add(Uint128::one())?;
+    CHALLENGE_COUNT.save(deps.storage, &challenge_id)?;
+
+    let accept_deadline = env.block.time.plus_seconds(ladder.extension.accept_window);
+    CHALLENGES.save(
+        deps.storage,
+        (ladder_id.u128(), challenge_id.u128()),
+        &Challenge {
+            challenge_id,
+            challenger: info.sender.clone(),
+            challenged: opponent.clone(),
+            accept_deadline,
+            status: ChallengeStatus::Pending,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "issue_challenge")
+        .add_attribute("ladder_id", ladder_id.to_string())
+        .add_attribute("challenge_id", challenge_id.to_string())
+        .add_attribute("challenger", info.sender)
+        .add_attribute("challenged", opponent)
+        .add_attribute("accept_deadline", accept_deadline.to_string()))
+}
+
+pub fn accept_challenge(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    ladder_id: Uint128,
+    challenge_id: Uint128,
+) -> Result<Response, ContractError> {
+    load_active_ladder(deps.storage, ladder_id)?;
+    let mut challenge = load_challenge(deps.storage, ladder_id, challenge_id)?;
+
+    if challenge.challenged != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    if challenge.status != ChallengeStatus::Pending {
+        return Err(ContractError::InvalidChallenge {
+            opponent: challenge.challenged.to_string(),
+            msg: "The challenge has already been accepted".to_string(),
+        });
+    }
+    if env.block.time >= challenge.accept_deadline {
+        return Err(ContractError::AcceptDeadlinePassed {});
+    }
+
+    challenge.status = ChallengeStatus::Accepted;
+    CHALLENGES.save(
+        deps.storage,
+        (ladder_id.u128(), challenge_id.u128()),
+        &challenge,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "accept_challenge")
+        .add_attribute("ladder_id", ladder_id.to_string())
+        .add_attribute("challenge_id", challenge_id.to_string()))
+}
+
+/// Processes the result of an accepted challenge, swapping the members' positions if the challenger wins.
+pub fn process_challenge(
+    mut deps: DepsMut,
+    info: MessageInfo,
+    ladder_id: Uint128,
+    challenge_id: Uint128,
+    winner: String,
+) -> Result<Response, ContractError> {
+    let competition_module = CompetitionModule::default();
+    let ladder = competition_module
+        .competitions
+        .load(deps.storage, ladder_id.u128())?;
+    competition_module.inner_validate_auth(&info.sender, &ladder, false)?;
+
+    let challenge = load_challenge(deps.storage, ladder_id, challenge_id)?;
+    if challenge.status != ChallengeStatus::Accepted {
+        return Err(ContractError::ChallengeNotAccepted {});
+    }
+
+    let winner = deps.api.addr_validate(&winner)?;
+    let loser = if winner == challenge.challenger {
+        &challenge.challenged
+    } else if winner == challenge.challenged {
+        &challenge.challenger
+    } else {
+        return Err(ContractError::InvalidChallenge {
+            opponent: challenge.challenged.to_string(),
+            msg: format!("{} is not part of the challenge", winner),
+        });
+    };
+
+    resolve_challenge(deps.storage, ladder_id, &challenge, &winner)?;
+
+    let sub_msgs = rating_adjustment(
+        deps.branch(),
+        &ladder,
+        vec![(
+            MemberResult {
+                addr: winner.clone(),
+                result: Decimal::one(),
+            },
+            MemberResult {
+                addr: loser.clone(),
+                result: Decimal::zero(),
+            },
+        )],
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "process_challenge")
+        .add_attribute("ladder_id", ladder_id.to_string())
+        .add_attribute("challenge_id", challenge_id.to_string())
+        .add_attribute("winner", winner)
+        .add_submessages(sub_msgs))
+}
+
+/// Awards a pending challenge to the challenger once the acceptance deadline has passed.
+///
+/// Forfeits do not trigger a rating adjustment, because no match was played.
+pub fn claim_forfeit(
+    deps: DepsMut,
+    env: Env,
+    ladder_id: Uint128,
+    challenge_id: Uint128,
+) -> Result<Response, ContractError> {
+    load_active_ladder(deps.storage, ladder_id)?;
+    let challenge = load_challenge(deps.storage, ladder_id, challenge_id)?;
+
+    if challenge.status != ChallengeStatus::Pending {
+        return Err(ContractError::InvalidChallenge {
+            opponent: challenge.challenged.to_string(),
+            msg: "An accepted challenge cannot be forfeited".to_string(),
+        });
+    }
+    if env.block.time < challenge.accept_deadline {
+        return Err(ContractError::AcceptDeadlineNotPassed {
+            deadline: challenge.accept_deadline,
+        });
+    }
+
+    resolve_challenge(deps.storage, ladder_id, &challenge, &challenge.challenger)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "claim_forfeit")
+        .add_attribute("ladder_id", ladder_id.to_string())
+        .add_attribute("challenge_id", challenge_id.to_string())
+        .add_attribute("winner", challenge.challenger))
+}
+
+/// Records the current ladder order as the next prize snapshot.
+///
+/// Snapshot `n` can be taken once `n * snapshot_interval` seconds have passed since the competition's date.
+/// Each snapshot awards an equal share of the prize pool by ladder position, and the accumulated shares are distributed from the escrow on the final snapshot.
+pub fn take_snapshot(
+    deps: DepsMut,
+    env: Env,
+    ladder_id: Uint128,
+) -> Result<Response, ContractError> {
+    let competition_module = CompetitionModule::default();
+    let ladder = load_active_ladder(deps.storage, ladder_id)?;
+
+    let snapshot = ladder
+        .extension
+        .snapshots_taken
+        .checked_add(Uint64::one())?;
+    let snapshot_time = ladder
+        .date
+        .plus_seconds(snapshot.u64() * ladder.extension.snapshot_interval);
+    if env.block.time < snapshot_time {
+        return Err(ContractError::SnapshotNotReady {
+            time: snapshot_time,
+        });
+    }
+
+    let positions = LADDERS.load(deps.storage, ladder_id.u128())?;
+    SNAPSHOTS.save(deps.storage, (ladder_id.u128(), snapshot.u64()), &positions)?;
+
+    let mut updated_ladder = ladder.clone();
+    updated_ladder.extension.snapshots_taken = snapshot;
+    competition_module.competitions.replace(
+        deps.storage,
+        ladder_id.u128(),
+        Some(&updated_ladder),
+        Some(&ladder),
+    )?;
+
+    let response = if snapshot >= ladder.extension.snapshots {
+        trigger_distribution(deps, &updated_ladder, &positions)?
+    } else {
+        Response::new()
+    };
+
+    Ok(response
+        .add_attribute("action", "take_snapshot")
+        .add_attribute("ladder_id", ladder_id.to_string())
+        .add_attribute("snapshot", snapshot.to_string()))
+}
+
+/// Distributes the prize pool from the accumulated snapshot shares.
+fn trigger_distribution(
+    deps: DepsMut,
+    ladder: &Ladder,
+    positions: &[Addr],
+) -> Result<Response, ContractError> {
+    let snapshot_share = Decimal::from_ratio(1u128, ladder.extension.snapshots.u64());
+
+    let mut shares: BTreeMap<Addr, Decimal> = BTreeMap::new();
+    for snapshot in
+        SNAPSHOTS
+            .prefix(ladder.id.u128())
+            .range(deps.storage, None, None, Order::Ascending)
+    {
+        let (_, snapshot) = snapshot?;
+
+        for (member, percentage) in snapshot.iter().zip(ladder.extension.distribution.iter()) {
+            let share = shares.entry(member.clone()).or_default();
+            *share = share.checked_add(percentage.checked_mul(snapshot_share)?)?;
+        }
+    }
+
+    // Members are listed in the final ladder order
+    let mut member_percentages: Vec<MemberPercentage<Addr>> = positions
+        .iter()
+        .filter_map(|member| {
+            shares.remove(member).map(|percentage| MemberPercentage {
+                addr: member.clone(),
+                percentage,
+            })
+        })
+        .collect();
+
+    // Rounding remainders are given to the highest placed member with a share
+    let total = member_percentages
+        .iter()
+        .map(|x| x.percentage)
+        .sum::<Decimal>();
+    if total < Decimal::one() {
+        member_percentages[0].percentage = member_percentages[0]
+            .percentage
+            .checked_add(Decimal::one().checked_sub(total)?)?;
+    }
+
+    let remainder_addr = member_percentages[0].addr.clone();
+    Ok(CompetitionModule::default().inner_process(
+        deps,
+        ladder,
+        Some(Distribution {
+            member_percentages,
+            remainder_addr,
+            fixed_amounts: vec![],
+            remainder_shares: vec![],
+        }),
+        None,
+        None,
+    )?)
+}
+
+/// Removes a challenge and swaps the members' positions if the challenger won.
+fn resolve_challenge(
+    storage: &mut dyn Storage,
+    ladder_id: Uint128,
+    challenge: &Challenge,
+    winner: &Addr,
+) -> StdResult<()> {
+    CHALLENGES.remove(storage, (ladder_id.u128(), challenge.challenge_id.u128()));
+
+    if *winner == challenge.challenger {
+        LADDERS.update(storage, ladder_id.u128(), |positions| -> StdResult<_> {
+            let mut positions = positions.unwrap_or_default();
+            let challenger = positions.iter().position(|x| *x == challenge.challenger);
+            let challenged = positions.iter().position(|x| *x == challenge.challenged);
+            if let (Some(challenger), Some(challenged)) = (challenger, challenged) {
+                positions.swap(challenger, challenged);
+            }
+
+            Ok(positions)
+        })?;
+    }
+
+    Ok(())
+}
+
+fn load_active_ladder(storage: &dyn Storage, ladder_id: Uint128) -> Result<Ladder, ContractError> {
+    let ladder = CompetitionModule::default()
+        .competitions
+        .load(storage, ladder_id.u128())?;
+
+    if !matches!(ladder.status, CompetitionStatus::Active { .. }) {
+        return Err(ContractError::CompetitionError(
+            cw_competition_base::error::CompetitionError::InvalidCompetitionStatus {
+                current_status: ladder.status,
+            },
+        ));
+    }
+
+    Ok(ladder)
+}
+
+fn load_challenge(
+    storage: &dyn Storage,
+    ladder_id: Uint128,
+    challenge_id: Uint128,
+) -> Result<Challenge, ContractError> {
+    CHALLENGES
+        .may_load(storage, (ladder_id.u128(), challenge_id.u128()))?
+        .ok_or(ContractError::ChallengeNotFound { challenge_id })
+}
+
+fn rating_adjustment(
+    deps: DepsMut,
+    ladder: &Ladder,
+    member_results: Vec<(MemberResult<Addr>, MemberResult<Addr>)>,
+) -> Result<Vec<SubMsg>, ContractError> {
+    let mut sub_msgs = vec![];
+
+    if let Some(category_id) = ladder.category_id {
+        if CompetitionModule::default().query_is_dao_member(
+            deps.as_ref(),
+            &ladder.host,
+            ladder.start_height,
+        ) {
+            sub_msgs.push(CompetitionModule::default().trigger_rating_adjustment(
+                deps.storage,
+                category_id,
+                member_results,
+            )?);
+        }
+    }
+
+    Ok(sub_msgs)
+}
@@ -0,0 +1,158 @@
+use crate::state::{Challenge, LadderExt};
+use arena_interface::{
+    competition::{
+        msg::{ExecuteBase, InstantiateBase, MigrateBase, QueryBase, ToCompetitionExt},
+        state::{Competition, CompetitionResponse},
+    },
+    group,
+};
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, Decimal, Empty, StdError, StdResult, Uint128, Uint64};
+
+#[cw_serde]
+#[derive(cw_orch::ExecuteFns)]
+pub enum ExecuteExt {
+    InstantiateLadder {},
+    /// Challenges a member up to the challenge range above the sender
+    IssueChallenge {
+        ladder_id: Uint128,
+        opponent: String,
+    },
+    /// Accepts a challenge before its acceptance deadline
+    AcceptChallenge {
+        ladder_id: Uint128,
+        challenge_id: Uint128,
+    },
+    /// Processes the result of an accepted challenge
+    /// The positions are swapped if the challenger wins
+    ProcessChallenge {
+        ladder_id: Uint128,
+        challenge_id: Uint128,
+        winner: String,
+    },
+    /// Awards a pending challenge to the challenger once its acceptance deadline has passed
+    ClaimForfeit {
+        ladder_id: Uint128,
+        challenge_id: Uint128,
+    },
+    /// Records the ladder order once the next snapshot time is reached
+    /// The prize pool is distributed on the final snapshot
+    TakeSnapshot {
+        ladder_id: Uint128,
+    },
+}
+
+impl From<ExecuteExt> for ExecuteMsg {
+    fn from(msg: ExecuteExt) -> Self {
+        ExecuteMsg::Extension { msg }
+    }
+}
+
+#[cw_serde]
+#[derive(QueryResponses, cw_orch::QueryFns)]
+pub enum LadderQueryExt {
+    #[returns(Vec<LadderStanding>)]
+    Standings { ladder_id: Uint128 },
+    #[returns(Vec<Challenge>)]
+    OpenChallenges {
+        ladder_id: Uint128,
+        start_after: Option<Uint128>,
+        limit: Option<u32>,
+    },
+    #[returns(Vec<Addr>)]
+    Snapshot {
+        ladder_id: Uint128,
+        snapshot: Uint64,
+    },
+}
+
+impl From<LadderQueryExt> for QueryMsg {
+    fn from(msg: LadderQueryExt) -> Self {
+        QueryMsg::QueryExtension { msg }
+    }
+}
+
+#[cw_serde]
+#[serde(untagged)]
+pub enum MigrateMsg {
+    Base(MigrateBase),
+}
+
+/// This is used to completely generate schema types
+/// QueryExt response types are hidden by the QueryBase mapping to Binary output
+#[cw_serde]
+pub struct SudoMsg {
+    pub ladder_standing: LadderStanding,
+    pub challenge: Challenge,
+}
+
+#[cw_serde]
+pub struct LadderInstantiateExt {
+    pub challenge_range: Uint64,
+    pub accept_window: u64,
+    pub snapshot_interval: u64,
+    pub snapshots: Uint64,
+    pub distribution: Vec<Decimal>,
+}
+
+impl ToCompetitionExt<LadderExt> for LadderInstantiateExt {
+    fn to_competition_ext(
+        &self,
+        deps: cosmwasm_std::Deps,
+        group_contract: &Addr,
+    ) -> StdResult<LadderExt> {
+        let team_count: Uint64 = deps.querier.query_wasm_smart(
+            group_contract.to_string(),
+            &group::QueryMsg::MembersCount {},
+        )?;
+        if team_count < Uint64::new(2) {
+            return Err(StdError::GenericErr {
+                msg: "At least 2 teams should be provided".to_string(),
+            });
+        }
+        if self.challenge_range.is_zero() {
+            return Err(StdError::generic_err(
+                "The challenge range must be at least 1",
+            ));
+        }
+        if self.accept_window == 0 || self.snapshot_interval == 0 {
+            return Err(StdError::generic_err(
+                "The accept window and snapshot interval must be greater than 0",
+            ));
+        }
+        if self.snapshots.is_zero() {
+            return Err(StdError::generic_err("At least 1 snapshot should be taken"));
+        }
+        if Uint64::new(self.distribution.len() as u64) > team_count {
+            return Err(StdError::GenericErr {
+                msg: "Cannot have a distribution size bigger than the teams size".to_string(),
+            });
+        }
+        if self.distribution.iter().sum::<Decimal>() != Decimal::one() {
+            return Err(StdError::generic_err("The distribution must sum up to 1"));
+        }
+
+        Ok(LadderExt {
+            challenge_range: self.challenge_range,
+            accept_window: self.accept_window,
+            snapshot_interval: self.snapshot_interval,
+            snapshots: self.snapshots,
+            snapshots_taken: Uint64::zero(),
+            teams: team_count,
+            distribution: self.distribution.clone(),
+        })
+    }
+}
+
+#[cw_serde]
+pub struct LadderStanding {
+    /// The 1-based ladder position
+    pub position: Uint64,
+    pub member: Addr,
+}
+
+pub type InstantiateMsg = InstantiateBase<Empty>;
+pub type ExecuteMsg = ExecuteBase<ExecuteExt, LadderInstantiateExt>;
+pub type QueryMsg = QueryBase<Empty, LadderQueryExt, LadderExt>;
+pub type Ladder = Competition<LadderExt>;
+pub type LadderResponse = CompetitionResponse<LadderExt>;
@@ -0,0 +1,40 @@
+use cosmwasm_std::{Addr, Deps, Order, StdResult, Uint128, Uint64};
+use cw_storage_plus::Bound;
+
+use crate::{
+    msg::LadderStanding,
+    state::{Challenge, CHALLENGES, LADDERS, SNAPSHOTS},
+};
+
+pub fn standings(deps: Deps, ladder_id: Uint128) -> StdResult<Vec<LadderStanding>> {
+    Ok(LADDERS
+        .load(deps.storage, ladder_id.u128())?
+        .into_iter()
+        .enumerate()
+        .map(|(i, member)| LadderStanding {
+            position: Uint64::new(i as u64 + 1),
+            member,
+        })
+        .collect())
+}
+
+pub fn open_challenges(
+    deps: Deps,
+    ladder_id: Uint128,
+    start_after: Option<Uint128>,
+    limit: Option<u32>,
+) -> StdResult<Vec<Challenge>> {
+    let start_after_bound = start_after.map(Bound::exclusive);
+    let limit = limit.unwrap_or(30) as usize;
+
+    CHALLENGES
+        .prefix(ladder_id.u128())
+        .range(deps.storage, start_after_bound, None, Order::Ascending)
+        .take(limit)
+        .map(|x| x.map(|y| y.1))
+        .collect::<StdResult<Vec<_>>>()
+}
+
+pub fn snapshot(deps: Deps, ladder_id: Uint128, snapshot: Uint64) -> StdResult<Vec<Addr>> {
+    SNAPSHOTS.load(deps.storage, (ladder_id.u128(), snapshot.u64()))
+}
@@ -0,0 +1,46 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Decimal, Timestamp, Uint128, Uint64};
+use cw_storage_plus::{Item, Map};
+
+#[cw_serde]
+pub struct LadderExt {
+    /// How many positions above themselves a member can challenge
+    pub challenge_range: Uint64,
+    /// The number of seconds a challenged member has to accept a challenge before forfeiting
+    pub accept_window: u64,
+    /// The number of seconds between prize snapshots
+    pub snapshot_interval: u64,
+    /// The total number of prize snapshots
+    pub snapshots: Uint64,
+    pub snapshots_taken: Uint64,
+    pub teams: Uint64,
+    /// The distribution of each snapshot's share of the prize pool by ladder position
+    pub distribution: Vec<Decimal>,
+}
+
+#[cw_serde]
+pub enum ChallengeStatus {
+    Pending,
+    Accepted,
+}
+
+#[cw_serde]
+pub struct Challenge {
+    pub challenge_id: Uint128,
+    pub challenger: Addr,
+    pub challenged: Addr,
+    pub accept_deadline: Timestamp,
+    pub status: ChallengeStatus,
+}
+
+/// The current ladder order, starting from the top position
+pub const LADDERS: Map<u128, Vec<Addr>> = Map::new("ladders");
+
+pub const CHALLENGE_COUNT: Item<Uint128> = Item::new("challenge_count");
+
+/// (Ladder Id, Challenge Id)
+/// Challenges are removed once they are resolved
+pub const CHALLENGES: Map<(u128, u128), Challenge> = Map::new("challenges");
+
+/// (Ladder Id, Snapshot Number)
+pub const SNAPSHOTS: Map<(u128, u64), Vec<Addr>> = Map::new("ladder_snapshots");
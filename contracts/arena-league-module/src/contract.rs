@@ -93,7 +93,59 @@ pub fn execute(
                 league_id,
                 round_number,
                 match_results,
-            } => execute::process_matches(deps, info, league_id, round_number, match_results),
+            } => execute::process_matches(deps, env, info, league_id, round_number, match_results),
+            ExecuteExt::CorrectMatch {
+                league_id,
+                round_number,
+                match_number,
+                match_result,
+            } => execute::correct_match(
+                deps,
+                info,
+                league_id,
+                round_number,
+                match_number,
+                match_result,
+            ),
+            ExecuteExt::DisputeMatch {
+                league_id,
+                round_number,
+                match_number,
+                match_result,
+                title,
+                description,
+                evidence,
+            } => execute::dispute_match(
+                deps,
+                env,
+                info,
+                league_id,
+                round_number,
+                match_number,
+                match_result,
+                title,
+                description,
+                evidence,
+            ),
+            ExecuteExt::ResolveDispute {
+                league_id,
+                round_number,
+                match_number,
+                match_result,
+            } => execute::resolve_dispute(
+                deps,
+                env,
+                info,
+                league_id,
+                round_number,
+                match_number,
+                match_result,
+            ),
+            ExecuteExt::DismissDispute {
+                league_id,
+                round_number,
+                match_number,
+            } => execute::dismiss_dispute(deps, env, info, league_id, round_number, match_number),
             ExecuteExt::UpdateDistribution {
                 league_id,
                 distribution,
@@ -104,10 +156,52 @@ pub fn execute(
                 point_adjustments,
             } => execute::add_point_adjustments(deps, info, league_id, addr, point_adjustments),
             ExecuteExt::InstantiateRounds {} => execute::instantiate_rounds(deps, env, info),
+            ExecuteExt::ReportMatch {
+                league_id,
+                round_number,
+                match_number,
+            } => execute::report_match(deps, env, info, league_id, round_number, match_number),
+            ExecuteExt::ProcessForfeits {
+                league_id,
+                round_number,
+            } => execute::process_forfeits(deps, env, league_id, round_number),
+            ExecuteExt::ProcessPlayoffMatch {
+                league_id,
+                match_results,
+            } => execute::process_playoff_matches(deps, info, league_id, match_results),
+            ExecuteExt::CreateSeason {
+                name,
+                divisions,
+                promotions,
+                relegations,
+            } => execute::create_season(deps, info, name, divisions, promotions, relegations),
+            ExecuteExt::CreateNextSeason {
+                season_id,
+                name,
+                group_code_id,
+                divisions,
+            } => execute::create_next_season(
+                deps,
+                env,
+                info,
+                season_id,
+                name,
+                group_code_id,
+                divisions,
+            ),
+            ExecuteExt::InstantiateDivision {
+                season_id,
+                group_contract,
+                division,
+            } => {
+                execute::instantiate_division(deps, env, info, season_id, group_contract, *division)
+            }
         },
         ExecuteBase::ProcessCompetition {
             competition_id,
             distribution,
+            nft_allocations,
+            vesting,
         } => {
             let competition = CompetitionModule::default()
                 .competitions
@@ -123,6 +217,8 @@ pub fn execute(
                 info,
                 competition_id,
                 distribution,
+                nft_allocations,
+                vesting,
                 None,
             )?)
         }
@@ -160,6 +256,25 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
                 league_id,
                 round_number,
             } => to_json_binary(&query::dump_state(deps, league_id, round_number)?),
+            LeagueQueryExt::PlayoffTournament { league_id } => {
+                to_json_binary(&query::playoff_tournament(deps, league_id)?)
+            }
+            LeagueQueryExt::MatchDispute {
+                league_id,
+                round_number,
+                match_number,
+            } => to_json_binary(&query::match_dispute(
+                deps,
+                league_id,
+                round_number,
+                match_number,
+            )?),
+            LeagueQueryExt::Season { season_id } => {
+                to_json_binary(&query::season(deps, season_id)?)
+            }
+            LeagueQueryExt::SeasonMovements { season_id } => {
+                to_json_binary(&query::season_movements(deps, season_id)?)
+            }
         },
         _ => CompetitionModule::default().query(deps, env, msg),
     }
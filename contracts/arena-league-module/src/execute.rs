@@ -1,21 +1,35 @@
 use arena_interface::{
-    competition::stats::StatValue,
-    group::{self, MemberMsg},
+    competition::{msg::EscrowContractInfo, state::CompetitionStatus},
+    core::{CompetitionModuleQuery, CompetitionModuleResponse},
+    escrow::TransferEscrowOwnershipMsg,
+    fees::FeeInformation,
+    group::{self, GroupContractInfo, MemberMsg},
+    helpers::round_robin_schedule,
     ratings::MemberResult,
 };
+use arena_tournament_module::msg::TournamentInstantiateExt;
 use cosmwasm_std::{
-    ensure_eq, Addr, Decimal, DepsMut, Env, MessageInfo, Order, Response, StdError, StdResult,
-    Uint128, Uint64,
+    ensure_eq, to_json_binary, Addr, CosmosMsg, Decimal, DepsMut, Env, MessageInfo, Order,
+    Response, StdError, StdResult, Storage, Uint128, Uint64, WasmMsg,
 };
 use cw_balance::{Distribution, MemberPercentage};
 use cw_competition_base::error::CompetitionError;
+use cw_ownable::{get_ownership, OwnershipError};
+use dao_interface::state::ModuleInstantiateInfo;
 use std::vec;
 
 use crate::{
     contract::CompetitionModule,
-    msg::{League, MatchResultMsg, MemberPoints},
+    msg::{
+        ExecuteExt, ExecuteMsg, League, MatchResultMsg, MemberPoints, PlacementDecider,
+        PlayoffMatchResultMsg, SeasonDivisionMsg,
+    },
     query,
-    state::{Match, PointAdjustment, Round, MATCHES, POINT_ADJUSTMENTS, ROUNDS},
+    state::{
+        LeaguePlayoffs, Match, MatchResult, PlayoffTournament, PointAdjustment, Round, Season,
+        LEAGUE_SEASONS, MATCHES, MATCH_DISPUTES, PLAYOFF_TOURNAMENTS, POINT_ADJUSTMENTS, ROUNDS,
+        SEASONS, SEASON_COUNT,
+    },
     ContractError,
 };
 
@@ -52,61 +66,57 @@ pub fn instantiate_rounds(
 
     let team_count = teams.len();
 
-    let mut teams_list = (1..=team_count).collect::<Vec<_>>();
-    let rounds = if team_count % 2 != 0 {
-        teams_list.push(0); // Using 0 as dummy team
-
-        team_count + 1
-    } else {
-        team_count
-    };
-
-    // Split teams into two groups
-    let (x, y) = teams_list.split_at(rounds / 2);
-    let mut x = x.to_vec();
-    let mut y = y.to_vec();
+    let schedule = round_robin_schedule(team_count);
 
     let mut round_number = 1u64;
     let mut match_number = 1u128;
-    for i in 0..rounds - 1 {
-        let mut matches = Vec::new();
-
-        // Rotate teams between x and y after the first round
-        if i != 0 {
-            let first_y = y.remove(0);
-            x.insert(1, first_y);
-            let last_x = x.pop().unwrap();
-            y.push(last_x);
-        }
+    for leg in 0..league.extension.legs {
+        for pairings in &schedule {
+            let mut matches = Vec::new();
 
-        // Create matches for the round
-        for j in 0..x.len() {
-            if x[j] != 0 && y[j] != 0 {
+            for &(home, away) in pairings {
+                // Home and away are swapped between legs
+                let (team_1, team_2) = if leg % 2 == 0 {
+                    (home, away)
+                } else {
+                    (away, home)
+                };
                 matches.push(Uint128::from(match_number));
 
                 MATCHES.save(
                     deps.storage,
                     (league_id.u128(), round_number, match_number),
                     &Match {
-                        team_1: teams[x[j] - 1].clone(), // adjust index for 0-based array
-                        team_2: teams[y[j] - 1].clone(),
+                        team_1: teams[team_1].clone(),
+                        team_2: teams[team_2].clone(),
                         result: None,
                         match_number: Uint128::from(match_number),
+                        reports: vec![],
+                        forfeits: vec![],
+                        rated_result: None,
                     },
                 )?;
                 match_number += 1;
             }
-        }
 
-        ROUNDS.save(
-            deps.storage,
-            (league_id.u128(), round_number),
-            &Round {
-                round_number: Uint64::from(round_number),
-                matches,
-            },
-        )?;
-        round_number += 1;
+            ROUNDS.save(
+                deps.storage,
+                (league_id.u128(), round_number),
+                &Round {
+                    round_number: Uint64::from(round_number),
+                    matches,
+                    scheduled_date: league
+                        .extension
+                        .round_duration
+                        .map(|x| league.date.plus_seconds((round_number - 1) * x)),
+                    deadline: league
+                        .extension
+                        .round_duration
+                        .map(|x| league.date.plus_seconds(round_number * x)),
+                },
+            )?;
+            round_number += 1;
+        }
     }
 
     Ok(Response::default()
@@ -124,15 +134,15 @@ pub fn instantiate_rounds(
 /// 3. Prepares rating updates for matches if the league has a category.
 /// 4. Updates the league's processed match count.
 /// 5. If all matches are complete:
-///    a. Calculates the leaderboard with optional stat-based tiebreaking.
-///    b. Groups members into placements based on points and tiebreakers.
+///    a. Calculates the leaderboard, ordered by points and the league's tiebreakers.
+///    b. Groups members into placements, where members are only grouped if every tiebreaker is equal.
 ///    c. Calculates the final distribution of rewards.
-///    d. Processes the competition results.
+///    d. Processes the competition results, or starts the playoffs if the league has them.
 ///
-/// The stat-based tiebreaking is applied only if stat types are defined for the league.
-/// The function uses the priority index to ensure stat types are considered in the correct order.
+/// The payout uses the same ordering as the `Leaderboard` query.
 pub fn process_matches(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     league_id: Uint128,
     round_number: Uint64,
@@ -156,6 +166,7 @@ pub fn process_matches(
             round_number.u64(),
             match_result.match_number.u128(),
         );
+        ensure_not_disputed(deps.storage, key)?;
         MATCHES.update(deps.storage, key, |x| -> Result<_, ContractError> {
             match x {
                 Some(mut m) => {
@@ -164,32 +175,11 @@ pub fn process_matches(
 
                         if league.category_id.is_some() {
                             // Prepare rating updates (only handled once per match)
-                            let (member_result_1, member_result_2) = match match_result.match_result
-                            {
-                                crate::state::MatchResult::Team1 => {
-                                    (Decimal::one(), Decimal::zero())
-                                }
-                                crate::state::MatchResult::Team2 => {
-                                    (Decimal::zero(), Decimal::one())
-                                }
-                                crate::state::MatchResult::Draw => {
-                                    (Decimal::percent(50), Decimal::percent(50))
-                                }
-                            };
-
-                            member_results.push((
-                                MemberResult {
-                                    addr: m.team_1.clone(),
-                                    result: member_result_1,
-                                },
-                                MemberResult {
-                                    addr: m.team_2.clone(),
-                                    result: member_result_2,
-                                },
-                            ));
+                            member_results.extend(rate_result(&mut m, &match_result.match_result));
                         }
                     }
                     m.result = Some(match_result.match_result);
+                    m.forfeits = vec![];
                     Ok(m)
                 }
                 None => Err(ContractError::StdError(StdError::NotFound {
@@ -232,9 +222,11 @@ pub fn process_matches(
 
     let mut response = Response::new();
 
-    // Process final results if all matches have been completed
-    if league.extension.processed_matches >= league.extension.matches {
-        response = process_final_results(deps, &league, league_id)?;
+    // Process final results if all matches have been completed and none are disputed
+    if league.extension.processed_matches >= league.extension.matches
+        && !has_open_disputes(deps.storage, league_id)
+    {
+        response = process_final_results(deps, &env, &league, league_id)?;
     }
 
     Ok(response
@@ -243,67 +235,517 @@ pub fn process_matches(
         .add_submessages(sub_msgs))
 }
 
-fn process_final_results(
+/// Corrects the result of a processed match before the league has been completed.
+///
+/// # Details
+/// - Only the host and the arena DAO can correct a match, through the same authorization as processing matches.
+/// - The leaderboard is calculated from the match results, so the points are rolled back by replacing the result.
+/// - A corrected forfeit becomes a regular result.
+/// - If the original result adjusted ratings, its reverse is sent to arena core before the corrected result.
+pub fn correct_match(
     deps: DepsMut,
-    league: &League,
+    info: MessageInfo,
     league_id: Uint128,
-) -> Result<Response, CompetitionError> {
-    let mut leaderboard = query::leaderboard(deps.as_ref(), league_id, None)?;
+    round_number: Uint64,
+    match_number: Uint128,
+    match_result: MatchResult,
+) -> Result<Response, ContractError> {
+    let league = CompetitionModule::default()
+        .competitions
+        .load(deps.storage, league_id.u128())?;
+    CompetitionModule::default().inner_validate_auth(&info.sender, &league, false)?;
 
-    // Fetch and sort stat types by priority
-    let mut stat_types: Vec<_> = CompetitionModule::default()
-        .stat_types
-        .prefix(league_id.u128())
-        .range(deps.storage, None, None, Order::Ascending)
-        .collect::<StdResult<Vec<_>>>()?;
+    let key = (league_id.u128(), round_number.u64(), match_number.u128());
+    ensure_not_disputed(deps.storage, key)?;
+    let mut m = MATCHES
+        .may_load(deps.storage, key)?
+        .ok_or(ContractError::StdError(StdError::NotFound {
+            kind: "Match".to_string(),
+        }))?;
+    let previous_result = m
+        .result
+        .clone()
+        .ok_or_else(|| StdError::generic_err("The match has not been processed"))?;
+    if previous_result == match_result {
+        return Err(ContractError::StdError(StdError::generic_err(
+            "The match already has this result",
+        )));
+    }
 
-    stat_types.sort_by(|a, b| {
-        a.1.tie_breaker_priority
-            .unwrap_or(u8::MAX)
-            .cmp(&b.1.tie_breaker_priority.unwrap_or(u8::MAX))
-    });
+    let mut sub_msgs = vec![];
+    if let Some(category_id) = league.category_id {
+        let member_results = correct_ratings(&mut m, &match_result);
+        if !member_results.is_empty()
+            && CompetitionModule::default().query_is_dao_member(
+                deps.as_ref(),
+                &league.host,
+                league.start_height,
+            )
+        {
+            sub_msgs.push(CompetitionModule::default().trigger_rating_adjustment(
+                deps.storage,
+                category_id,
+                member_results,
+            )?);
+        }
+    }
 
-    // Define a comparison function that considers both points and stats
-    let compare_members = |a: &MemberPoints, b: &MemberPoints| {
-        b.points.cmp(&a.points).then_with(|| {
-            for (_, stat_type) in &stat_types {
-                let (a_stat, b_stat) = match &stat_type.aggregation_type {
-                    Some(_) => (
-                        CompetitionModule::default()
-                            .inner_aggregate(deps.as_ref(), league_id, &a.member, stat_type)
-                            .ok(),
-                        CompetitionModule::default()
-                            .inner_aggregate(deps.as_ref(), league_id, &b.member, stat_type)
-                            .ok(),
-                    ),
-                    None => (
-                        CompetitionModule::default()
-                            .stats
-                            .may_load(deps.storage, (league_id.u128(), &a.member, &stat_type.name))
-                            .ok()
-                            .flatten(),
-                        CompetitionModule::default()
-                            .stats
-                            .may_load(deps.storage, (league_id.u128(), &b.member, &stat_type.name))
-                            .ok()
-                            .flatten(),
-                    ),
-                };
-                dbg!(a_stat.clone());
-                dbg!(b_stat.clone());
-                if let (Some(a_val), Some(b_val)) = (a_stat, b_stat) {
-                    let cmp = compare_stat_values(&a_val, &b_val, stat_type.is_beneficial);
-                    if cmp != std::cmp::Ordering::Equal {
-                        return cmp;
-                    }
-                }
-            }
-            std::cmp::Ordering::Equal
-        })
+    m.result = Some(match_result.clone());
+    m.forfeits = vec![];
+    MATCHES.save(deps.storage, key, &m)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "correct_match")
+        .add_attribute("match_number", match_number.to_string())
+        .add_attribute("previous_result", format!("{:?}", previous_result))
+        .add_attribute("result", format!("{:?}", match_result))
+        .add_submessages(sub_msgs))
+}
+
+/// Rates a match's result, and records it so that a correction can reverse it.
+fn rate_result(
+    m: &mut Match,
+    match_result: &MatchResult,
+) -> Option<(MemberResult<Addr>, MemberResult<Addr>)> {
+    let member_results = member_results_for(m, match_result)?;
+    m.rated_result = Some(match_result.clone());
+
+    Some(member_results)
+}
+
+/// Reverses the rated result, where each team receives the other team's result, and then rates the corrected result.
+fn correct_ratings(
+    m: &mut Match,
+    match_result: &MatchResult,
+) -> Vec<(MemberResult<Addr>, MemberResult<Addr>)> {
+    let mut member_results = vec![];
+    if let Some(rated_result) = m.rated_result.take() {
+        if let Some((member_result_1, member_result_2)) = member_results_for(m, &rated_result) {
+            member_results.push((
+                MemberResult {
+                    result: member_result_2.result,
+                    ..member_result_1
+                },
+                MemberResult {
+                    result: member_result_1.result,
+                    ..member_result_2
+                },
+            ));
+        }
+    }
+    member_results.extend(rate_result(m, match_result));
+
+    member_results
+}
+
+/// Gets the rating results of a match, where a double forfeit was not played and is not rated
+fn member_results_for(
+    m: &Match,
+    match_result: &MatchResult,
+) -> Option<(MemberResult<Addr>, MemberResult<Addr>)> {
+    let (result_1, result_2) = match match_result {
+        MatchResult::Team1 => (Decimal::one(), Decimal::zero()),
+        MatchResult::Team2 => (Decimal::zero(), Decimal::one()),
+        MatchResult::Draw => (Decimal::percent(50), Decimal::percent(50)),
+        MatchResult::DoubleForfeit => return None,
     };
 
-    // Sort the leaderboard using the comparison function
-    leaderboard.sort_by(compare_members);
+    Some((
+        MemberResult {
+            addr: m.team_1.clone(),
+            result: result_1,
+        },
+        MemberResult {
+            addr: m.team_2.clone(),
+            result: result_2,
+        },
+    ))
+}
+
+/// Disputes a match that the sender plays in without jailing the league.
+///
+/// # Details
+/// - The match is frozen until the dispute is resolved, so it cannot be processed, corrected, or forfeited.
+/// - The rest of the league keeps running, but its final results are not processed while a dispute is open.
+/// - A DAO proposal is created through the arena core, which resolves the dispute with the proposed result.
+#[allow(clippy::too_many_arguments)]
+pub fn dispute_match(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    league_id: Uint128,
+    round_number: Uint64,
+    match_number: Uint128,
+    match_result: MatchResult,
+    title: String,
+    description: String,
+    evidence: Vec<String>,
+) -> Result<Response, ContractError> {
+    let league = CompetitionModule::default()
+        .competitions
+        .load(deps.storage, league_id.u128())?;
+
+    let key = (league_id.u128(), round_number.u64(), match_number.u128());
+    let m = MATCHES
+        .may_load(deps.storage, key)?
+        .ok_or(ContractError::StdError(StdError::NotFound {
+            kind: "Match".to_string(),
+        }))?;
+    if m.team_1 != info.sender && m.team_2 != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    ensure_not_disputed(deps.storage, key)?;
+
+    let resolve_msg = to_json_binary(&ExecuteMsg::Extension {
+        msg: ExecuteExt::ResolveDispute {
+            league_id,
+            round_number,
+            match_number,
+            match_result,
+        },
+    })?;
+    let (dispute, msg) = CompetitionModule::default().inner_dispute_match(
+        deps.branch(),
+        &env,
+        info.clone(),
+        &league,
+        title,
+        description,
+        evidence,
+        resolve_msg,
+    )?;
+    MATCH_DISPUTES.save(deps.storage, key, &dispute)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "dispute_match")
+        .add_attribute("match_number", match_number.to_string())
+        .add_attribute("originator", info.sender)
+        .add_message(msg))
+}
+
+/// Resolves a disputed match with the result decided by the arena DAO.
+///
+/// # Details
+/// - An unprocessed match is processed, and a processed match is corrected like `correct_match`.
+/// - The final results are processed if the league's matches are complete and this was the last open dispute.
+pub fn resolve_dispute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    league_id: Uint128,
+    round_number: Uint64,
+    match_number: Uint128,
+    match_result: MatchResult,
+) -> Result<Response, ContractError> {
+    let mut league = CompetitionModule::default()
+        .competitions
+        .load(deps.storage, league_id.u128())?;
+    if info.sender != league.admin_dao {
+        return Err(ContractError::Unauthorized {});
+    }
+    if !matches!(league.status, CompetitionStatus::Active { .. }) {
+        return Err(ContractError::CompetitionError(
+            CompetitionError::InvalidCompetitionStatus {
+                current_status: league.status,
+            },
+        ));
+    }
+
+    let key = (league_id.u128(), round_number.u64(), match_number.u128());
+    if !MATCH_DISPUTES.has(deps.storage, key) {
+        return Err(ContractError::StdError(StdError::NotFound {
+            kind: "Match dispute".to_string(),
+        }));
+    }
+    MATCH_DISPUTES.remove(deps.storage, key);
+
+    let mut m = MATCHES.load(deps.storage, key)?;
+    let previous_result = m.result.clone();
+
+    let member_results =
+        if league.category_id.is_none() || previous_result.as_ref() == Some(&match_result) {
+            vec![]
+        } else {
+            correct_ratings(&mut m, &match_result)
+        };
+
+    m.result = Some(match_result.clone());
+    m.forfeits = vec![];
+    MATCHES.save(deps.storage, key, &m)?;
+
+    let mut sub_msgs = vec![];
+    if let Some(category_id) = league.category_id {
+        if !member_results.is_empty()
+            && CompetitionModule::default().query_is_dao_member(
+                deps.as_ref(),
+                &league.host,
+                league.start_height,
+            )
+        {
+            sub_msgs.push(CompetitionModule::default().trigger_rating_adjustment(
+                deps.storage,
+                category_id,
+                member_results,
+            )?);
+        }
+    }
+
+    if previous_result.is_none() {
+        let mut updated_league = league.clone();
+        updated_league.extension.processed_matches += Uint128::one();
+
+        CompetitionModule::default().competitions.replace(
+            deps.storage,
+            league_id.u128(),
+            Some(&updated_league),
+            Some(&league),
+        )?;
+
+        league = updated_league;
+    }
+
+    let mut response = Response::new();
+    if league.extension.processed_matches >= league.extension.matches
+        && !has_open_disputes(deps.storage, league_id)
+    {
+        response = process_final_results(deps, &env, &league, league_id)?;
+    }
+
+    Ok(response
+        .add_attribute("action", "resolve_dispute")
+        .add_attribute("match_number", match_number.to_string())
+        .add_attribute("result", format!("{:?}", match_result))
+        .add_submessages(sub_msgs))
+}
+
+/// Dismisses a disputed match without deciding its result.
+///
+/// # Details
+/// - Only the host and the arena DAO can dismiss a dispute, through the same authorization as processing matches.
+/// - A dispute whose proposal was rejected or never executed would otherwise keep the match frozen.
+/// - The final results are processed if the league's matches are complete and this was the last open dispute.
+pub fn dismiss_dispute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    league_id: Uint128,
+    round_number: Uint64,
+    match_number: Uint128,
+) -> Result<Response, ContractError> {
+    let league = CompetitionModule::default()
+        .competitions
+        .load(deps.storage, league_id.u128())?;
+    CompetitionModule::default().inner_validate_auth(&info.sender, &league, false)?;
+
+    let key = (league_id.u128(), round_number.u64(), match_number.u128());
+    if !MATCH_DISPUTES.has(deps.storage, key) {
+        return Err(ContractError::StdError(StdError::NotFound {
+            kind: "Match dispute".to_string(),
+        }));
+    }
+    MATCH_DISPUTES.remove(deps.storage, key);
+
+    let mut response = Response::new();
+    if matches!(league.status, CompetitionStatus::Active { .. })
+        && league.extension.processed_matches >= league.extension.matches
+        && !has_open_disputes(deps.storage, league_id)
+    {
+        response = process_final_results(deps, &env, &league, league_id)?;
+    }
+
+    Ok(response
+        .add_attribute("action", "dismiss_dispute")
+        .add_attribute("match_number", match_number.to_string()))
+}
+
+fn ensure_not_disputed(storage: &dyn Storage, key: (u128, u64, u128)) -> Result<(), ContractError> {
+    if MATCH_DISPUTES.has(storage, key) {
+        return Err(ContractError::StdError(StdError::generic_err(format!(
+            "Match {} is disputed",
+            key.2
+        ))));
+    }
+
+    Ok(())
+}
+
+fn has_open_disputes(storage: &dyn Storage, league_id: Uint128) -> bool {
+    MATCH_DISPUTES
+        .sub_prefix(league_id.u128())
+        .keys(storage, None, None, Order::Ascending)
+        .next()
+        .is_some()
+}
+
+/// Reports the sender's team for a match before its round's deadline.
+///
+/// # Details
+/// - Only leagues with scheduled rounds accept reports.
+/// - A team that does not report before the deadline can be forfeited through `process_forfeits`.
+pub fn report_match(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    league_id: Uint128,
+    round_number: Uint64,
+    match_number: Uint128,
+) -> Result<Response, ContractError> {
+    let league = CompetitionModule::default()
+        .competitions
+        .load(deps.storage, league_id.u128())?;
+    if !matches!(league.status, CompetitionStatus::Active { .. }) {
+        return Err(ContractError::CompetitionError(
+            CompetitionError::InvalidCompetitionStatus {
+                current_status: league.status,
+            },
+        ));
+    }
+
+    let round = ROUNDS.load(deps.storage, (league_id.u128(), round_number.u64()))?;
+    let deadline = round
+        .deadline
+        .ok_or_else(|| StdError::generic_err("The league does not have scheduled rounds"))?;
+    if env.block.time > deadline {
+        return Err(ContractError::StdError(StdError::generic_err(
+            "The round's deadline has passed",
+        )));
+    }
+
+    MATCHES.update(
+        deps.storage,
+        (league_id.u128(), round_number.u64(), match_number.u128()),
+        |x| -> Result<_, ContractError> {
+            let mut m = x.ok_or(ContractError::StdError(StdError::NotFound {
+                kind: "Match".to_string(),
+            }))?;
+
+            if m.team_1 != info.sender && m.team_2 != info.sender {
+                return Err(ContractError::Unauthorized {});
+            }
+            if m.result.is_some() {
+                return Err(ContractError::StdError(StdError::generic_err(
+                    "The match has already been processed",
+                )));
+            }
+            if !m.reports.contains(&info.sender) {
+                m.reports.push(info.sender.clone());
+            }
+
+            Ok(m)
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "report_match")
+        .add_attribute("match_number", match_number.to_string())
+        .add_attribute("team", info.sender))
+}
+
+/// Records forfeits for a round's unprocessed matches once its deadline has passed.
+///
+/// # Details
+/// - A team that reported wins against a team that did not, and a match where neither team reported is a double forfeit.
+/// - A match where both teams reported was played, but its result was not processed by the deadline, so it is a draw.
+/// - Disputed matches are left for the arena DAO to resolve.
+/// - Forfeits and unprocessed draws do not trigger rating adjustments, since no result was reported for them.
+/// - The final results are processed if this completes the league's matches.
+pub fn process_forfeits(
+    deps: DepsMut,
+    env: Env,
+    league_id: Uint128,
+    round_number: Uint64,
+) -> Result<Response, ContractError> {
+    let mut league = CompetitionModule::default()
+        .competitions
+        .load(deps.storage, league_id.u128())?;
+    if !matches!(league.status, CompetitionStatus::Active { .. }) {
+        return Err(ContractError::CompetitionError(
+            CompetitionError::InvalidCompetitionStatus {
+                current_status: league.status,
+            },
+        ));
+    }
+
+    let round = ROUNDS.load(deps.storage, (league_id.u128(), round_number.u64()))?;
+    let deadline = round
+        .deadline
+        .ok_or_else(|| StdError::generic_err("The league does not have scheduled rounds"))?;
+    if env.block.time <= deadline {
+        return Err(ContractError::StdError(StdError::generic_err(format!(
+            "Forfeits can only be processed after {}",
+            deadline
+        ))));
+    }
+
+    let mut processed_matches = league.extension.processed_matches;
+    for match_number in round.matches {
+        let key = (league_id.u128(), round_number.u64(), match_number.u128());
+        let mut m = MATCHES.load(deps.storage, key)?;
+
+        let team_1_reported = m.reports.contains(&m.team_1);
+        let team_2_reported = m.reports.contains(&m.team_2);
+        if m.result.is_some() || MATCH_DISPUTES.has(deps.storage, key) {
+            continue;
+        }
+
+        let (result, forfeits) = match (team_1_reported, team_2_reported) {
+            (true, true) => (MatchResult::Draw, vec![]),
+            (true, false) => (MatchResult::Team1, vec![m.team_2.clone()]),
+            (false, true) => (MatchResult::Team2, vec![m.team_1.clone()]),
+            (false, false) => (
+                MatchResult::DoubleForfeit,
+                vec![m.team_1.clone(), m.team_2.clone()],
+            ),
+        };
+
+        m.result = Some(result);
+        m.forfeits = forfeits;
+        MATCHES.save(deps.storage, key, &m)?;
+
+        processed_matches += Uint128::one();
+    }
+
+    let resolved_matches = processed_matches - league.extension.processed_matches;
+    if !resolved_matches.is_zero() {
+        let mut updated_league = league.clone();
+        updated_league.extension.processed_matches = processed_matches;
+
+        CompetitionModule::default().competitions.replace(
+            deps.storage,
+            league_id.u128(),
+            Some(&updated_league),
+            Some(&league),
+        )?;
+
+        league.extension.processed_matches = processed_matches;
+    }
+
+    let mut response = Response::new();
+    if !resolved_matches.is_zero()
+        && league.extension.processed_matches >= league.extension.matches
+        && !has_open_disputes(deps.storage, league_id)
+    {
+        response = process_final_results(deps, &env, &league, league_id)?;
+    }
+
+    Ok(response
+        .add_attribute("action", "process_forfeits")
+        .add_attribute("resolved_matches", resolved_matches.to_string())
+        .add_attribute("processed_matches", processed_matches.to_string()))
+}
+
+fn process_final_results(
+    deps: DepsMut,
+    env: &Env,
+    league: &League,
+    league_id: Uint128,
+) -> Result<Response, CompetitionError> {
+    // The leaderboard is already ordered by points and the league's tiebreakers
+    let leaderboard = query::leaderboard(deps.as_ref(), league_id, None)?;
+
+    if let Some(playoffs) = &league.extension.playoffs {
+        return start_playoffs(deps, env, league, playoffs, leaderboard);
+    }
 
     let placements = league.extension.distribution.len();
     let mut placement_members: Vec<Vec<Addr>> = vec![];
@@ -312,19 +754,16 @@ fn process_final_results(
     for (i, member_points) in leaderboard.iter().enumerate() {
         if i == 0 {
             placement_members.push(vec![member_points.member.clone()]);
+        } else if member_points.decided_by == Some(PlacementDecider::Tied) {
+            placement_members
+                .last_mut()
+                .unwrap()
+                .push(member_points.member.clone());
         } else {
-            let previous = &leaderboard[i - 1];
-            if compare_members(previous, member_points) == std::cmp::Ordering::Equal {
-                placement_members
-                    .last_mut()
-                    .unwrap()
-                    .push(member_points.member.clone());
-            } else {
-                if placement_members.len() >= placements {
-                    break;
-                }
-                placement_members.push(vec![member_points.member.clone()]);
+            if placement_members.len() >= placements {
+                break;
             }
+            placement_members.push(vec![member_points.member.clone()]);
         }
     }
 
@@ -369,23 +808,210 @@ fn process_final_results(
         Some(Distribution::<Addr> {
             member_percentages,
             remainder_addr: leaderboard[0].member.clone(),
+            fixed_amounts: vec![],
+            remainder_shares: vec![],
         }),
+        None,
+        None,
     )
 }
 
-// Helper function to compare stat values
-fn compare_stat_values(a: &StatValue, b: &StatValue, is_beneficial: bool) -> std::cmp::Ordering {
-    let ord = match (a, b) {
-        (StatValue::Bool(a), StatValue::Bool(b)) => a.cmp(b),
-        (StatValue::Decimal(a), StatValue::Decimal(b)) => a.cmp(b),
-        (StatValue::Uint(a), StatValue::Uint(b)) => a.cmp(b),
-        _ => std::cmp::Ordering::Equal,
-    };
-    if is_beneficial {
-        ord.reverse()
-    } else {
-        ord
+/// Creates the playoff tournament on the tournament module from the final leaderboard.
+///
+/// # Details
+/// - The qualifiers are seeded in leaderboard order, and the regular season prizes follow the same order.
+/// - The escrow's ownership is transferred to the tournament module, so the playoffs use the league's funds.
+/// - The tournament pays the regular season share as a carried distribution before the bracket's distribution.
+/// - The module hosts the tournament, and the league's host processes its matches through `process_playoff_matches`.
+fn start_playoffs(
+    deps: DepsMut,
+    env: &Env,
+    league: &League,
+    playoffs: &LeaguePlayoffs,
+    leaderboard: Vec<MemberPoints>,
+) -> Result<Response, CompetitionError> {
+    let qualifiers: Vec<Addr> = leaderboard
+        .into_iter()
+        .take(playoffs.qualifiers.u64() as usize)
+        .map(|x| x.member)
+        .collect();
+
+    let mut carried_distribution = vec![];
+    for (percentage, member) in league.extension.distribution.iter().zip(qualifiers.iter()) {
+        let percentage = percentage.checked_mul(playoffs.regular_season_share)?;
+        if !percentage.is_zero() {
+            carried_distribution.push(MemberPercentage {
+                addr: member.to_string(),
+                percentage,
+            });
+        }
+    }
+
+    // Find the tournament module through arena core
+    let arena_core = get_ownership(deps.storage)?
+        .owner
+        .ok_or(CompetitionError::OwnershipError(OwnershipError::NoOwner))?;
+    let tournament_module = deps
+        .querier
+        .query_wasm_smart::<Option<CompetitionModuleResponse<Addr>>>(
+            arena_core,
+            &arena_interface::core::QueryMsg::QueryExtension {
+                msg: arena_interface::core::QueryExt::CompetitionModule {
+                    query: CompetitionModuleQuery::Key("Tournaments".to_string(), None),
+                },
+            },
+        )?
+        .filter(|x| x.is_enabled)
+        .ok_or_else(|| StdError::generic_err("Could not find the tournament module"))?
+        .addr;
+
+    let tournament_id = deps
+        .querier
+        .query_wasm_smart::<Uint128>(
+            tournament_module.to_string(),
+            &arena_tournament_module::msg::QueryMsg::CompetitionCount {},
+        )?
+        .checked_add(Uint128::one())?;
+    let group_code_id = deps
+        .querier
+        .query_wasm_contract_info(league.group_contract.to_string())?
+        .code_id;
+
+    PLAYOFF_TOURNAMENTS.save(
+        deps.storage,
+        league.id.u128(),
+        &PlayoffTournament {
+            module: tournament_module.clone(),
+            tournament_id,
+        },
+    )?;
+
+    // The league's prize pool is now paid out by the playoffs
+    let mut updated_league = league.clone();
+    updated_league.status = CompetitionStatus::Inactive;
+    CompetitionModule::default().competitions.replace(
+        deps.storage,
+        league.id.u128(),
+        Some(&updated_league),
+        Some(league),
+    )?;
+
+    let lock_msg = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: league.escrow.to_string(),
+        msg: to_json_binary(&arena_interface::escrow::ExecuteMsg::Lock {
+            value: true,
+            transfer_ownership: Some(TransferEscrowOwnershipMsg {
+                addr: tournament_module.to_string(),
+                is_enrollment: false,
+            }),
+        })?,
+        funds: vec![],
+    });
+    let create_msg = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: tournament_module.to_string(),
+        msg: to_json_binary(
+            &arena_tournament_module::msg::ExecuteMsg::CreateCompetition {
+                host: None,
+                category_id: league.category_id,
+                escrow: EscrowContractInfo::Existing {
+                    addr: league.escrow.to_string(),
+                    additional_layered_fees: league.fees.as_ref().map(|fees| {
+                        fees.iter()
+                            .map(|fee| FeeInformation {
+                                tax: fee.tax,
+                                receiver: fee.receiver.to_string(),
+                                cw20_msg: fee.cw20_msg.clone(),
+                                cw721_msg: fee.cw721_msg.clone(),
+                            })
+                            .collect()
+                    }),
+                },
+                name: format!("{} Playoffs", league.name),
+                description: league.description.clone(),
+                date: env.block.time,
+                duration: playoffs.duration,
+                rules: CompetitionModule::default()
+                    .competition_rules
+                    .may_load(deps.storage, league.id.u128())?,
+                rulesets: league.rulesets.clone(),
+                banner: league.banner.clone(),
+                group_contract: GroupContractInfo::New {
+                    info: ModuleInstantiateInfo {
+                        code_id: group_code_id,
+                        msg: to_json_binary(&group::InstantiateMsg {
+                            members: Some(
+                                qualifiers
+                                    .iter()
+                                    .enumerate()
+                                    .map(|(i, x)| group::AddMemberMsg {
+                                        addr: x.to_string(),
+                                        seed: Some(Uint64::new(i as u64 + 1)),
+                                    })
+                                    .collect(),
+                            ),
+                        })?,
+                        admin: None,
+                        funds: vec![],
+                        label: format!("{} Playoffs", league.name),
+                    },
+                },
+                instantiate_extension: TournamentInstantiateExt {
+                    elimination_type: playoffs.elimination_type.clone(),
+                    distribution: playoffs.distribution.clone(),
+                    series: None,
+                    carried_distribution: if carried_distribution.is_empty() {
+                        None
+                    } else {
+                        Some(carried_distribution)
+                    },
+                    // The group is created with the qualifiers' leaderboard order as seeds
+                    seeding: None,
+                    placements: None,
+                    bracket_reset: None,
+                },
+            },
+        )?,
+        funds: vec![],
+    });
+
+    Ok(Response::new()
+        .add_attribute("action", "start_playoffs")
+        .add_attribute("tournament_id", tournament_id)
+        .add_message(lock_msg)
+        .add_message(create_msg))
+}
+
+/// Forwards playoff match results to the tournament module, where the module is the playoffs' host.
+pub fn process_playoff_matches(
+    deps: DepsMut,
+    info: MessageInfo,
+    league_id: Uint128,
+    match_results: Vec<PlayoffMatchResultMsg>,
+) -> Result<Response, ContractError> {
+    let league = CompetitionModule::default()
+        .competitions
+        .load(deps.storage, league_id.u128())?;
+    if info.sender != league.host && info.sender != league.admin_dao {
+        return Err(ContractError::Unauthorized {});
     }
+
+    let playoff_tournament = PLAYOFF_TOURNAMENTS
+        .may_load(deps.storage, league_id.u128())?
+        .ok_or_else(|| StdError::generic_err("The league's playoffs have not started"))?;
+
+    Ok(Response::new()
+        .add_attribute("action", "process_playoff_matches")
+        .add_attribute("tournament_id", playoff_tournament.tournament_id)
+        .add_message(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: playoff_tournament.module.to_string(),
+            msg: to_json_binary(&arena_tournament_module::msg::ExecuteMsg::Extension {
+                msg: arena_tournament_module::msg::ExecuteExt::ProcessMatch {
+                    tournament_id: playoff_tournament.tournament_id,
+                    match_results,
+                },
+            })?,
+            funds: vec![],
+        })))
 }
 
 pub fn update_distribution(
@@ -496,3 +1122,240 @@ pub fn add_point_adjustments(
 
     Ok(Response::new().add_attribute("action", "add_point_adjustments"))
 }
+
+/// Links existing leagues as the divisions of a new season.
+///
+/// # Details
+/// - Divisions are ordered from the top division down, and a league can only belong to one season.
+/// - Every division must have enough teams for its promotions and relegations.
+pub fn create_season(
+    deps: DepsMut,
+    info: MessageInfo,
+    name: String,
+    divisions: Vec<Uint128>,
+    promotions: Uint64,
+    relegations: Uint64,
+) -> Result<Response, ContractError> {
+    if divisions.is_empty() {
+        return Err(ContractError::StdError(StdError::generic_err(
+            "A season must have at least 1 division",
+        )));
+    }
+
+    let season_id = SEASON_COUNT
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        .checked_add(Uint128::one())?;
+    let season = Season {
+        id: season_id,
+        host: info.sender,
+        name,
+        divisions,
+        promotions,
+        relegations,
+        previous_season: None,
+        next_season: None,
+    };
+
+    let league_module = CompetitionModule::default();
+    for (i, league_id) in season.divisions.iter().enumerate() {
+        if season.divisions[..i].contains(league_id) {
+            return Err(ContractError::StdError(StdError::generic_err(
+                "Divisions must be unique",
+            )));
+        }
+
+        let league = league_module
+            .competitions
+            .load(deps.storage, league_id.u128())?;
+        if league.host != season.host {
+            return Err(ContractError::Unauthorized {});
+        }
+        if LEAGUE_SEASONS.has(deps.storage, league_id.u128()) {
+            return Err(ContractError::StdError(StdError::generic_err(format!(
+                "League {} already belongs to a season",
+                league_id
+            ))));
+        }
+
+        let (promotions, relegations) = season.division_movements(i);
+        if (promotions + relegations) as u64 > league.extension.teams.u64() {
+            return Err(ContractError::StdError(StdError::generic_err(format!(
+                "League {} does not have enough teams for its promotions and relegations",
+                league_id
+            ))));
+        }
+    }
+
+    SEASON_COUNT.save(deps.storage, &season_id)?;
+    for league_id in season.divisions.iter() {
+        LEAGUE_SEASONS.save(deps.storage, league_id.u128(), &season_id.u128())?;
+    }
+    SEASONS.save(deps.storage, season_id.u128(), &season)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "create_season")
+        .add_attribute("season_id", season_id))
+}
+
+/// Creates the next season from a season whose divisions have all been processed.
+///
+/// # Details
+/// - Each division's members are calculated by `query::season_movements`.
+/// - A division is created per self-message, so each league's group and rounds are instantiated before the next.
+pub fn create_next_season(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    season_id: Uint128,
+    name: String,
+    group_code_id: u64,
+    divisions: Vec<SeasonDivisionMsg>,
+) -> Result<Response, ContractError> {
+    let mut season = SEASONS.load(deps.storage, season_id.u128())?;
+    if season.host != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    if season.next_season.is_some() {
+        return Err(ContractError::StdError(StdError::generic_err(
+            "The next season has already been created",
+        )));
+    }
+    if divisions.len() != season.divisions.len() {
+        return Err(ContractError::StdError(StdError::generic_err(format!(
+            "Expected {} divisions",
+            season.divisions.len()
+        ))));
+    }
+
+    let movements = query::season_movements(deps.as_ref(), season_id)?;
+
+    // The next season keeps the promotions and relegations, so each new division must still be large enough for them
+    for (i, movement) in movements.iter().enumerate() {
+        let (promotions, relegations) = season.division_movements(i);
+        if promotions + relegations > movement.next_members.len() {
+            return Err(ContractError::StdError(StdError::generic_err(format!(
+                "Division {} of the next season does not have enough teams for its promotions and relegations",
+                i + 1
+            ))));
+        }
+    }
+
+    let next_season_id = SEASON_COUNT
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        .checked_add(Uint128::one())?;
+    SEASON_COUNT.save(deps.storage, &next_season_id)?;
+    SEASONS.save(
+        deps.storage,
+        next_season_id.u128(),
+        &Season {
+            id: next_season_id,
+            host: season.host.clone(),
+            name,
+            divisions: vec![],
+            promotions: season.promotions,
+            relegations: season.relegations,
+            previous_season: Some(season_id),
+            next_season: None,
+        },
+    )?;
+    season.next_season = Some(next_season_id);
+    SEASONS.save(deps.storage, season_id.u128(), &season)?;
+
+    let msgs = divisions
+        .into_iter()
+        .zip(movements)
+        .map(|(division, movement)| {
+            let group_contract = GroupContractInfo::New {
+                info: ModuleInstantiateInfo {
+                    code_id: group_code_id,
+                    msg: to_json_binary(&group::InstantiateMsg {
+                        members: Some(
+                            movement
+                                .next_members
+                                .into_iter()
+                                .map(|x| group::AddMemberMsg {
+                                    addr: x.to_string(),
+                                    seed: None,
+                                })
+                                .collect(),
+                        ),
+                    })?,
+                    admin: None,
+                    funds: vec![],
+                    label: division.name.clone(),
+                },
+            };
+
+            Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: env.contract.address.to_string(),
+                msg: to_json_binary(&ExecuteMsg::Extension {
+                    msg: ExecuteExt::InstantiateDivision {
+                        season_id: next_season_id,
+                        group_contract,
+                        division: Box::new(division),
+                    },
+                })?,
+                funds: vec![],
+            }))
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(Response::new()
+        .add_attribute("action", "create_next_season")
+        .add_attribute("season_id", next_season_id)
+        .add_messages(msgs))
+}
+
+/// Creates a division of the next season on behalf of the season's host, and links it to the season.
+pub fn instantiate_division(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    season_id: Uint128,
+    group_contract: GroupContractInfo,
+    division: SeasonDivisionMsg,
+) -> Result<Response, ContractError> {
+    ensure_eq!(
+        info.sender,
+        env.contract.address,
+        ContractError::Unauthorized {}
+    );
+
+    let league_module = CompetitionModule::default();
+    let mut season = SEASONS.load(deps.storage, season_id.u128())?;
+
+    let response = league_module.execute_create_competition(
+        &mut deps,
+        &env,
+        &info,
+        Some(season.host.to_string()),
+        division.category_id,
+        division.escrow,
+        division.name,
+        division.description,
+        division.date,
+        division.duration,
+        division.rules,
+        division.rulesets,
+        division.banner,
+        group_contract,
+        division.instantiate_extension,
+    )?;
+
+    let league_id = league_module.competition_count.load(deps.storage)?;
+    season.divisions.push(league_id);
+    SEASONS.save(deps.storage, season_id.u128(), &season)?;
+    LEAGUE_SEASONS.save(deps.storage, league_id.u128(), &season_id.u128())?;
+
+    Ok(response
+        .add_attribute("season_id", season_id)
+        .add_message(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: env.contract.address.to_string(),
+            msg: to_json_binary(&ExecuteMsg::Extension {
+                msg: ExecuteExt::InstantiateRounds {},
+            })?,
+            funds: vec![],
+        })))
+}
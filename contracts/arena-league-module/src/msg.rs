@@ -1,13 +1,22 @@
-use crate::state::{LeagueExt, Match, MatchResult, PointAdjustment};
+use crate::state::{
+    LeagueExt, LeaguePlayoffs, LeagueTiebreaker, Match, MatchResult, PlayoffTournament,
+    PointAdjustment, Season,
+};
 use arena_interface::{
     competition::{
-        msg::{ExecuteBase, InstantiateBase, MigrateBase, QueryBase, ToCompetitionExt},
-        state::{Competition, CompetitionResponse},
+        msg::{
+            EscrowContractInfo, ExecuteBase, InstantiateBase, MigrateBase, QueryBase,
+            ToCompetitionExt,
+        },
+        state::{Competition, CompetitionResponse, MatchDispute},
     },
-    group,
+    group::{self, GroupContractInfo},
 };
+use arena_tournament_module::state::EliminationType;
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Decimal, Empty, Int128, StdError, StdResult, Uint128, Uint64};
+use cosmwasm_std::{Addr, Decimal, Empty, Int128, StdError, StdResult, Timestamp, Uint128, Uint64};
+
+pub use arena_tournament_module::msg::MatchResultMsg as PlayoffMatchResultMsg;
 
 #[cw_serde]
 #[derive(cw_orch::ExecuteFns)]
@@ -19,6 +28,39 @@ pub enum ExecuteExt {
         round_number: Uint64,
         match_results: Vec<MatchResultMsg>,
     },
+    /// Corrects a processed match's result while the league is still active
+    CorrectMatch {
+        league_id: Uint128,
+        round_number: Uint64,
+        match_number: Uint128,
+        match_result: MatchResult,
+    },
+    /// Disputes a match that the sender plays in, and proposes its result to the arena DAO
+    /// Only the match is frozen, but the league's final results wait for its disputes to be resolved
+    #[cw_orch(payable)]
+    DisputeMatch {
+        league_id: Uint128,
+        round_number: Uint64,
+        match_number: Uint128,
+        match_result: MatchResult,
+        title: String,
+        description: String,
+        evidence: Vec<String>,
+    },
+    /// Callable only by the arena DAO to decide a disputed match's result
+    ResolveDispute {
+        league_id: Uint128,
+        round_number: Uint64,
+        match_number: Uint128,
+        match_result: MatchResult,
+    },
+    /// Dismisses a disputed match, such as after its proposal was rejected, and keeps the match's current result
+    /// Callable by the host or the arena DAO
+    DismissDispute {
+        league_id: Uint128,
+        round_number: Uint64,
+        match_number: Uint128,
+    },
     UpdateDistribution {
         league_id: Uint128,
         distribution: Vec<Decimal>,
@@ -28,6 +70,46 @@ pub enum ExecuteExt {
         addr: String,
         point_adjustments: Vec<PointAdjustment>,
     },
+    /// Reports the sender's team for a scheduled match before its round's deadline
+    ReportMatch {
+        league_id: Uint128,
+        round_number: Uint64,
+        match_number: Uint128,
+    },
+    /// Records forfeit losses for the teams that did not report for a round's unprocessed matches
+    /// Unprocessed matches that both teams reported are recorded as draws
+    /// This can be called by anyone after the round's deadline
+    ProcessForfeits {
+        league_id: Uint128,
+        round_number: Uint64,
+    },
+    /// Links existing leagues as the divisions of a new season, ordered from the top division down
+    /// The sender must be the host of every division
+    CreateSeason {
+        name: String,
+        divisions: Vec<Uint128>,
+        promotions: Uint64,
+        relegations: Uint64,
+    },
+    /// Creates the next season's divisions after every division of the season has been processed
+    /// Each division's group is instantiated from `group_code_id` with the members after promotions and relegations
+    CreateNextSeason {
+        season_id: Uint128,
+        name: String,
+        group_code_id: u64,
+        divisions: Vec<SeasonDivisionMsg>,
+    },
+    /// Processes the playoff tournament's matches, which are hosted by the module on the tournament module
+    ProcessPlayoffMatch {
+        league_id: Uint128,
+        match_results: Vec<PlayoffMatchResultMsg>,
+    },
+    /// Callable only by the module to create a division when creating the next season
+    InstantiateDivision {
+        season_id: Uint128,
+        group_contract: GroupContractInfo,
+        division: Box<SeasonDivisionMsg>,
+    },
 }
 
 impl From<ExecuteExt> for ExecuteMsg {
@@ -36,6 +118,21 @@ impl From<ExecuteExt> for ExecuteMsg {
     }
 }
 
+/// The competition info for a division of the next season, ordered the same as the previous season's divisions
+#[cw_serde]
+pub struct SeasonDivisionMsg {
+    pub category_id: Option<Uint128>,
+    pub escrow: EscrowContractInfo,
+    pub name: String,
+    pub description: String,
+    pub date: Timestamp,
+    pub duration: u64,
+    pub rules: Option<Vec<String>>,
+    pub rulesets: Option<Vec<Uint128>>,
+    pub banner: Option<String>,
+    pub instantiate_extension: LeagueInstantiateExt,
+}
+
 #[cw_serde]
 pub struct MatchResultMsg {
     pub match_number: Uint128,
@@ -66,6 +163,19 @@ pub enum LeagueQueryExt {
         league_id: Uint128,
         round_number: Uint64,
     },
+    #[returns(Option<PlayoffTournament>)]
+    PlayoffTournament { league_id: Uint128 },
+    #[returns(Option<MatchDispute>)]
+    MatchDispute {
+        league_id: Uint128,
+        round_number: Uint64,
+        match_number: Uint128,
+    },
+    #[returns(Season)]
+    Season { season_id: Uint128 },
+    /// The promotions and relegations of each division, once every division has been processed
+    #[returns(Vec<DivisionMovement>)]
+    SeasonMovements { season_id: Uint128 },
 }
 
 impl From<LeagueQueryExt> for QueryMsg {
@@ -85,6 +195,10 @@ pub enum MigrateMsg {
 pub struct SudoMsg {
     pub member_points: MemberPoints,
     pub round_response: RoundResponse,
+    pub match_dispute: MatchDispute,
+    pub playoff_tournament: PlayoffTournament,
+    pub season: Season,
+    pub division_movement: DivisionMovement,
 }
 
 #[cw_serde]
@@ -93,6 +207,18 @@ pub struct LeagueInstantiateExt {
     pub match_draw_points: Uint64,
     pub match_lose_points: Uint64,
     pub distribution: Vec<Decimal>,
+    /// The seconds between each round's scheduled date, starting from the competition's date
+    /// If set, teams that do not report for a match before the end of its round can be forfeited
+    pub round_duration: Option<u64>,
+    /// The number of times each pairing is played, with home and away swapped between legs
+    /// Defaults to a single round robin
+    pub legs: Option<u8>,
+    /// The ordered tiebreakers applied to members with equal points, in both the leaderboard and the payout
+    /// Defaults to the stat priorities
+    pub tiebreakers: Option<Vec<LeagueTiebreaker>>,
+    /// A playoff tournament created on the tournament module from the final leaderboard
+    /// The escrow is carried over to the playoffs, which pay out both the regular season and playoff shares
+    pub playoffs: Option<LeaguePlayoffs>,
 }
 
 impl ToCompetitionExt<LeagueExt> for LeagueInstantiateExt {
@@ -118,13 +244,88 @@ impl ToCompetitionExt<LeagueExt> for LeagueInstantiateExt {
         if self.distribution.iter().sum::<Decimal>() != Decimal::one() {
             return Err(StdError::generic_err("The distribution must sum up to 1"));
         }
+        if let Some(tiebreakers) = &self.tiebreakers {
+            if (1..tiebreakers.len()).any(|i| tiebreakers[..i].contains(&tiebreakers[i])) {
+                return Err(StdError::generic_err("Tiebreakers must be unique"));
+            }
+        }
+        if self.legs == Some(0) {
+            return Err(StdError::generic_err("At least 1 leg should be played"));
+        }
+        if self.round_duration == Some(0) {
+            return Err(StdError::generic_err(
+                "The round duration must be greater than 0",
+            ));
+        }
+
+        if let Some(playoffs) = &self.playoffs {
+            if playoffs.qualifiers < Uint64::new(2) || playoffs.qualifiers > team_count {
+                return Err(StdError::generic_err(
+                    "The playoff qualifiers must be between 2 and the teams size",
+                ));
+            }
+            if playoffs.duration == 0 {
+                return Err(StdError::generic_err(
+                    "The playoff duration must be greater than 0",
+                ));
+            }
+            if playoffs.regular_season_share > Decimal::one() {
+                return Err(StdError::generic_err(
+                    "The regular season share cannot be more than 1",
+                ));
+            }
+            // The regular season prizes are paid by the playoffs, so they must go to qualifiers
+            if Uint64::new(self.distribution.len() as u64) > playoffs.qualifiers {
+                return Err(StdError::generic_err(
+                    "Cannot have a distribution size bigger than the playoff qualifiers",
+                ));
+            }
+
+            let max_placements = match playoffs.elimination_type {
+                EliminationType::SingleElimination {
+                    play_third_place_match,
+                } => {
+                    if play_third_place_match {
+                        if playoffs.qualifiers < Uint64::new(4) {
+                            return Err(StdError::generic_err(
+                                "At least 4 members should qualify for a 3rd place match",
+                            ));
+                        }
+
+                        Uint64::new(4)
+                    } else {
+                        Uint64::new(2)
+                    }
+                }
+                EliminationType::DoubleElimination => {
+                    Uint64::min(playoffs.qualifiers, Uint64::new(3))
+                }
+                EliminationType::RoundRobinGroups { .. } => {
+                    return Err(StdError::generic_err(
+                        "The playoffs cannot have a group stage",
+                    ));
+                }
+            };
+            if Uint64::new(playoffs.distribution.len() as u64) > max_placements {
+                return Err(StdError::generic_err(
+                    "Cannot have a playoff distribution size bigger than the possible placements",
+                ));
+            }
+            if playoffs.distribution.iter().sum::<Decimal>() != Decimal::one() {
+                return Err(StdError::generic_err(
+                    "The playoff distribution must sum up to 1",
+                ));
+            }
+        }
 
-        let matches = team_count * (team_count - Uint64::one()) / Uint64::new(2);
+        let legs = self.legs.unwrap_or(1);
+        let matches =
+            team_count * (team_count - Uint64::one()) / Uint64::new(2) * Uint64::from(legs);
         let rounds = if team_count.u64() % 2 == 0 {
             team_count - Uint64::one()
         } else {
             team_count
-        };
+        } * Uint64::from(legs);
 
         Ok(LeagueExt {
             match_win_points: self.match_win_points,
@@ -135,6 +336,13 @@ impl ToCompetitionExt<LeagueExt> for LeagueInstantiateExt {
             matches: matches.into(),
             processed_matches: Uint128::zero(),
             distribution: self.distribution.clone(),
+            round_duration: self.round_duration,
+            legs,
+            tiebreakers: self
+                .tiebreakers
+                .clone()
+                .unwrap_or(vec![LeagueTiebreaker::StatPriority]),
+            playoffs: self.playoffs.clone(),
         })
     }
 }
@@ -144,12 +352,41 @@ pub struct MemberPoints {
     pub member: Addr,
     pub points: Int128,
     pub matches_played: Uint64,
+    pub wins: Uint64,
+    pub losses: Uint64,
+    pub forfeits: Uint64,
+    /// How this member was placed relative to the member above them
+    /// This is None for the first member
+    pub decided_by: Option<PlacementDecider>,
+    /// The record from matches played as the home team, excluding point adjustments
+    pub home: HomeAwayRecord,
+    /// The record from matches played as the away team, excluding point adjustments
+    pub away: HomeAwayRecord,
+}
+
+#[cw_serde]
+pub enum PlacementDecider {
+    Points,
+    Tiebreaker {
+        tiebreaker: LeagueTiebreaker,
+    },
+    /// Every tiebreaker was equal, so the members share the placement
+    Tied,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct HomeAwayRecord {
+    pub points: Int128,
+    pub matches_played: Uint64,
 }
 
 #[cw_serde]
 pub struct RoundResponse {
     pub round_number: Uint64,
     pub matches: Vec<Match>,
+    pub scheduled_date: Option<Timestamp>,
+    pub deadline: Option<Timestamp>,
 }
 
 #[cw_serde]
@@ -158,6 +395,17 @@ pub struct PointAdjustmentResponse {
     pub point_adjustments: Vec<PointAdjustment>,
 }
 
+#[cw_serde]
+pub struct DivisionMovement {
+    pub league_id: Uint128,
+    /// The members moving up to the division above
+    pub promoted: Vec<Addr>,
+    /// The members moving down to the division below
+    pub relegated: Vec<Addr>,
+    /// The division's members for the next season
+    pub next_members: Vec<Addr>,
+}
+
 #[cw_serde]
 pub struct DumpStateResponse {
     pub leaderboard: Vec<MemberPoints>,
@@ -1,12 +1,23 @@
-use std::{cmp::Reverse, collections::BTreeMap};
+use std::{cmp::Ordering, collections::BTreeMap};
 
 use crate::{
     contract::CompetitionModule,
-    msg::{DumpStateResponse, MemberPoints, PointAdjustmentResponse, RoundResponse},
-    state::{Match, MatchResult, MATCHES, POINT_ADJUSTMENTS, ROUNDS},
+    msg::{
+        DivisionMovement, DumpStateResponse, HomeAwayRecord, League, MemberPoints,
+        PlacementDecider, PointAdjustmentResponse, RoundResponse,
+    },
+    state::{
+        LeagueExt, LeagueTiebreaker, Match, MatchResult, PlayoffTournament, Season, MATCHES,
+        MATCH_DISPUTES, PLAYOFF_TOURNAMENTS, POINT_ADJUSTMENTS, ROUNDS, SEASONS,
+    },
 };
-use cosmwasm_std::{Addr, Deps, Int128, Order, StdResult, Uint128, Uint64};
+use arena_interface::competition::{
+    state::{CompetitionStatus, MatchDispute},
+    stats::{StatType, StatValue},
+};
+use cosmwasm_std::{Addr, Deps, Int128, Order, StdError, StdResult, Uint128, Uint64};
 use cw_storage_plus::Bound;
+use sha2::{Digest, Sha256};
 
 /// Calculates and returns the leaderboard for a specific league.
 ///
@@ -17,15 +28,17 @@ use cw_storage_plus::Bound;
 ///
 /// # Returns
 /// Returns a `StdResult` containing a `Vec<MemberPoints>`. On success, the vector contains
-/// `MemberPoints` structs for each member in the league, sorted in descending order by points.
+/// `MemberPoints` structs for each member in the league, sorted by placement.
 ///
 /// # Details
 /// - The function calculates points based on match results and any point adjustments.
 /// - It processes all rounds up to `round_number` if specified, or all rounds if None.
 /// - Match results are processed in descending order within each round.
+/// - A forfeiting team receives the lose points, and its opponent receives the win points unless it also forfeited.
+/// - Each match's points are also recorded in the home team's home record and the away team's away record.
 /// - Point adjustments are applied after processing all matches.
-/// - The final leaderboard is sorted by points (highest to lowest).
-/// - In case of ties (equal points), no additional tie-breaking mechanism is applied.
+/// - The final leaderboard is sorted by points (highest to lowest), then by the league's tiebreakers in order.
+/// - Each member records what placed them below the previous member, which is the same ordering used for the payout.
 ///
 /// # Errors
 /// This function will return an error if:
@@ -36,9 +49,6 @@ use cw_storage_plus::Bound;
 /// # Performance
 /// - Time complexity: O(m log m + n log n), where m is the number of matches and n is the number of members
 /// - Space complexity: O(n) for storing the leaderboard
-///
-/// # Note
-/// Tie breaking is handled in conjunction with this query
 pub fn leaderboard(
     deps: Deps,
     league_id: Uint128,
@@ -50,7 +60,9 @@ pub fn leaderboard(
         .load(deps.storage, league_id.u128())?;
 
     // Initialize leaderboard map
-    let mut leaderboard: BTreeMap<Addr, (Int128, Uint64)> = BTreeMap::new();
+    let mut leaderboard: BTreeMap<Addr, MemberPoints> = BTreeMap::new();
+    // The points each team earned in each match, used for the head-to-head tiebreaker
+    let mut match_points: Vec<[(Addr, Int128); 2]> = vec![];
 
     // Determine the range of rounds to process
     let end_bound = round_number.map(|x| Bound::inclusive(x.u64()));
@@ -73,36 +85,50 @@ pub fn leaderboard(
                 (league_id.u128(), round.round_number.u64(), match_key),
             )?;
 
-            if let Some(match_result) = m.result {
-                let (winner, loser) = match match_result {
-                    MatchResult::Team1 => (m.team_1, m.team_2),
-                    MatchResult::Team2 => (m.team_2, m.team_1),
-                    MatchResult::Draw => {
-                        update_leaderboard(
-                            &mut leaderboard,
-                            m.team_1,
-                            league.extension.match_draw_points.into(),
-                        )?;
-                        update_leaderboard(
-                            &mut leaderboard,
-                            m.team_2,
-                            league.extension.match_draw_points.into(),
-                        )?;
-                        continue;
+            let Some(match_result) = m.result else {
+                continue;
+            };
+
+            let (team_1_outcome, team_2_outcome) = if !m.forfeits.is_empty() {
+                let outcome = |team: &Addr| {
+                    if m.forfeits.contains(team) {
+                        Outcome::Forfeit
+                    } else {
+                        Outcome::Win
                     }
                 };
 
-                update_leaderboard(
-                    &mut leaderboard,
-                    winner,
-                    league.extension.match_win_points.into(),
-                )?;
-                update_leaderboard(
-                    &mut leaderboard,
-                    loser,
-                    league.extension.match_lose_points.into(),
-                )?;
-            }
+                (outcome(&m.team_1), outcome(&m.team_2))
+            } else {
+                match match_result {
+                    MatchResult::Team1 => (Outcome::Win, Outcome::Loss),
+                    MatchResult::Team2 => (Outcome::Loss, Outcome::Win),
+                    MatchResult::Draw => (Outcome::Draw, Outcome::Draw),
+                    MatchResult::DoubleForfeit => (Outcome::Forfeit, Outcome::Forfeit),
+                }
+            };
+
+            let team_1_points = team_1_outcome.points(&league.extension);
+            let team_2_points = team_2_outcome.points(&league.extension);
+            match_points.push([
+                (m.team_1.clone(), team_1_points),
+                (m.team_2.clone(), team_2_points),
+            ]);
+
+            update_leaderboard(
+                &mut leaderboard,
+                m.team_1,
+                team_1_points,
+                team_1_outcome,
+                true,
+            )?;
+            update_leaderboard(
+                &mut leaderboard,
+                m.team_2,
+                team_2_points,
+                team_2_outcome,
+                false,
+            )?;
         }
     }
 
@@ -114,38 +140,237 @@ pub fn leaderboard(
     {
         let (addr, adjustments) = point_adjustment?;
         if let Some(record) = leaderboard.get_mut(&addr) {
-            record.0 += adjustments.iter().map(|x| x.amount).sum::<Int128>();
+            record.points += adjustments.iter().map(|x| x.amount).sum::<Int128>();
         }
     }
 
-    // Convert to Vec, sort by points (descending), and map to MemberPoints
-    let mut sorted_leaderboard: Vec<MemberPoints> = leaderboard
-        .into_iter()
-        .map(|(member, (points, matches_played))| MemberPoints {
-            member,
-            points,
-            matches_played,
-        })
-        .collect();
+    // Sort by points (descending), then by the tiebreakers
+    let tiebreaks = Tiebreaks::new(deps, &league, &leaderboard, &match_points)?;
+    let mut sorted_leaderboard: Vec<MemberPoints> = leaderboard.into_values().collect();
 
-    sorted_leaderboard.sort_unstable_by_key(|mp| Reverse(mp.points));
+    sorted_leaderboard.sort_by(|a, b| tiebreaks.compare(a, b).0);
+
+    for i in 1..sorted_leaderboard.len() {
+        let (_, decided_by) = tiebreaks.compare(&sorted_leaderboard[i - 1], &sorted_leaderboard[i]);
+        sorted_leaderboard[i].decided_by = Some(decided_by);
+    }
 
     Ok(sorted_leaderboard)
 }
 
+enum Outcome {
+    Win,
+    Draw,
+    Loss,
+    Forfeit,
+}
+
+impl Outcome {
+    fn points(&self, league: &LeagueExt) -> Int128 {
+        match self {
+            Outcome::Win => league.match_win_points,
+            Outcome::Draw => league.match_draw_points,
+            Outcome::Loss | Outcome::Forfeit => league.match_lose_points,
+        }
+        .into()
+    }
+}
+
 fn update_leaderboard(
-    leaderboard: &mut BTreeMap<Addr, (Int128, Uint64)>,
+    leaderboard: &mut BTreeMap<Addr, MemberPoints>,
     team: Addr,
     points: Int128,
+    outcome: Outcome,
+    is_home: bool,
 ) -> StdResult<()> {
     let record = leaderboard
-        .entry(team)
-        .or_insert((Int128::zero(), Uint64::zero()));
-    record.0 = record.0.checked_add(points)?;
-    record.1 = record.1.checked_add(Uint64::one())?;
+        .entry(team.clone())
+        .or_insert_with(|| MemberPoints {
+            member: team,
+            points: Int128::zero(),
+            matches_played: Uint64::zero(),
+            wins: Uint64::zero(),
+            losses: Uint64::zero(),
+            forfeits: Uint64::zero(),
+            decided_by: None,
+            home: HomeAwayRecord::default(),
+            away: HomeAwayRecord::default(),
+        });
+    record.points = record.points.checked_add(points)?;
+    record.matches_played = record.matches_played.checked_add(Uint64::one())?;
+    match outcome {
+        Outcome::Win => record.wins = record.wins.checked_add(Uint64::one())?,
+        Outcome::Draw => {}
+        Outcome::Loss => record.losses = record.losses.checked_add(Uint64::one())?,
+        Outcome::Forfeit => {
+            record.losses = record.losses.checked_add(Uint64::one())?;
+            record.forfeits = record.forfeits.checked_add(Uint64::one())?;
+        }
+    }
+
+    let side = if is_home {
+        &mut record.home
+    } else {
+        &mut record.away
+    };
+    side.points = side.points.checked_add(points)?;
+    side.matches_played = side.matches_played.checked_add(Uint64::one())?;
     Ok(())
 }
 
+/// The values used to order members with equal points
+struct Tiebreaks<'a> {
+    tiebreakers: &'a [LeagueTiebreaker],
+    head_to_head: BTreeMap<Addr, Int128>,
+    stat_types: Vec<StatType>,
+    stats: BTreeMap<Addr, Vec<Option<StatValue>>>,
+    coin_flips: BTreeMap<Addr, [u8; 32]>,
+}
+
+impl<'a> Tiebreaks<'a> {
+    fn new(
+        deps: Deps,
+        league: &'a League,
+        leaderboard: &BTreeMap<Addr, MemberPoints>,
+        match_points: &[[(Addr, Int128); 2]],
+    ) -> StdResult<Self> {
+        let tiebreakers = &league.extension.tiebreakers;
+        let mut tiebreaks = Tiebreaks {
+            tiebreakers,
+            head_to_head: BTreeMap::new(),
+            stat_types: vec![],
+            stats: BTreeMap::new(),
+            coin_flips: BTreeMap::new(),
+        };
+
+        if tiebreakers.contains(&LeagueTiebreaker::HeadToHead) {
+            // Only matches between members with equal points count
+            for [(team_1, team_1_points), (team_2, team_2_points)] in match_points {
+                if leaderboard[team_1].points == leaderboard[team_2].points {
+                    *tiebreaks.head_to_head.entry(team_1.clone()).or_default() += *team_1_points;
+                    *tiebreaks.head_to_head.entry(team_2.clone()).or_default() += *team_2_points;
+                }
+            }
+        }
+
+        if tiebreakers.contains(&LeagueTiebreaker::StatPriority) {
+            let competition_module = CompetitionModule::default();
+            let mut stat_types: Vec<StatType> = competition_module
+                .stat_types
+                .prefix(league.id.u128())
+                .range(deps.storage, None, None, Order::Ascending)
+                .map(|x| x.map(|y| y.1))
+                .collect::<StdResult<_>>()?;
+            stat_types.sort_by_key(|x| x.tie_breaker_priority.unwrap_or(u8::MAX));
+
+            for member in leaderboard.keys() {
+                let values = stat_types
+                    .iter()
+                    .map(|stat_type| match &stat_type.aggregation_type {
+                        Some(_) => competition_module
+                            .inner_aggregate(deps, league.id, member, stat_type)
+                            .ok(),
+                        None => competition_module
+                            .stats
+                            .may_load(deps.storage, (league.id.u128(), member, &stat_type.name))
+                            .ok()
+                            .flatten(),
+                    })
+                    .collect();
+                tiebreaks.stats.insert(member.clone(), values);
+            }
+            tiebreaks.stat_types = stat_types;
+        }
+
+        if tiebreakers.contains(&LeagueTiebreaker::CoinFlip) {
+            for member in leaderboard.keys() {
+                let seed = format!("{}{}{}", league.id, league.start_height, member);
+                tiebreaks
+                    .coin_flips
+                    .insert(member.clone(), Sha256::digest(seed.as_bytes()).into());
+            }
+        }
+
+        Ok(tiebreaks)
+    }
+
+    /// Orders members by points, then by each tiebreaker in order, and returns what decided the order
+    fn compare(&self, a: &MemberPoints, b: &MemberPoints) -> (Ordering, PlacementDecider) {
+        let cmp = b.points.cmp(&a.points);
+        if cmp != Ordering::Equal {
+            return (cmp, PlacementDecider::Points);
+        }
+
+        for tiebreaker in self.tiebreakers {
+            let cmp = match tiebreaker {
+                LeagueTiebreaker::HeadToHead => {
+                    let head_to_head = |x: &MemberPoints| {
+                        self.head_to_head
+                            .get(&x.member)
+                            .copied()
+                            .unwrap_or_default()
+                    };
+                    head_to_head(b).cmp(&head_to_head(a))
+                }
+                LeagueTiebreaker::Wins => b.wins.cmp(&a.wins),
+                LeagueTiebreaker::MatchDifferential => {
+                    let differential =
+                        |x: &MemberPoints| i128::from(x.wins.u64()) - i128::from(x.losses.u64());
+                    differential(b).cmp(&differential(a))
+                }
+                LeagueTiebreaker::StatPriority => self.compare_stats(a, b),
+                LeagueTiebreaker::CoinFlip => self
+                    .coin_flips
+                    .get(&a.member)
+                    .cmp(&self.coin_flips.get(&b.member)),
+            };
+            if cmp != Ordering::Equal {
+                return (
+                    cmp,
+                    PlacementDecider::Tiebreaker {
+                        tiebreaker: tiebreaker.clone(),
+                    },
+                );
+            }
+        }
+
+        (Ordering::Equal, PlacementDecider::Tied)
+    }
+
+    fn compare_stats(&self, a: &MemberPoints, b: &MemberPoints) -> Ordering {
+        let (Some(a_stats), Some(b_stats)) = (self.stats.get(&a.member), self.stats.get(&b.member))
+        else {
+            return Ordering::Equal;
+        };
+
+        for (i, stat_type) in self.stat_types.iter().enumerate() {
+            if let (Some(a_val), Some(b_val)) = (&a_stats[i], &b_stats[i]) {
+                let cmp = compare_stat_values(a_val, b_val, stat_type.is_beneficial);
+                if cmp != Ordering::Equal {
+                    return cmp;
+                }
+            }
+        }
+
+        Ordering::Equal
+    }
+}
+
+// Helper function to compare stat values
+fn compare_stat_values(a: &StatValue, b: &StatValue, is_beneficial: bool) -> Ordering {
+    let ord = match (a, b) {
+        (StatValue::Bool(a), StatValue::Bool(b)) => a.cmp(b),
+        (StatValue::Decimal(a), StatValue::Decimal(b)) => a.cmp(b),
+        (StatValue::Uint(a), StatValue::Uint(b)) => a.cmp(b),
+        _ => Ordering::Equal,
+    };
+    if is_beneficial {
+        ord.reverse()
+    } else {
+        ord
+    }
+}
+
 pub fn round(deps: Deps, league_id: Uint128, round_number: Uint64) -> StdResult<RoundResponse> {
     ROUNDS
         .load(deps.storage, (league_id.u128(), round_number.u64()))?
@@ -188,3 +413,80 @@ pub fn dump_state(
         point_adjustments: point_adjustments(deps, league_id, None, None)?,
     })
 }
+
+pub fn playoff_tournament(deps: Deps, league_id: Uint128) -> StdResult<Option<PlayoffTournament>> {
+    PLAYOFF_TOURNAMENTS.may_load(deps.storage, league_id.u128())
+}
+
+pub fn match_dispute(
+    deps: Deps,
+    league_id: Uint128,
+    round_number: Uint64,
+    match_number: Uint128,
+) -> StdResult<Option<MatchDispute>> {
+    MATCH_DISPUTES.may_load(
+        deps.storage,
+        (league_id.u128(), round_number.u64(), match_number.u128()),
+    )
+}
+
+pub fn season(deps: Deps, season_id: Uint128) -> StdResult<Season> {
+    SEASONS.load(deps.storage, season_id.u128())
+}
+
+/// Calculates the promotions and relegations of a season from each division's final leaderboard.
+///
+/// The top `promotions` members of every division below the top move up a division,
+/// and the bottom `relegations` members of every division above the bottom move down a division.
+/// Every division must have been processed.
+pub fn season_movements(deps: Deps, season_id: Uint128) -> StdResult<Vec<DivisionMovement>> {
+    let season = SEASONS.load(deps.storage, season_id.u128())?;
+    let league_module = CompetitionModule::default();
+    let division_count = season.divisions.len();
+
+    let mut movements = vec![];
+    for (i, league_id) in season.divisions.iter().enumerate() {
+        let league = league_module
+            .competitions
+            .load(deps.storage, league_id.u128())?;
+        if !matches!(league.status, CompetitionStatus::Inactive) {
+            return Err(StdError::generic_err(format!(
+                "Division {} has not been processed",
+                league_id
+            )));
+        }
+
+        let members: Vec<Addr> = leaderboard(deps, *league_id, None)?
+            .into_iter()
+            .map(|x| x.member)
+            .collect();
+        let (promotions, relegations) = season.division_movements(i);
+        if promotions + relegations > members.len() {
+            return Err(StdError::generic_err(format!(
+                "Division {} does not have enough members for its promotions and relegations",
+                league_id
+            )));
+        }
+
+        movements.push(DivisionMovement {
+            league_id: *league_id,
+            promoted: members[..promotions].to_vec(),
+            relegated: members[members.len() - relegations..].to_vec(),
+            next_members: members[promotions..members.len() - relegations].to_vec(),
+        });
+    }
+
+    // Members dropping from the division above and rising from the division below join the remaining members
+    for i in 0..division_count {
+        if i > 0 {
+            let relegated = movements[i - 1].relegated.clone();
+            movements[i].next_members.extend(relegated);
+        }
+        if i + 1 < division_count {
+            let promoted = movements[i + 1].promoted.clone();
+            movements[i].next_members.extend(promoted);
+        }
+    }
+
+    Ok(movements)
+}
@@ -1,15 +1,29 @@
+use arena_interface::competition::state::MatchDispute;
+use arena_tournament_module::state::EliminationType;
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Decimal, Deps, Int128, StdResult, Uint128, Uint64};
-use cw_storage_plus::Map;
+use cosmwasm_std::{Addr, Decimal, Deps, Int128, StdResult, Timestamp, Uint128, Uint64};
+use cw_storage_plus::{Item, Map};
 
 use crate::msg::RoundResponse;
 
 #[cw_serde]
 pub struct Match {
     pub match_number: Uint128,
+    /// The home team
     pub team_1: Addr,
+    /// The away team
     pub team_2: Addr,
     pub result: Option<MatchResult>,
+    /// The teams that reported for the match before its round's deadline
+    #[serde(default)]
+    pub reports: Vec<Addr>,
+    /// The teams that forfeited the match by not reporting
+    /// A forfeiting team receives the lose points, and the other team receives the win points
+    #[serde(default)]
+    pub forfeits: Vec<Addr>,
+    /// The result that adjusted the teams' ratings, which is reversed if the match is corrected
+    #[serde(default)]
+    pub rated_result: Option<MatchResult>,
 }
 
 #[cw_serde]
@@ -17,12 +31,19 @@ pub enum MatchResult {
     Team1,
     Team2,
     Draw,
+    /// Neither team played the match, so both teams receive the lose points
+    DoubleForfeit,
 }
 
 #[cw_serde]
 pub struct Round {
     pub round_number: Uint64,
     pub matches: Vec<Uint128>, // A link to the Match by match_number
+    #[serde(default)]
+    pub scheduled_date: Option<Timestamp>,
+    /// Unprocessed matches can be forfeited after the deadline
+    #[serde(default)]
+    pub deadline: Option<Timestamp>,
 }
 
 impl Round {
@@ -36,6 +57,8 @@ impl Round {
         Ok(RoundResponse {
             round_number: self.round_number,
             matches,
+            scheduled_date: self.scheduled_date,
+            deadline: self.deadline,
         })
     }
 }
@@ -50,6 +73,95 @@ pub struct LeagueExt {
     pub teams: Uint64,
     pub processed_matches: Uint128,
     pub distribution: Vec<Decimal>,
+    /// The seconds between each round's scheduled date
+    #[serde(default)]
+    pub round_duration: Option<u64>,
+    /// The number of times each pairing is played, with home and away swapped between legs
+    #[serde(default = "default_legs")]
+    pub legs: u8,
+    /// The ordered tiebreakers applied to members with equal points
+    #[serde(default = "default_tiebreakers")]
+    pub tiebreakers: Vec<LeagueTiebreaker>,
+    /// The playoffs seeded from the final leaderboard once every match has a result
+    #[serde(default)]
+    pub playoffs: Option<LeaguePlayoffs>,
+}
+
+fn default_legs() -> u8 {
+    1
+}
+
+fn default_tiebreakers() -> Vec<LeagueTiebreaker> {
+    vec![LeagueTiebreaker::StatPriority]
+}
+
+#[cw_serde]
+pub enum LeagueTiebreaker {
+    /// The points earned in matches between members with equal points
+    HeadToHead,
+    /// The number of matches won
+    Wins,
+    /// The number of matches won minus the number of matches lost
+    MatchDifferential,
+    /// The league's stats, ordered by their tie breaker priority
+    StatPriority,
+    /// A coin flip seeded by the league and the member's address
+    CoinFlip,
+}
+
+#[cw_serde]
+pub struct LeaguePlayoffs {
+    pub elimination_type: EliminationType,
+    /// The number of members from the top of the final leaderboard seeded into the playoffs
+    pub qualifiers: Uint64,
+    /// The share of the prize pool paid by the league's distribution, with the rest paid by the playoffs
+    pub regular_season_share: Decimal,
+    /// The playoffs' distribution by bracket placement
+    pub distribution: Vec<Decimal>,
+    /// The playoffs' duration in seconds, which starts when the regular season's final results are processed
+    pub duration: u64,
+}
+
+/// The tournament created on the tournament module for a league's playoffs
+#[cw_serde]
+pub struct PlayoffTournament {
+    pub module: Addr,
+    pub tournament_id: Uint128,
+}
+
+/// A set of leagues played as divisions of the same season
+#[cw_serde]
+pub struct Season {
+    pub id: Uint128,
+    pub host: Addr,
+    pub name: String,
+    /// The division league ids, ordered from the top division down
+    pub divisions: Vec<Uint128>,
+    /// The number of top members moved up from each division below the top
+    pub promotions: Uint64,
+    /// The number of bottom members moved down from each division above the bottom
+    pub relegations: Uint64,
+    pub previous_season: Option<Uint128>,
+    pub next_season: Option<Uint128>,
+}
+
+impl Season {
+    /// Gets the number of members promoted and relegated from a division
+    /// The top division has no promotions, and the bottom division has no relegations
+    pub fn division_movements(&self, index: usize) -> (usize, usize) {
+        let promotions = if index == 0 {
+            0
+        } else {
+            self.promotions.u64() as usize
+        };
+        let relegations = if index + 1 == self.divisions.len() {
+            0
+        } else {
+            self.relegations.u64() as usize
+        };
+
+        (promotions, relegations)
+    }
 }
 
 #[cw_serde]
@@ -65,3 +177,11 @@ pub const MATCHES: Map<(u128, u64, u128), Match> = Map::new("matches");
 /// (League Id, Addr)
 pub const POINT_ADJUSTMENTS: Map<(u128, &Addr), Vec<PointAdjustment>> =
     Map::new("point_adjustments");
+pub const SEASON_COUNT: Item<Uint128> = Item::new("season_count");
+pub const SEASONS: Map<u128, Season> = Map::new("seasons");
+/// League Id -> Season Id
+pub const LEAGUE_SEASONS: Map<u128, u128> = Map::new("league_seasons");
+/// League Id -> The playoff tournament
+pub const PLAYOFF_TOURNAMENTS: Map<u128, PlayoffTournament> = Map::new("playoff_tournaments");
+/// (League Id, Round Number, Match Number) -> The open dispute on the match
+pub const MATCH_DISPUTES: Map<(u128, u64, u128), MatchDispute> = Map::new("match_disputes");
@@ -31,6 +31,8 @@ pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> S
         ExecuteMsg::SetDistributionRemainderSelf { member_percentages } => {
             let distribution = Distribution {
                 remainder_addr: info.sender.to_string(),
+                fixed_amounts: vec![],
+                remainder_shares: vec![],
                 member_percentages,
             };
 
@@ -0,0 +1,429 @@
+use std::collections::BTreeSet;
+
+use arena_interface::{
+    group::{self, MemberMsg},
+    ratings::MemberResult,
+};
+use cosmwasm_std::{
+    ensure_eq, Addr, Decimal, DepsMut, Env, MessageInfo, Order, Response, StdError, StdResult,
+    Storage, Uint128, Uint64,
+};
+use cw_balance::{Distribution, MemberPercentage};
+use cw_competition_base::error::CompetitionError;
+
+use crate::{
+    contract::CompetitionModule,
+    msg::{MatchResultMsg, Swiss},
+    query::{self, pair_key},
+    state::{Match, MatchResult, Round, MATCHES, ROUNDS},
+    ContractError,
+};
+
+/// Bounds the search for a pairing without rematches before falling back to pairing in order
+const MAX_PAIRING_ATTEMPTS: u32 = 10_000;
+
+pub fn instantiate_round(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    ensure_eq!(
+        info.sender,
+        env.contract.address,
+        ContractError::Unauthorized {}
+    );
+
+    let swiss_module = CompetitionModule::default();
+    let swiss_id = swiss_module.competition_count.load(deps.storage)?;
+    let swiss = swiss_module
+        .competitions
+        .load(deps.storage, swiss_id.u128())?;
+
+    // Members are returned in seed order
+    let teams: Vec<Addr> = deps
+        .querier
+        .query_wasm_smart::<Vec<MemberMsg<Addr>>>(
+            swiss.group_contract.to_string(),
+            &group::QueryMsg::Members {
+                start_after: None,
+                limit: None,
+            },
+        )?
+        .into_iter()
+        .map(|x| x.addr)
+        .collect();
+
+    // The first round pairs the top half against the bottom half
+    // The lowest seed receives the bye for an odd member count
+    let mut ordered = teams.clone();
+    let bye = if ordered.len() % 2 != 0 {
+        ordered.pop()
+    } else {
+        None
+    };
+    let (top, bottom) = ordered.split_at(ordered.len() / 2);
+    let pairings: Vec<(Addr, Addr)> = top.iter().cloned().zip(bottom.iter().cloned()).collect();
+
+    let round = save_round(deps.storage, &swiss, 1, pairings, bye)?;
+
+    let mut updated_swiss = swiss.clone();
+    updated_swiss.extension.current_round = round.round_number;
+    swiss_module.competitions.replace(
+        deps.storage,
+        swiss_id.u128(),
+        Some(&updated_swiss),
+        Some(&swiss),
+    )?;
+
+    Ok(Response::default()
+        .add_attribute("action", "instantiate_round")
+        .add_attribute("round", round.round_number.to_string())
+        .add_attribute("matches", round.matches.len().to_string())
+        .add_attribute("teams", teams.len().to_string()))
+}
+
+/// Processes match results for the current round of a Swiss competition.
+///
+/// This function performs the following key operations:
+/// 1. Validates the sender's authorization to process matches.
+/// 2. Updates the match results and tracks processed matches.
+/// 3. Prepares rating updates for matches if the competition has a category.
+/// 4. If every match in the current round has a result:
+///    a. Pairs the next round by the current standings, avoiding rematches, or
+///    b. Calculates the final placements and distribution after the last round.
+pub fn process_matches(
+    mut deps: DepsMut,
+    info: MessageInfo,
+    swiss_id: Uint128,
+    match_results: Vec<MatchResultMsg>,
+) -> Result<Response, ContractError> {
+    let swiss_module = CompetitionModule::default();
+    let swiss = swiss_module
+        .competitions
+        .load(deps.storage, swiss_id.u128())?;
+
+    // Validate state and authorization
+    swiss_module.inner_validate_auth(&info.sender, &swiss, false)?;
+
+    let round_number = swiss.extension.current_round.u64();
+    let mut processed_matches = swiss.extension.processed_matches;
+    let mut member_results = vec![];
+
+    for match_result in match_results {
+        let key = (
+            swiss_id.u128(),
+            round_number,
+            match_result.match_number.u128(),
+        );
+        MATCHES.update(deps.storage, key, |x| -> Result<_, ContractError> {
+            match x {
+                Some(mut m) => {
+                    if m.result.is_none() {
+                        processed_matches += Uint128::one();
+
+                        if swiss.category_id.is_some() {
+                            // Prepare rating updates (only handled once per match)
+                            let (member_result_1, member_result_2) = match match_result.match_result
+                            {
+                                MatchResult::Team1 => (Decimal::one(), Decimal::zero()),
+                                MatchResult::Team2 => (Decimal::zero(), Decimal::one()),
+                                MatchResult::Draw => (Decimal::percent(50), Decimal::percent(50)),
+                            };
+
+                            member_results.push((
+                                MemberResult {
+                                    addr: m.team_1.clone(),
+                                    result: member_result_1,
+                                },
+                                MemberResult {
+                                    addr: m.team_2.clone(),
+                                    result: member_result_2,
+                                },
+                            ));
+                        }
+                    }
+                    m.result = Some(match_result.match_result);
+                    Ok(m)
+                }
+                None => Err(ContractError::StdError(StdError::NotFound {
+                    kind: "Match".to_string(),
+                })),
+            }
+        })?;
+    }
+
+    // Trigger rating adjustments if applicable
+    let mut sub_msgs = vec![];
+    if let Some(category_id) = swiss.category_id {
+        if swiss_module.query_is_dao_member(deps.as_ref(), &swiss.host, swiss.start_height) {
+            sub_msgs.push(swiss_module.trigger_rating_adjustment(
+                deps.storage,
+                category_id,
+                member_results,
+            )?);
+        }
+    }
+
+    let mut updated_swiss = swiss.clone();
+    updated_swiss.extension.processed_matches = processed_matches;
+
+    let is_round_complete = MATCHES
+        .prefix((swiss_id.u128(), round_number))
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|x| x.map(|(_, m)| m.result.is_some()))
+        .collect::<StdResult<Vec<_>>>()?
+        .into_iter()
+        .all(|x| x);
+
+    let mut is_final_round_complete = false;
+    if is_round_complete {
+        if swiss.extension.current_round < swiss.extension.rounds {
+            let round = pair_next_round(deps.branch(), &updated_swiss)?;
+
+            updated_swiss.extension.current_round = round.round_number;
+        } else {
+            is_final_round_complete = true;
+        }
+    }
+
+    // Update the competition if changed
+    if updated_swiss != swiss {
+        swiss_module.competitions.replace(
+            deps.storage,
+            swiss_id.u128(),
+            Some(&updated_swiss),
+            Some(&swiss),
+        )?;
+    }
+
+    let mut response = Response::new();
+
+    // Process final results if every round has been completed
+    if is_final_round_complete {
+        response = process_final_results(deps, &updated_swiss)?;
+    }
+
+    Ok(response
+        .add_attribute("action", "process_matches")
+        .add_attribute("processed_matches", processed_matches.to_string())
+        .add_submessages(sub_msgs))
+}
+
+/// Pairs members with equal or adjacent scores against each other, avoiding rematches where possible.
+/// The lowest ranked member without a previous bye sits out if there is an odd number of members.
+fn pair_next_round(deps: DepsMut, swiss: &Swiss) -> Result<Round, ContractError> {
+    let standings = query::standings(deps.as_ref(), swiss.id, None)?;
+    let (played, byes) = query::pairing_history(deps.as_ref(), swiss.id)?;
+    let ordered: Vec<Addr> = standings.into_iter().map(|x| x.member).collect();
+
+    // Bye candidates are considered from the bottom of the standings
+    let bye_candidates: Vec<Option<usize>> = if ordered.len() % 2 != 0 {
+        let mut candidates: Vec<Option<usize>> = (0..ordered.len())
+            .rev()
+            .filter(|i| !byes.contains(&ordered[*i]))
+            .map(Some)
+            .collect();
+        if candidates.is_empty() {
+            candidates.push(Some(ordered.len() - 1));
+        }
+        candidates
+    } else {
+        vec![None]
+    };
+
+    let mut attempts = MAX_PAIRING_ATTEMPTS;
+    let mut selected = None;
+    for bye_index in &bye_candidates {
+        let remaining: Vec<Addr> = ordered
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| Some(*i) != *bye_index)
+            .map(|(_, x)| x.clone())
+            .collect();
+
+        if let Some(pairings) = find_pairings(&remaining, &played, &mut attempts) {
+            selected = Some((pairings, bye_index.map(|i| ordered[i].clone())));
+            break;
+        }
+    }
+
+    // Fall back to pairing in standings order if every pairing requires a rematch
+    let (pairings, bye) = match selected {
+        Some(selected) => selected,
+        None => {
+            let bye_index = bye_candidates[0];
+            let remaining: Vec<Addr> = ordered
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| Some(*i) != bye_index)
+                .map(|(_, x)| x.clone())
+                .collect();
+            (
+                remaining
+                    .chunks(2)
+                    .map(|x| (x[0].clone(), x[1].clone()))
+                    .collect(),
+                bye_index.map(|i| ordered[i].clone()),
+            )
+        }
+    };
+
+    Ok(save_round(
+        deps.storage,
+        swiss,
+        swiss.extension.current_round.u64() + 1,
+        pairings,
+        bye,
+    )?)
+}
+
+/// Pairs each member with the highest ranked available opponent they have not played yet
+fn find_pairings(
+    remaining: &[Addr],
+    played: &BTreeSet<(Addr, Addr)>,
+    attempts: &mut u32,
+) -> Option<Vec<(Addr, Addr)>> {
+    if remaining.is_empty() {
+        return Some(vec![]);
+    }
+    if *attempts == 0 {
+        return None;
+    }
+    *attempts -= 1;
+
+    let first = &remaining[0];
+    for i in 1..remaining.len() {
+        let opponent = &remaining[i];
+        if played.contains(&pair_key(first, opponent)) {
+            continue;
+        }
+
+        let rest: Vec<Addr> = remaining[1..]
+            .iter()
+            .enumerate()
+            .filter(|(j, _)| j + 1 != i)
+            .map(|(_, x)| x.clone())
+            .collect();
+
+        if let Some(mut pairings) = find_pairings(&rest, played, attempts) {
+            pairings.insert(0, (first.clone(), opponent.clone()));
+            return Some(pairings);
+        }
+    }
+
+    None
+}
+
+fn save_round(
+    storage: &mut dyn Storage,
+    swiss: &Swiss,
+    round_number: u64,
+    pairings: Vec<(Addr, Addr)>,
+    bye: Option<Addr>,
+) -> StdResult<Round> {
+    // Every round has the same number of matches, so match numbers stay unique across rounds
+    let matches_per_round = swiss.extension.teams.u64() as u128 / 2;
+    let first_match_number = (round_number as u128 - 1) * matches_per_round + 1;
+
+    let mut matches = vec![];
+    for (match_number, (team_1, team_2)) in (first_match_number..).zip(pairings) {
+        MATCHES.save(
+            storage,
+            (swiss.id.u128(), round_number, match_number),
+            &Match {
+                match_number: Uint128::new(match_number),
+                team_1,
+                team_2,
+                result: None,
+            },
+        )?;
+        matches.push(Uint128::new(match_number));
+    }
+
+    let round = Round {
+        round_number: Uint64::new(round_number),
+        matches,
+        bye,
+    };
+    ROUNDS.save(storage, (swiss.id.u128(), round_number), &round)?;
+
+    Ok(round)
+}
+
+fn process_final_results(deps: DepsMut, swiss: &Swiss) -> Result<Response, CompetitionError> {
+    let standings = query::standings(deps.as_ref(), swiss.id, None)?;
+    let tiebreakers = &swiss.extension.tiebreakers;
+
+    let placements = swiss.extension.distribution.len();
+    let mut placement_members: Vec<Vec<Addr>> = vec![];
+
+    // Group members into placements based on their points and tiebreakers
+    for (i, standing) in standings.iter().enumerate() {
+        if i == 0 {
+            placement_members.push(vec![standing.member.clone()]);
+        } else {
+            let previous = &standings[i - 1];
+            if query::compare_standings(previous, standing, tiebreakers)
+                == std::cmp::Ordering::Equal
+            {
+                placement_members
+                    .last_mut()
+                    .unwrap()
+                    .push(standing.member.clone());
+            } else {
+                if placement_members.len() >= placements {
+                    break;
+                }
+                placement_members.push(vec![standing.member.clone()]);
+            }
+        }
+    }
+
+    // Calculate the final distribution
+    let mut member_percentages = vec![];
+    let summed_extras: Decimal = swiss.extension.distribution[placement_members.len()..placements]
+        .iter()
+        .sum();
+    let mut distribution = swiss.extension.distribution[0..placement_members.len()].to_vec();
+    let redistributed_percentage_share = summed_extras.checked_div(Decimal::from_ratio(
+        placement_members.len() as u128,
+        Uint128::one(),
+    ))?;
+
+    for entry in distribution.iter_mut() {
+        *entry = entry.checked_add(redistributed_percentage_share)?;
+    }
+
+    let mut remainder_percentage = Decimal::one();
+    for (i, members) in placement_members.iter().enumerate() {
+        let placement_percentage = distribution[i]
+            .checked_div(Decimal::from_ratio(members.len() as u128, Uint128::one()))?;
+        for member in members {
+            remainder_percentage = remainder_percentage.checked_sub(placement_percentage)?;
+            member_percentages.push(MemberPercentage::<Addr> {
+                addr: member.clone(),
+                percentage: placement_percentage,
+            });
+        }
+    }
+
+    if remainder_percentage > Decimal::zero() {
+        member_percentages[0].percentage = member_percentages[0]
+            .percentage
+            .checked_add(remainder_percentage)?;
+    }
+
+    // Process the competition results
+    CompetitionModule::default().inner_process(
+        deps,
+        swiss,
+        Some(Distribution::<Addr> {
+            member_percentages,
+            remainder_addr: standings[0].member.clone(),
+            fixed_amounts: vec![],
+            remainder_shares: vec![],
+        }),
+        None,
+        None,
+    )
+}
@@ -0,0 +1,8 @@
+pub mod contract;
+mod error;
+pub mod execute;
+pub mod msg;
+pub mod query;
+pub mod state;
+
+pub use crate::error::ContractError;
@@ -0,0 +1,155 @@
+use crate::state::{Match, MatchResult, SwissExt, SwissTiebreaker};
+use arena_interface::{
+    competition::{
+        msg::{ExecuteBase, InstantiateBase, MigrateBase, QueryBase, ToCompetitionExt},
+        state::{Competition, CompetitionResponse},
+    },
+    group,
+};
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, Decimal, Empty, StdError, StdResult, Uint128, Uint64};
+
+#[cw_serde]
+#[derive(cw_orch::ExecuteFns)]
+pub enum ExecuteExt {
+    /// Callable only by the module to pair the first round when creating a competition
+    InstantiateRound {},
+    /// Results can only be submitted for the current round
+    /// The next round is paired once every match of the current round has a result
+    ProcessMatch {
+        swiss_id: Uint128,
+        match_results: Vec<MatchResultMsg>,
+    },
+}
+
+impl From<ExecuteExt> for ExecuteMsg {
+    fn from(msg: ExecuteExt) -> Self {
+        ExecuteMsg::Extension { msg }
+    }
+}
+
+#[cw_serde]
+pub struct MatchResultMsg {
+    pub match_number: Uint128,
+    pub match_result: MatchResult,
+}
+
+#[cw_serde]
+#[derive(QueryResponses, cw_orch::QueryFns)]
+pub enum SwissQueryExt {
+    #[returns(Vec<SwissStanding>)]
+    Standings {
+        swiss_id: Uint128,
+        round: Option<Uint64>,
+    },
+    #[returns(RoundResponse)]
+    Round {
+        swiss_id: Uint128,
+        round_number: Uint64,
+    },
+}
+
+impl From<SwissQueryExt> for QueryMsg {
+    fn from(msg: SwissQueryExt) -> Self {
+        QueryMsg::QueryExtension { msg }
+    }
+}
+
+#[cw_serde]
+#[serde(untagged)]
+pub enum MigrateMsg {
+    Base(MigrateBase),
+}
+
+/// This is used to completely generate schema types
+/// QueryExt response types are hidden by the QueryBase mapping to Binary output
+#[cw_serde]
+pub struct SudoMsg {
+    pub swiss_standing: SwissStanding,
+    pub round_response: RoundResponse,
+}
+
+#[cw_serde]
+pub struct SwissInstantiateExt {
+    pub match_win_points: Uint64,
+    pub match_draw_points: Uint64,
+    pub match_lose_points: Uint64,
+    pub rounds: Uint64,
+    pub distribution: Vec<Decimal>,
+    /// Applied in order after points
+    pub tiebreakers: Vec<SwissTiebreaker>,
+}
+
+impl ToCompetitionExt<SwissExt> for SwissInstantiateExt {
+    fn to_competition_ext(
+        &self,
+        deps: cosmwasm_std::Deps,
+        group_contract: &Addr,
+    ) -> StdResult<SwissExt> {
+        let team_count: Uint64 = deps.querier.query_wasm_smart(
+            group_contract.to_string(),
+            &group::QueryMsg::MembersCount {},
+        )?;
+        if team_count < Uint64::new(2) {
+            return Err(StdError::GenericErr {
+                msg: "At least 2 teams should be provided".to_string(),
+            });
+        }
+        if Uint64::new(self.distribution.len() as u64) > team_count {
+            return Err(StdError::GenericErr {
+                msg: "Cannot have a distribution size bigger than the teams size".to_string(),
+            });
+        }
+        if self.distribution.iter().sum::<Decimal>() != Decimal::one() {
+            return Err(StdError::generic_err("The distribution must sum up to 1"));
+        }
+
+        // A Swiss event cannot have more rounds than a full round-robin without rematches
+        let max_rounds = if team_count.u64() % 2 == 0 {
+            team_count - Uint64::one()
+        } else {
+            team_count
+        };
+        if self.rounds.is_zero() || self.rounds > max_rounds {
+            return Err(StdError::generic_err(format!(
+                "The number of rounds must be between 1 and {}",
+                max_rounds
+            )));
+        }
+
+        Ok(SwissExt {
+            match_win_points: self.match_win_points,
+            match_draw_points: self.match_draw_points,
+            match_lose_points: self.match_lose_points,
+            rounds: self.rounds,
+            current_round: Uint64::zero(),
+            teams: team_count,
+            processed_matches: Uint128::zero(),
+            distribution: self.distribution.clone(),
+            tiebreakers: self.tiebreakers.clone(),
+        })
+    }
+}
+
+#[cw_serde]
+pub struct SwissStanding {
+    pub member: Addr,
+    pub points: Uint64,
+    pub buchholz: Uint64,
+    pub sonneborn_berger: Decimal,
+    pub matches_played: Uint64,
+    pub byes: Uint64,
+}
+
+#[cw_serde]
+pub struct RoundResponse {
+    pub round_number: Uint64,
+    pub matches: Vec<Match>,
+    pub bye: Option<Addr>,
+}
+
+pub type InstantiateMsg = InstantiateBase<Empty>;
+pub type ExecuteMsg = ExecuteBase<ExecuteExt, SwissInstantiateExt>;
+pub type QueryMsg = QueryBase<Empty, SwissQueryExt, SwissExt>;
+pub type Swiss = Competition<SwissExt>;
+pub type SwissResponse = CompetitionResponse<SwissExt>;
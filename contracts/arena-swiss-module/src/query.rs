@@ -0,0 +1,207 @@
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, BTreeSet},
+};
+
+use crate::{
+    contract::CompetitionModule,
+    msg::{RoundResponse, SwissStanding},
+    state::{MatchResult, SwissTiebreaker, MATCHES, ROUNDS},
+};
+use cosmwasm_std::{Addr, Decimal, Deps, Order, StdResult, Uint128, Uint64};
+use cw_storage_plus::Bound;
+
+#[derive(Default)]
+struct StandingRecord {
+    points: Uint64,
+    matches_played: Uint64,
+    byes: Uint64,
+    /// The opponent and the share of the match won against them
+    results: Vec<(Addr, Decimal)>,
+}
+
+/// Calculates and returns the standings for a specific Swiss competition.
+///
+/// # Details
+/// - Points are awarded from the match results, and a bye is worth the match win points.
+/// - Buchholz is the sum of every opponent's points.
+/// - Sonneborn-Berger is the sum of the defeated opponents' points plus half of the drawn opponents' points.
+/// - The standings are sorted by points, then by the competition's tiebreakers in order.
+pub fn standings(
+    deps: Deps,
+    swiss_id: Uint128,
+    round_number: Option<Uint64>,
+) -> StdResult<Vec<SwissStanding>> {
+    let swiss = CompetitionModule::default()
+        .competitions
+        .load(deps.storage, swiss_id.u128())?;
+
+    let mut records: BTreeMap<Addr, StandingRecord> = BTreeMap::new();
+
+    let end_bound = round_number.map(|x| Bound::inclusive(x.u64()));
+    for round in
+        ROUNDS
+            .prefix(swiss_id.u128())
+            .range(deps.storage, None, end_bound, Order::Ascending)
+    {
+        let (_, round) = round?;
+
+        if let Some(bye) = round.bye {
+            let record = records.entry(bye).or_default();
+            record.points = record
+                .points
+                .checked_add(swiss.extension.match_win_points)?;
+            record.byes = record.byes.checked_add(Uint64::one())?;
+        }
+
+        for m in MATCHES
+            .prefix((swiss_id.u128(), round.round_number.u64()))
+            .range(deps.storage, None, None, Order::Ascending)
+        {
+            let (_, m) = m?;
+
+            // Ensure every paired member is listed, even before a result is in
+            records.entry(m.team_1.clone()).or_default();
+            records.entry(m.team_2.clone()).or_default();
+
+            if let Some(result) = m.result {
+                let (points_1, points_2, share_1, share_2) = match result {
+                    MatchResult::Team1 => (
+                        swiss.extension.match_win_points,
+                        swiss.extension.match_lose_points,
+                        Decimal::one(),
+                        Decimal::zero(),
+                    ),
+                    MatchResult::Team2 => (
+                        swiss.extension.match_lose_points,
+                        swiss.extension.match_win_points,
+                        Decimal::zero(),
+                        Decimal::one(),
+                    ),
+                    MatchResult::Draw => (
+                        swiss.extension.match_draw_points,
+                        swiss.extension.match_draw_points,
+                        Decimal::percent(50),
+                        Decimal::percent(50),
+                    ),
+                };
+
+                update_record(&mut records, &m.team_1, &m.team_2, points_1, share_1)?;
+                update_record(&mut records, &m.team_2, &m.team_1, points_2, share_2)?;
+            }
+        }
+    }
+
+    // Calculate the tiebreakers from the final points
+    let points: BTreeMap<Addr, Uint64> = records
+        .iter()
+        .map(|(addr, record)| (addr.clone(), record.points))
+        .collect();
+
+    let mut standings = records
+        .into_iter()
+        .map(|(member, record)| -> StdResult<SwissStanding> {
+            let mut buchholz = Uint64::zero();
+            let mut sonneborn_berger = Decimal::zero();
+            for (opponent, share) in &record.results {
+                let opponent_points = points.get(opponent).copied().unwrap_or_default();
+                buchholz = buchholz.checked_add(opponent_points)?;
+                sonneborn_berger = sonneborn_berger.checked_add(
+                    Decimal::from_ratio(opponent_points, 1u128).checked_mul(*share)?,
+                )?;
+            }
+
+            Ok(SwissStanding {
+                member,
+                points: record.points,
+                buchholz,
+                sonneborn_berger,
+                matches_played: record.matches_played,
+                byes: record.byes,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    standings.sort_by(|a, b| compare_standings(a, b, &swiss.extension.tiebreakers));
+
+    Ok(standings)
+}
+
+fn update_record(
+    records: &mut BTreeMap<Addr, StandingRecord>,
+    team: &Addr,
+    opponent: &Addr,
+    points: Uint64,
+    share: Decimal,
+) -> StdResult<()> {
+    let record = records.entry(team.clone()).or_default();
+    record.points = record.points.checked_add(points)?;
+    record.matches_played = record.matches_played.checked_add(Uint64::one())?;
+    record.results.push((opponent.clone(), share));
+    Ok(())
+}
+
+/// Orders standings by points, then by each tiebreaker in the given order
+pub fn compare_standings(
+    a: &SwissStanding,
+    b: &SwissStanding,
+    tiebreakers: &[SwissTiebreaker],
+) -> Ordering {
+    b.points.cmp(&a.points).then_with(|| {
+        for tiebreaker in tiebreakers {
+            let cmp = match tiebreaker {
+                SwissTiebreaker::Buchholz => b.buchholz.cmp(&a.buchholz),
+                SwissTiebreaker::SonnebornBerger => b.sonneborn_berger.cmp(&a.sonneborn_berger),
+            };
+            if cmp != Ordering::Equal {
+                return cmp;
+            }
+        }
+        Ordering::Equal
+    })
+}
+
+/// The pairs of members who have already played each other and the members who have had a bye
+pub type PairingHistory = (BTreeSet<(Addr, Addr)>, BTreeSet<Addr>);
+
+/// Returns the swiss competition's pairing history
+pub fn pairing_history(deps: Deps, swiss_id: Uint128) -> StdResult<PairingHistory> {
+    let mut played = BTreeSet::new();
+    let mut byes = BTreeSet::new();
+
+    for round in ROUNDS
+        .prefix(swiss_id.u128())
+        .range(deps.storage, None, None, Order::Ascending)
+    {
+        let (_, round) = round?;
+
+        if let Some(bye) = round.bye {
+            byes.insert(bye);
+        }
+
+        for m in MATCHES
+            .prefix((swiss_id.u128(), round.round_number.u64()))
+            .range(deps.storage, None, None, Order::Ascending)
+        {
+            let (_, m) = m?;
+            played.insert(pair_key(&m.team_1, &m.team_2));
+        }
+    }
+
+    Ok((played, byes))
+}
+
+/// Pairs are stored in a consistent order so lookups do not depend on which side a member played
+pub fn pair_key(a: &Addr, b: &Addr) -> (Addr, Addr) {
+    if a < b {
+        (a.clone(), b.clone())
+    } else {
+        (b.clone(), a.clone())
+    }
+}
+
+pub fn round(deps: Deps, swiss_id: Uint128, round_number: Uint64) -> StdResult<RoundResponse> {
+    ROUNDS
+        .load(deps.storage, (swiss_id.u128(), round_number.u64()))?
+        .into_response(deps, swiss_id)
+}
@@ -0,0 +1,70 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Decimal, Deps, StdResult, Uint128, Uint64};
+use cw_storage_plus::Map;
+
+use crate::msg::RoundResponse;
+
+#[cw_serde]
+pub struct Match {
+    pub match_number: Uint128,
+    pub team_1: Addr,
+    pub team_2: Addr,
+    pub result: Option<MatchResult>,
+}
+
+#[cw_serde]
+pub enum MatchResult {
+    Team1,
+    Team2,
+    Draw,
+}
+
+#[cw_serde]
+pub struct Round {
+    pub round_number: Uint64,
+    pub matches: Vec<Uint128>, // A link to the Match by match_number
+    /// The member sitting out this round, awarded the win points
+    pub bye: Option<Addr>,
+}
+
+impl Round {
+    pub fn into_response(self, deps: Deps, swiss_id: Uint128) -> StdResult<RoundResponse> {
+        let matches = MATCHES
+            .prefix((swiss_id.u128(), self.round_number.u64()))
+            .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+            .map(|x| x.map(|y| y.1))
+            .collect::<StdResult<Vec<Match>>>()?;
+
+        Ok(RoundResponse {
+            round_number: self.round_number,
+            matches,
+            bye: self.bye,
+        })
+    }
+}
+
+#[cw_serde]
+pub enum SwissTiebreaker {
+    /// The sum of the opponents' points
+    Buchholz,
+    /// The sum of the points of defeated opponents plus half the points of drawn opponents
+    SonnebornBerger,
+}
+
+#[cw_serde]
+pub struct SwissExt {
+    pub match_win_points: Uint64,
+    pub match_draw_points: Uint64,
+    pub match_lose_points: Uint64,
+    pub rounds: Uint64,
+    pub current_round: Uint64,
+    pub teams: Uint64,
+    pub processed_matches: Uint128,
+    pub distribution: Vec<Decimal>,
+    pub tiebreakers: Vec<SwissTiebreaker>,
+}
+
+/// (Swiss Id, Round Number)
+pub const ROUNDS: Map<(u128, u64), Round> = Map::new("swiss_rounds");
+/// (Swiss Id, Round Number, Match Number)
+pub const MATCHES: Map<(u128, u64, u128), Match> = Map::new("swiss_matches");
@@ -93,6 +93,47 @@ pub fn execute(
                 tournament_id,
                 match_results,
             } => execute::process_matches(deps, info, tournament_id, match_results),
+            ExecuteExt::ProcessGroupMatch {
+                tournament_id,
+                group,
+                match_results,
+            } => execute::process_group_matches(deps, info, tournament_id, group, match_results),
+            ExecuteExt::CorrectMatch {
+                tournament_id,
+                match_number,
+                match_result,
+            } => execute::correct_match(deps, info, tournament_id, match_number, match_result),
+            ExecuteExt::DisputeMatch {
+                tournament_id,
+                match_number,
+                match_result,
+                title,
+                description,
+                evidence,
+            } => execute::dispute_match(
+                deps,
+                env,
+                info,
+                tournament_id,
+                match_number,
+                match_result,
+                title,
+                description,
+                evidence,
+            ),
+            ExecuteExt::ResolveDispute {
+                tournament_id,
+                match_number,
+                match_result,
+            } => execute::resolve_dispute(deps, info, tournament_id, match_number, match_result),
+            ExecuteExt::DismissDispute {
+                tournament_id,
+                match_number,
+            } => execute::dismiss_dispute(deps, info, tournament_id, match_number),
+            ExecuteExt::SubmitRandomness {
+                tournament_id,
+                randomness,
+            } => execute::submit_randomness(deps, info, tournament_id, randomness),
             ExecuteExt::InstantiateTournament {} => {
                 execute::instantiate_tournament(deps, env, info)
             }
@@ -100,6 +141,8 @@ pub fn execute(
         ExecuteBase::ProcessCompetition {
             competition_id,
             distribution,
+            nft_allocations,
+            vesting,
         } => {
             let competition = CompetitionModule::default()
                 .competitions
@@ -115,6 +158,8 @@ pub fn execute(
                 info,
                 competition_id,
                 distribution,
+                nft_allocations,
+                vesting,
                 None,
             )?)
         }
@@ -139,6 +184,31 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
                 tournament_id,
                 match_number,
             } => to_json_binary(&query::query_match(deps, tournament_id, match_number)?),
+            QueryExt::MatchDispute {
+                tournament_id,
+                match_number,
+            } => to_json_binary(&query::query_match_dispute(
+                deps,
+                tournament_id,
+                match_number,
+            )?),
+            QueryExt::Groups { tournament_id } => {
+                to_json_binary(&query::query_groups(deps, tournament_id)?)
+            }
+            QueryExt::GroupStandings {
+                tournament_id,
+                group,
+            } => to_json_binary(&query::query_group_standings(deps, tournament_id, group)?),
+            QueryExt::GroupMatches {
+                tournament_id,
+                group,
+                round_number,
+            } => to_json_binary(&query::query_group_matches(
+                deps,
+                tournament_id,
+                group,
+                round_number,
+            )?),
         },
         _ => CompetitionModule::default().query(deps, env, msg),
     }
@@ -1,4 +1,4 @@
-use cosmwasm_std::{OverflowError, StdError};
+use cosmwasm_std::{CheckedFromRatioError, OverflowError, StdError};
 use cw_competition_base::error::CompetitionError;
 use cw_ownable::OwnershipError;
 use thiserror::Error;
@@ -14,6 +14,9 @@ pub enum ContractError {
     #[error("{0}")]
     OverflowError(#[from] OverflowError),
 
+    #[error("{0}")]
+    CheckedFromRatioError(#[from] CheckedFromRatioError),
+
     #[error("{0}")]
     OwnershipError(#[from] OwnershipError),
 
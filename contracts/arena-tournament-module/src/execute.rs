@@ -1,13 +1,25 @@
 use crate::contract::CompetitionModule;
-use crate::msg::{MatchResultMsg, Tournament};
-use crate::state::{EliminationType, Match, MatchResult, MATCHES};
+use crate::msg::{ExecuteExt, ExecuteMsg, GroupMatchResultMsg, MatchResultMsg, Tournament};
+use crate::query;
+use crate::state::{
+    BracketStage, EliminationType, GroupMatch, GroupMatchResult, GroupStage, Match, MatchResult,
+    PlacementMode, Seeding, SeriesConfig, TournamentExt, GROUPS, GROUP_MATCHES, MATCHES,
+    MATCH_DISPUTES, RANDOMNESS,
+};
 use crate::{ContractError, NestedArray};
+use arena_interface::competition::state::CompetitionStatus;
 use arena_interface::group::{self, MemberMsg};
-use arena_interface::ratings::MemberResult;
-use cosmwasm_std::{ensure_eq, Addr, Decimal, Env, MessageInfo, StdError, Storage};
-use cosmwasm_std::{DepsMut, Response, StdResult, Uint128};
+use arena_interface::helpers::round_robin_schedule;
+use arena_interface::ratings::{MemberResult, Rating};
+use cosmwasm_std::{
+    ensure_eq, to_json_binary, Addr, Decimal, Env, HexBinary, MessageInfo, Order, StdError, Storage,
+};
+use cosmwasm_std::{Deps, DepsMut, Response, StdResult, Uint128, Uint64};
 use cw_balance::{Distribution, MemberPercentage};
+use cw_competition_base::error::CompetitionError;
+use cw_ownable::get_ownership;
 use itertools::Itertools;
+use sha2::{Digest, Sha256};
 use std::collections::{BTreeMap, BTreeSet, VecDeque};
 use std::iter::repeat;
 
@@ -28,6 +40,63 @@ pub fn instantiate_tournament(
         .competitions
         .load(deps.storage, tournament_id.u128())?;
 
+    // Random seeding waits for the arena DAO's randomness, so the host cannot choose the seeds
+    let response = Response::default()
+        .add_attribute("action", "instantiate_tournament")
+        .add_attribute("tournament_id", tournament_id.to_string());
+    if matches!(tournament.extension.seeding, Some(Seeding::Random {})) {
+        return Ok(response.add_attribute("awaiting_randomness", "true"));
+    }
+
+    generate_tournament(deps, tournament)?;
+
+    Ok(response)
+}
+
+/// Submits the randomness of a randomly seeded tournament, then seeds the teams and generates the bracket.
+///
+/// # Details
+/// - Only the arena DAO can submit the randomness, such as a drand beacon published after the tournament was created.
+/// - The randomness can only be submitted once.
+pub fn submit_randomness(
+    deps: DepsMut,
+    info: MessageInfo,
+    tournament_id: Uint128,
+    randomness: HexBinary,
+) -> Result<Response, ContractError> {
+    let tournament = CompetitionModule::default()
+        .competitions
+        .load(deps.storage, tournament_id.u128())?;
+    if info.sender != tournament.admin_dao {
+        return Err(ContractError::Unauthorized {});
+    }
+    if !matches!(tournament.extension.seeding, Some(Seeding::Random {})) {
+        return Err(ContractError::StdError(StdError::generic_err(
+            "The tournament is not seeded randomly",
+        )));
+    }
+    if RANDOMNESS.has(deps.storage, tournament_id.u128()) {
+        return Err(ContractError::StdError(StdError::generic_err(
+            "The randomness has already been submitted",
+        )));
+    }
+    if randomness.is_empty() {
+        return Err(ContractError::StdError(StdError::generic_err(
+            "The randomness cannot be empty",
+        )));
+    }
+    RANDOMNESS.save(deps.storage, tournament_id.u128(), &randomness)?;
+
+    generate_tournament(deps, tournament)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "submit_randomness")
+        .add_attribute("tournament_id", tournament_id.to_string())
+        .add_attribute("randomness", randomness.to_hex()))
+}
+
+/// Seeds the tournament's teams, then draws the groups or generates the bracket.
+fn generate_tournament(deps: DepsMut, tournament: Tournament) -> Result<(), ContractError> {
     // Convert teams to addresses
     let teams: Vec<Addr> = deps
         .querier
@@ -41,26 +110,209 @@ pub fn instantiate_tournament(
         .into_iter()
         .map(|x| x.addr)
         .collect();
+    let teams = seed_teams(deps.as_ref(), &tournament, teams)?;
 
-    // Single Elimination Bracket
-    if let EliminationType::SingleElimination {
-        play_third_place_match,
-    } = tournament.extension.elimination_type
+    // With a group stage, the bracket is generated once every group match has a result
+    match tournament
+        .extension
+        .elimination_type
+        .group_count(Uint64::new(teams.len() as u64))
     {
-        generate_single_elimination_bracket(
-            deps,
-            &teams,
-            tournament_id.u128(),
-            play_third_place_match,
+        Some(group_count) => draw_groups(deps, tournament, teams, group_count)?,
+        None => start_bracket(deps, &tournament, &teams)?,
+    }
+
+    Ok(())
+}
+
+/// Generates the tournament's bracket for the teams in seed order and applies its series lengths.
+fn start_bracket(mut deps: DepsMut, tournament: &Tournament, teams: &[Addr]) -> StdResult<()> {
+    generate_bracket(
+        deps.branch(),
+        teams,
+        tournament.id.u128(),
+        &tournament.extension.elimination_type,
+        tournament.extension.placements,
+    )?;
+
+    if let Some(series) = &tournament.extension.series {
+        apply_series_lengths(
+            deps.storage,
+            tournament.id.u128(),
+            &tournament.extension.elimination_type,
+            series,
         )?;
-    } else {
-        // Double Elimination Bracket
-        generate_double_elimination_bracket(deps, &teams, tournament_id.u128())?;
     }
 
-    Ok(Response::default()
-        .add_attribute("action", "instantiate_tournament")
-        .add_attribute("tournament_id", tournament_id.to_string()))
+    Ok(())
+}
+
+/// Draws the seeded teams into groups and generates each group's round-robin schedule.
+fn draw_groups(
+    deps: DepsMut,
+    mut tournament: Tournament,
+    teams: Vec<Addr>,
+    group_count: Uint64,
+) -> StdResult<()> {
+    let mut group_matches = 0u128;
+    for (i, (members, pairings)) in draw_group_schedules(teams, group_count.u64() as usize)
+        .into_iter()
+        .enumerate()
+    {
+        let group = i as u64 + 1;
+
+        group_matches += pairings.len() as u128;
+        for (match_number, (round_number, team_1, team_2)) in (1u128..).zip(pairings) {
+            GROUP_MATCHES.save(
+                deps.storage,
+                (tournament.id.u128(), group, match_number),
+                &GroupMatch {
+                    match_number: Uint128::new(match_number),
+                    round_number,
+                    team_1,
+                    team_2,
+                    result: None,
+                },
+            )?;
+        }
+
+        GROUPS.save(deps.storage, (tournament.id.u128(), group), &members)?;
+    }
+
+    tournament.extension.group_stage = Some(GroupStage {
+        group_matches: Uint128::new(group_matches),
+        processed_group_matches: Uint128::zero(),
+    });
+    CompetitionModule::default()
+        .competitions
+        .save(deps.storage, tournament.id.u128(), &tournament)
+}
+
+/// A group match's (round number, team 1, team 2)
+pub type GroupPairing = (Uint64, Addr, Addr);
+
+/// Draws the seeded teams into groups and pairs each group's members in a round-robin schedule.
+///
+/// Teams are drawn using a snake draw, so each group receives a similar spread of seeds.
+/// Each group's pairings are in match number order.
+pub fn draw_group_schedules(
+    teams: Vec<Addr>,
+    group_count: usize,
+) -> Vec<(Vec<Addr>, Vec<GroupPairing>)> {
+    let mut groups: Vec<Vec<Addr>> = vec![vec![]; group_count];
+    for (i, team) in teams.into_iter().enumerate() {
+        let row = i / group_count;
+        let column = i % group_count;
+        let group = if row % 2 == 0 {
+            column
+        } else {
+            group_count - 1 - column
+        };
+
+        groups[group].push(team);
+    }
+
+    groups
+        .into_iter()
+        .map(|members| {
+            let mut pairings = vec![];
+            for (round, round_pairings) in
+                round_robin_schedule(members.len()).into_iter().enumerate()
+            {
+                for (team_1, team_2) in round_pairings {
+                    pairings.push((
+                        Uint64::new(round as u64 + 1),
+                        members[team_1].clone(),
+                        members[team_2].clone(),
+                    ));
+                }
+            }
+
+            (members, pairings)
+        })
+        .collect()
+}
+
+/// Orders the teams by the tournament's seeding strategy, starting from the group's seed order.
+fn seed_teams(deps: Deps, tournament: &Tournament, teams: Vec<Addr>) -> StdResult<Vec<Addr>> {
+    Ok(match &tournament.extension.seeding {
+        None | Some(Seeding::GroupSeed) => teams,
+        Some(Seeding::Random {}) => {
+            let randomness = RANDOMNESS.load(deps.storage, tournament.id.u128())?;
+
+            teams
+                .into_iter()
+                .sorted_by_cached_key(|team| -> [u8; 32] {
+                    Sha256::new()
+                        .chain_update(randomness.as_slice())
+                        .chain_update(team.as_bytes())
+                        .finalize()
+                        .into()
+                })
+                .collect()
+        }
+        Some(Seeding::Rating) => {
+            let category_id = tournament.category_id.ok_or_else(|| {
+                StdError::generic_err(
+                    "Seeding by rating requires the tournament to have a category",
+                )
+            })?;
+            let arena_core = get_ownership(deps.storage)?
+                .owner
+                .ok_or_else(|| StdError::generic_err("The module does not have an owner"))?;
+
+            let mut team_ratings = teams
+                .into_iter()
+                .map(|team| -> StdResult<_> {
+                    let rating = deps.querier.query_wasm_smart::<Option<Rating>>(
+                        &arena_core,
+                        &arena_interface::core::QueryMsg::QueryExtension {
+                            msg: arena_interface::core::QueryExt::Rating {
+                                category_id,
+                                addr: team.to_string(),
+                            },
+                        },
+                    )?;
+
+                    Ok((team, rating.map(|x| x.value)))
+                })
+                .collect::<StdResult<Vec<_>>>()?;
+
+            // Unrated teams sort last, and the stable sort keeps the group's order for equal ratings
+            team_ratings.sort_by_key(|x| std::cmp::Reverse(x.1));
+
+            team_ratings.into_iter().map(|x| x.0).collect()
+        }
+        Some(Seeding::Manual { teams }) => teams.clone(),
+    })
+}
+
+/// Generates and saves the matches of a bracket for the teams in seed order
+pub fn generate_bracket(
+    deps: DepsMut,
+    teams: &[Addr],
+    tournament_id: u128,
+    elimination_type: &EliminationType,
+    placements: Option<PlacementMode>,
+) -> StdResult<()> {
+    match elimination_type {
+        EliminationType::SingleElimination {
+            play_third_place_match,
+        } => generate_single_elimination_bracket(
+            deps,
+            teams,
+            tournament_id,
+            *play_third_place_match,
+            placements,
+        ),
+        EliminationType::DoubleElimination => {
+            generate_double_elimination_bracket(deps, teams, tournament_id)
+        }
+        // The teams are the group stage's qualifiers
+        EliminationType::RoundRobinGroups { then, .. } => {
+            generate_bracket(deps, teams, tournament_id, then, placements)
+        }
+    }
 }
 
 fn generate_matches(
@@ -150,6 +402,9 @@ fn create_match(
         next_match_winner: parent_match_number,
         next_match_loser: None,
         is_losers_bracket,
+        best_of: None,
+        games: None,
+        placement: None,
     };
 
     matches.insert(match_number.u128(), match_);
@@ -165,6 +420,7 @@ fn generate_single_elimination_bracket(
     teams: &[Addr],
     tournament_id: u128,
     play_third_place_match: bool,
+    placements: Option<PlacementMode>,
 ) -> StdResult<()> {
     let mut matches = BTreeMap::new();
     let mut layer_map = BTreeMap::new();
@@ -207,12 +463,77 @@ fn generate_single_elimination_bracket(
         if let Some(match_) = matches.get_mut(&3) {
             match_.next_match_loser = Some(third_place_match_number);
         }
+
+        // The losers of each round before the semifinals play for the places after the teams still in
+        if let Some(placements) = placements {
+            let rounds = layer_map.keys().copied().filter(|x| *x > 2).collect_vec();
+            for layer in rounds {
+                // Rounds with byes still decide the places below the full round's losers
+                let first_place = (1u32 << (layer - 1)) + 1;
+                if !placements.plays_for(first_place as u64) {
+                    continue;
+                }
+
+                // Losers of matches that feed the same match are paired first
+                let mut round = layer_map[&layer].iter().copied().collect_vec();
+                round.sort_by_key(|x| (matches[x].next_match_winner, *x));
+
+                add_placement_bracket(
+                    &mut matches,
+                    &mut layer_map,
+                    round.into_iter().map(|x| (x, true)).collect(),
+                    first_place,
+                    placements,
+                );
+            }
+        }
     }
 
     // Save matches
     save_matches(&mut matches, layer_map, tournament_id, deps.storage)
 }
 
+/// Creates the placement bracket for the winners or losers of the source matches, which contests the places from `first_place`.
+/// Placement matches are numbered with the semifinals, so the third place match and final keep the last match numbers.
+fn add_placement_bracket(
+    matches: &mut BTreeMap<u128, Match>,
+    layer_map: &mut BTreeMap<usize, BTreeSet<u128>>,
+    sources: Vec<(u128, bool)>,
+    first_place: u32,
+    placements: PlacementMode,
+) {
+    let mut winners = vec![];
+    let mut losers = vec![];
+    for pair in sources.chunks(2) {
+        let match_number = create_match(None, None, None, matches, layer_map, 2, None);
+        if let Some(match_) = matches.get_mut(&match_number.u128()) {
+            match_.placement = Some(first_place);
+        }
+
+        for (source, is_loser) in pair {
+            if let Some(source_match) = matches.get_mut(source) {
+                if *is_loser {
+                    source_match.next_match_loser = Some(match_number);
+                } else {
+                    source_match.next_match_winner = Some(match_number);
+                }
+            }
+        }
+
+        winners.push((match_number.u128(), false));
+        losers.push((match_number.u128(), true));
+    }
+
+    if winners.len() > 1 {
+        let losers_first_place = first_place + winners.len() as u32;
+
+        add_placement_bracket(matches, layer_map, winners, first_place, placements);
+        if placements.plays_for(losers_first_place as u64) {
+            add_placement_bracket(matches, layer_map, losers, losers_first_place, placements);
+        }
+    }
+}
+
 // Updates the match ordering and saves them to storage
 fn save_matches(
     matches: &mut BTreeMap<u128, Match>,
@@ -429,11 +750,64 @@ fn generate_double_elimination_bracket(
         match_.next_match_winner = Some(final_match_number);
     }
 
-    // The rebuttal match will be added dynamically on final processing
+    // A bracket reset match may be added dynamically on final processing
 
     save_matches(&mut matches, layer_map, tournament_id, deps.storage)
 }
 
+/// Sets the series length of each match by its stage in the bracket
+fn apply_series_lengths(
+    storage: &mut dyn Storage,
+    tournament_id: u128,
+    elimination_type: &EliminationType,
+    series: &SeriesConfig,
+) -> StdResult<()> {
+    let matches = MATCHES
+        .prefix(tournament_id)
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<BTreeMap<u128, Match>>>()?;
+
+    for (match_number, match_) in matches.iter() {
+        let stage = match match_.is_losers_bracket {
+            _ if match_.placement.is_some() => BracketStage::PlacementMatch,
+            Some(true) => match elimination_type.bracket() {
+                EliminationType::SingleElimination { .. } => BracketStage::ThirdPlaceMatch,
+                _ => BracketStage::LosersBracket,
+            },
+            _ => {
+                // Follow the winner's path to the final
+                let mut rounds_before_final = 0;
+                let mut next = match_.next_match_winner;
+                while let Some(next_match) = next {
+                    rounds_before_final += 1;
+                    next = matches
+                        .get(&next_match.u128())
+                        .and_then(|x| x.next_match_winner);
+                }
+
+                if rounds_before_final == 0 {
+                    BracketStage::Final
+                } else {
+                    BracketStage::WinnersRound {
+                        rounds_before_final,
+                    }
+                }
+            }
+        };
+
+        let best_of = series.best_of(&stage);
+        if best_of > 1 {
+            let mut match_ = match_.clone();
+            match_.best_of = Some(best_of);
+
+            MATCHES.save(storage, (tournament_id, *match_number), &match_)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Processes match results for a tournament, then distributes the prize pool once every match has a result.
 pub fn process_matches(
     deps: DepsMut,
     info: MessageInfo,
@@ -446,7 +820,632 @@ pub fn process_matches(
         .competitions
         .load(deps.storage, tournament_id.u128())?;
     competition_module.inner_validate_auth(&info.sender, &tournament, false)?;
+    if let Some(group_stage) = &tournament.extension.group_stage {
+        if !group_stage.is_complete() {
+            return Err(ContractError::StdError(StdError::generic_err(
+                "The group stage is not complete",
+            )));
+        }
+    }
+    ensure_not_frozen(
+        deps.storage,
+        tournament_id,
+        match_results.iter().map(|x| x.match_number),
+    )?;
+
+    let member_results = advance_matches(
+        deps.storage,
+        tournament_id,
+        &mut tournament.extension,
+        match_results,
+        tournament.category_id.is_some(),
+    )?;
+
+    // Trigger rating adjustments
+    let mut sub_msgs = vec![];
+    if let Some(category_id) = tournament.category_id {
+        if CompetitionModule::default().query_is_dao_member(
+            deps.as_ref(),
+            &tournament.host,
+            tournament.start_height,
+        ) {
+            sub_msgs.push(CompetitionModule::default().trigger_rating_adjustment(
+                deps.storage,
+                category_id,
+                member_results,
+            )?);
+        }
+    }
+
+    competition_module
+        .competitions
+        .save(deps.storage, tournament_id.u128(), &tournament)?;
+
+    // Trigger distribution if all matches are processed and none are disputed
+    let response = if tournament.extension.processed_matches >= tournament.extension.total_matches
+        && !has_open_disputes(deps.storage, tournament_id)
+    {
+        // Trigger the distribution logic here
+        trigger_distribution(deps, tournament)?
+    } else {
+        Response::new()
+    };
+
+    Ok(response
+        .add_attribute("action", "process_matches")
+        .add_submessages(sub_msgs))
+}
+
+/// Processes group stage match results.
+///
+/// Results can be changed until the group stage is complete, and rating updates are only handled the first time.
+/// Once every group match has a result, the top teams of each group are seeded into the bracket.
+pub fn process_group_matches(
+    mut deps: DepsMut,
+    info: MessageInfo,
+    tournament_id: Uint128,
+    group: Uint64,
+    match_results: Vec<GroupMatchResultMsg>,
+) -> Result<Response, ContractError> {
+    let competition_module = CompetitionModule::default();
+    let mut tournament = competition_module
+        .competitions
+        .load(deps.storage, tournament_id.u128())?;
+    competition_module.inner_validate_auth(&info.sender, &tournament, false)?;
+
+    let group_stage = tournament
+        .extension
+        .group_stage
+        .as_mut()
+        .filter(|x| !x.is_complete())
+        .ok_or_else(|| StdError::generic_err("The tournament does not have a group stage left"))?;
+
+    let mut member_results = vec![];
+    for match_result in match_results {
+        let mut m = GROUP_MATCHES
+            .may_load(
+                deps.storage,
+                (
+                    tournament_id.u128(),
+                    group.u64(),
+                    match_result.match_number.u128(),
+                ),
+            )?
+            .ok_or_else(|| {
+                StdError::generic_err(format!(
+                    "Match number {} not found in group {}",
+                    match_result.match_number, group
+                ))
+            })?;
+
+        if m.result.is_none() {
+            group_stage.processed_group_matches += Uint128::one();
+
+            if tournament.category_id.is_some() {
+                let (member_result_1, member_result_2) = match match_result.match_result {
+                    GroupMatchResult::Team1 => (Decimal::one(), Decimal::zero()),
+                    GroupMatchResult::Team2 => (Decimal::zero(), Decimal::one()),
+                    GroupMatchResult::Draw => (Decimal::percent(50), Decimal::percent(50)),
+                };
+
+                member_results.push((
+                    MemberResult {
+                        addr: m.team_1.clone(),
+                        result: member_result_1,
+                    },
+                    MemberResult {
+                        addr: m.team_2.clone(),
+                        result: member_result_2,
+                    },
+                ));
+            }
+        }
+
+        m.result = Some(match_result.match_result);
+        GROUP_MATCHES.save(
+            deps.storage,
+            (tournament_id.u128(), group.u64(), m.match_number.u128()),
+            &m,
+        )?;
+    }
+    let is_complete = group_stage.is_complete();
+
+    // Trigger rating adjustments
+    let mut sub_msgs = vec![];
+    if let Some(category_id) = tournament.category_id {
+        if !member_results.is_empty()
+            && competition_module.query_is_dao_member(
+                deps.as_ref(),
+                &tournament.host,
+                tournament.start_height,
+            )
+        {
+            sub_msgs.push(competition_module.trigger_rating_adjustment(
+                deps.storage,
+                category_id,
+                member_results,
+            )?);
+        }
+    }
+
+    competition_module
+        .competitions
+        .save(deps.storage, tournament_id.u128(), &tournament)?;
+
+    let mut response = Response::new();
+    if is_complete {
+        let seeds = seed_from_groups(deps.as_ref(), &tournament)?;
+        start_bracket(deps.branch(), &tournament, &seeds)?;
+
+        response = response.add_attribute("stage", "bracket");
+    }
+
+    Ok(response
+        .add_attribute("action", "process_group_matches")
+        .add_submessages(sub_msgs))
+}
+
+/// Returns the bracket's seeds from the final group standings.
+///
+/// Teams are ordered by group placement, so every group winner is seeded above every runner-up.
+/// Each placement is in group order, so the bracket pairs a group's winner with another group's qualifier.
+fn seed_from_groups(deps: Deps, tournament: &Tournament) -> StdResult<Vec<Addr>> {
+    let advance_per_group = match &tournament.extension.elimination_type {
+        EliminationType::RoundRobinGroups {
+            advance_per_group, ..
+        } => advance_per_group.u64() as usize,
+        _ => return Ok(vec![]),
+    };
+
+    let mut standings = vec![];
+    for group in query::query_groups(deps, tournament.id)? {
+        standings.push(query::query_group_standings(
+            deps,
+            tournament.id,
+            group.group,
+        )?);
+    }
+
+    Ok((0..advance_per_group)
+        .flat_map(|placement| {
+            standings
+                .iter()
+                .filter_map(move |x| x.get(placement).map(|y| y.member.clone()))
+        })
+        .collect())
+}
+
+/// Corrects the result of a processed match whose dependent matches have not been played.
+///
+/// # Details
+/// - Only the host and the arena DAO can correct a match, through the same authorization as processing matches.
+/// - The bracket advancement is rolled back by reprocessing the match, which replaces the advanced teams.
+/// - The game-by-game results of a corrected series are cleared.
+/// - If the original result adjusted ratings, its reverse is sent to arena core before the corrected result.
+pub fn correct_match(
+    deps: DepsMut,
+    info: MessageInfo,
+    tournament_id: Uint128,
+    match_number: Uint128,
+    match_result: MatchResult,
+) -> Result<Response, ContractError> {
+    let competition_module = CompetitionModule::default();
+    let mut tournament = competition_module
+        .competitions
+        .load(deps.storage, tournament_id.u128())?;
+    competition_module.inner_validate_auth(&info.sender, &tournament, false)?;
+    ensure_not_frozen(deps.storage, tournament_id, [match_number].into_iter())?;
+
+    let match_ = MATCHES.load(deps.storage, (tournament_id.u128(), match_number.u128()))?;
+    let previous_result = match_
+        .result
+        .clone()
+        .ok_or_else(|| StdError::generic_err("The match has not been processed"))?;
+    if previous_result == match_result {
+        return Err(ContractError::StdError(StdError::generic_err(
+            "The match already has this result",
+        )));
+    }
+    ensure_dependents_unplayed(deps.storage, tournament_id, &match_)?;
+
+    set_match_result(
+        deps.storage,
+        tournament_id,
+        &mut tournament.extension,
+        match_number,
+        match_result.clone(),
+        false,
+    )?;
+
+    let mut sub_msgs = vec![];
+    if let Some(category_id) = tournament.category_id {
+        if competition_module.query_is_dao_member(
+            deps.as_ref(),
+            &tournament.host,
+            tournament.start_height,
+        ) {
+            sub_msgs.push(competition_module.trigger_rating_adjustment(
+                deps.storage,
+                category_id,
+                corrected_member_results(&match_, &previous_result, &match_result),
+            )?);
+        }
+    }
+
+    competition_module
+        .competitions
+        .save(deps.storage, tournament_id.u128(), &tournament)?;
+
+    // A corrected grand final can remove the bracket reset, which completes the tournament
+    let response = if tournament.extension.processed_matches >= tournament.extension.total_matches
+        && !has_open_disputes(deps.storage, tournament_id)
+    {
+        trigger_distribution(deps, tournament)?
+    } else {
+        Response::new()
+    };
 
+    Ok(response
+        .add_attribute("action", "correct_match")
+        .add_attribute("match_number", match_number.to_string())
+        .add_attribute("previous_result", format!("{:?}", previous_result))
+        .add_attribute("result", format!("{:?}", match_result))
+        .add_submessages(sub_msgs))
+}
+
+/// Disputes a match that the sender plays in without jailing the tournament.
+///
+/// # Details
+/// - The match and every match that depends on it are frozen until the dispute is resolved.
+/// - A processed match can only be disputed while its dependent matches have not been played.
+/// - The distribution is not triggered while a dispute is open.
+/// - A DAO proposal is created through the arena core, which resolves the dispute with the proposed result.
+#[allow(clippy::too_many_arguments)]
+pub fn dispute_match(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    tournament_id: Uint128,
+    match_number: Uint128,
+    match_result: MatchResult,
+    title: String,
+    description: String,
+    evidence: Vec<String>,
+) -> Result<Response, ContractError> {
+    let competition_module = CompetitionModule::default();
+    let tournament = competition_module
+        .competitions
+        .load(deps.storage, tournament_id.u128())?;
+
+    let match_ = MATCHES.load(deps.storage, (tournament_id.u128(), match_number.u128()))?;
+    if match_.team_1.as_ref() != Some(&info.sender) && match_.team_2.as_ref() != Some(&info.sender)
+    {
+        return Err(ContractError::Unauthorized {});
+    }
+    ensure_not_frozen(deps.storage, tournament_id, [match_number].into_iter())?;
+    ensure_dependents_unplayed(deps.storage, tournament_id, &match_)?;
+
+    let resolve_msg = to_json_binary(&ExecuteMsg::Extension {
+        msg: ExecuteExt::ResolveDispute {
+            tournament_id,
+            match_number,
+            match_result,
+        },
+    })?;
+    let (dispute, msg) = competition_module.inner_dispute_match(
+        deps.branch(),
+        &env,
+        info.clone(),
+        &tournament,
+        title,
+        description,
+        evidence,
+        resolve_msg,
+    )?;
+    MATCH_DISPUTES.save(
+        deps.storage,
+        (tournament_id.u128(), match_number.u128()),
+        &dispute,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "dispute_match")
+        .add_attribute("match_number", match_number.to_string())
+        .add_attribute("originator", info.sender)
+        .add_message(msg))
+}
+
+/// Resolves a disputed match with the result decided by the arena DAO.
+///
+/// # Details
+/// - The result is set directly, even for a best-of-N series, and the teams advance from it.
+/// - A changed result is corrected like `correct_match`.
+/// - The distribution is triggered if every match is processed and this was the last open dispute.
+pub fn resolve_dispute(
+    deps: DepsMut,
+    info: MessageInfo,
+    tournament_id: Uint128,
+    match_number: Uint128,
+    match_result: MatchResult,
+) -> Result<Response, ContractError> {
+    let competition_module = CompetitionModule::default();
+    let mut tournament = competition_module
+        .competitions
+        .load(deps.storage, tournament_id.u128())?;
+    if info.sender != tournament.admin_dao {
+        return Err(ContractError::Unauthorized {});
+    }
+    if !matches!(tournament.status, CompetitionStatus::Active { .. }) {
+        return Err(ContractError::CompetitionError(
+            CompetitionError::InvalidCompetitionStatus {
+                current_status: tournament.status,
+            },
+        ));
+    }
+
+    let key = (tournament_id.u128(), match_number.u128());
+    if !MATCH_DISPUTES.has(deps.storage, key) {
+        return Err(ContractError::StdError(StdError::NotFound {
+            kind: "Match dispute".to_string(),
+        }));
+    }
+    MATCH_DISPUTES.remove(deps.storage, key);
+
+    let match_ = MATCHES.load(deps.storage, key)?;
+    let mut member_results = set_match_result(
+        deps.storage,
+        tournament_id,
+        &mut tournament.extension,
+        match_number,
+        match_result.clone(),
+        tournament.category_id.is_some(),
+    )?;
+    if let Some(previous_result) = &match_.result {
+        if *previous_result != match_result {
+            member_results = corrected_member_results(&match_, previous_result, &match_result);
+        }
+    }
+
+    let mut sub_msgs = vec![];
+    if let Some(category_id) = tournament.category_id {
+        if !member_results.is_empty()
+            && competition_module.query_is_dao_member(
+                deps.as_ref(),
+                &tournament.host,
+                tournament.start_height,
+            )
+        {
+            sub_msgs.push(competition_module.trigger_rating_adjustment(
+                deps.storage,
+                category_id,
+                member_results,
+            )?);
+        }
+    }
+
+    competition_module
+        .competitions
+        .save(deps.storage, tournament_id.u128(), &tournament)?;
+
+    let response = if tournament.extension.processed_matches >= tournament.extension.total_matches
+        && !has_open_disputes(deps.storage, tournament_id)
+    {
+        trigger_distribution(deps, tournament)?
+    } else {
+        Response::new()
+    };
+
+    Ok(response
+        .add_attribute("action", "resolve_dispute")
+        .add_attribute("match_number", match_number.to_string())
+        .add_attribute("result", format!("{:?}", match_result))
+        .add_submessages(sub_msgs))
+}
+
+/// Dismisses a disputed match without deciding its result.
+///
+/// # Details
+/// - Only the host and the arena DAO can dismiss a dispute, through the same authorization as processing matches.
+/// - A dispute whose proposal was rejected or never executed would otherwise keep the match and its dependents frozen.
+/// - The distribution is triggered if every match is processed and this was the last open dispute.
+pub fn dismiss_dispute(
+    deps: DepsMut,
+    info: MessageInfo,
+    tournament_id: Uint128,
+    match_number: Uint128,
+) -> Result<Response, ContractError> {
+    let competition_module = CompetitionModule::default();
+    let tournament = competition_module
+        .competitions
+        .load(deps.storage, tournament_id.u128())?;
+    competition_module.inner_validate_auth(&info.sender, &tournament, false)?;
+
+    let key = (tournament_id.u128(), match_number.u128());
+    if !MATCH_DISPUTES.has(deps.storage, key) {
+        return Err(ContractError::StdError(StdError::NotFound {
+            kind: "Match dispute".to_string(),
+        }));
+    }
+    MATCH_DISPUTES.remove(deps.storage, key);
+
+    let response = if matches!(tournament.status, CompetitionStatus::Active { .. })
+        && tournament.extension.processed_matches >= tournament.extension.total_matches
+        && !has_open_disputes(deps.storage, tournament_id)
+    {
+        trigger_distribution(deps, tournament)?
+    } else {
+        Response::new()
+    };
+
+    Ok(response
+        .add_attribute("action", "dismiss_dispute")
+        .add_attribute("match_number", match_number.to_string()))
+}
+
+/// Sets a match's result as a single game, so a series takes the result directly, and advances the teams.
+fn set_match_result(
+    storage: &mut dyn Storage,
+    tournament_id: Uint128,
+    ext: &mut TournamentExt,
+    match_number: Uint128,
+    match_result: MatchResult,
+    track_ratings: bool,
+) -> StdResult<Vec<(MemberResult<Addr>, MemberResult<Addr>)>> {
+    let key = (tournament_id.u128(), match_number.u128());
+    let mut match_ = MATCHES.load(storage, key)?;
+    let best_of = match_.best_of.take();
+    match_.games = None;
+    MATCHES.save(storage, key, &match_)?;
+
+    let member_results = advance_matches(
+        storage,
+        tournament_id,
+        ext,
+        vec![MatchResultMsg {
+            match_number,
+            match_result,
+        }],
+        track_ratings,
+    )?;
+
+    MATCHES.update(storage, key, |x| -> StdResult<_> {
+        let mut match_ = x.unwrap();
+        match_.best_of = best_of;
+        Ok(match_)
+    })?;
+
+    Ok(member_results)
+}
+
+/// Reverses a previous result's rating adjustment, where each team receives the other team's result,
+/// then applies the corrected result.
+fn corrected_member_results(
+    match_: &Match,
+    previous_result: &MatchResult,
+    match_result: &MatchResult,
+) -> Vec<(MemberResult<Addr>, MemberResult<Addr>)> {
+    let (member_result_1, member_result_2) = member_results_for(match_, previous_result);
+    let reversed_result = (
+        MemberResult {
+            result: member_result_2.result,
+            ..member_result_1
+        },
+        MemberResult {
+            result: member_result_1.result,
+            ..member_result_2
+        },
+    );
+
+    vec![reversed_result, member_results_for(match_, match_result)]
+}
+
+fn member_results_for(
+    match_: &Match,
+    match_result: &MatchResult,
+) -> (MemberResult<Addr>, MemberResult<Addr>) {
+    let (result_1, result_2) = match match_result {
+        MatchResult::Team1 => (Decimal::one(), Decimal::zero()),
+        MatchResult::Team2 => (Decimal::zero(), Decimal::one()),
+    };
+
+    (
+        MemberResult {
+            addr: match_.team_1.clone().unwrap(),
+            result: result_1,
+        },
+        MemberResult {
+            addr: match_.team_2.clone().unwrap(),
+            result: result_2,
+        },
+    )
+}
+
+fn ensure_dependents_unplayed(
+    storage: &dyn Storage,
+    tournament_id: Uint128,
+    match_: &Match,
+) -> Result<(), ContractError> {
+    for dependent_match in [match_.next_match_winner, match_.next_match_loser]
+        .into_iter()
+        .flatten()
+    {
+        let dependent_match =
+            MATCHES.load(storage, (tournament_id.u128(), dependent_match.u128()))?;
+        if dependent_match.result.is_some() || dependent_match.games.is_some() {
+            return Err(ContractError::StdError(StdError::generic_err(format!(
+                "Match number {} depends on this match and has already been played",
+                dependent_match.match_number
+            ))));
+        }
+    }
+
+    Ok(())
+}
+
+/// Ensures that none of the matches are disputed or depend on a disputed match.
+fn ensure_not_frozen(
+    storage: &dyn Storage,
+    tournament_id: Uint128,
+    match_numbers: impl Iterator<Item = Uint128>,
+) -> Result<(), ContractError> {
+    let mut queue = MATCH_DISPUTES
+        .prefix(tournament_id.u128())
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<VecDeque<_>>>()?;
+    if queue.is_empty() {
+        return Ok(());
+    }
+
+    // Follow the bracket from each disputed match
+    let mut frozen = BTreeSet::new();
+    while let Some(match_number) = queue.pop_front() {
+        if !frozen.insert(match_number) {
+            continue;
+        }
+
+        let match_ = MATCHES.load(storage, (tournament_id.u128(), match_number))?;
+        queue.extend(
+            [match_.next_match_winner, match_.next_match_loser]
+                .into_iter()
+                .flatten()
+                .map(|x| x.u128()),
+        );
+    }
+
+    for match_number in match_numbers {
+        if frozen.contains(&match_number.u128()) {
+            return Err(ContractError::StdError(StdError::generic_err(format!(
+                "Match number {} is frozen by a dispute",
+                match_number
+            ))));
+        }
+    }
+
+    Ok(())
+}
+
+fn has_open_disputes(storage: &dyn Storage, tournament_id: Uint128) -> bool {
+    MATCH_DISPUTES
+        .prefix(tournament_id.u128())
+        .keys(storage, None, None, Order::Ascending)
+        .next()
+        .is_some()
+}
+
+/// Applies match results to a bracket and advances the teams.
+///
+/// A single game match takes its result directly, and the result can be changed by processing it again.
+/// A best-of-N series records each result as a game, and only has a result once a team wins the majority of games.
+/// Teams advance through `next_match_winner` and `next_match_loser` once a match has a result.
+///
+/// Returns the member results of newly decided matches when `track_ratings` is set.
+pub fn advance_matches(
+    storage: &mut dyn Storage,
+    tournament_id: Uint128,
+    ext: &mut TournamentExt,
+    match_results: Vec<MatchResultMsg>,
+    track_ratings: bool,
+) -> StdResult<Vec<(MemberResult<Addr>, MemberResult<Addr>)>> {
     // Prepare updates for the next matches
     let mut updates = Vec::new();
     let mut newly_processed_matches = 0;
@@ -455,7 +1454,7 @@ pub fn process_matches(
     let mut member_results = vec![];
     for result in match_results {
         let mut match_ = MATCHES.update(
-            deps.storage,
+            storage,
             (tournament_id.u128(), result.match_number.u128()),
             |match_info| -> StdResult<_> {
                 let mut match_info = match_info.ok_or_else(|| {
@@ -466,26 +1465,53 @@ pub fn process_matches(
                     return Err(StdError::generic_err("Match is not populated yet"));
                 }
 
-                let previous_team = match match_info.result.as_ref() {
+                // Accumulate games for a series until a team has won the majority
+                let match_result = match match_info.best_of {
+                    Some(best_of) if best_of > 1 => {
+                        if match_info.result.is_some() {
+                            return Err(StdError::generic_err(format!(
+                                "The series for match number {} is already decided",
+                                result.match_number
+                            )));
+                        }
+
+                        let games = match_info.games.get_or_insert_with(Vec::new);
+                        games.push(result.match_result.clone());
+
+                        let wins = games.iter().filter(|x| **x == result.match_result).count();
+                        if wins <= best_of as usize / 2 {
+                            return Ok(match_info);
+                        }
+
+                        result.match_result.clone()
+                    }
+                    _ => result.match_result.clone(),
+                };
+
+                // The previously advanced winner and loser are replaced when a result is changed
+                let (previous_winner, previous_loser) = match match_info.result.as_ref() {
                     Some(previous_result) => {
-                        if *previous_result == result.match_result {
+                        if *previous_result == match_result {
                             return Ok(match_info);
                         }
-                        Some(match previous_result {
-                            MatchResult::Team1 => match_info.team_1.clone(),
-                            MatchResult::Team2 => match_info.team_2.clone(),
-                        })
+                        match previous_result {
+                            MatchResult::Team1 => {
+                                (match_info.team_1.clone(), match_info.team_2.clone())
+                            }
+                            MatchResult::Team2 => {
+                                (match_info.team_2.clone(), match_info.team_1.clone())
+                            }
+                        }
                     }
                     None => {
                         newly_processed_matches += 1;
-                        None
+                        (None, None)
                     }
-                }
-                .flatten();
+                };
 
                 // Rating updates are only handled the first time
-                if tournament.category_id.is_some() && match_info.result.is_none() {
-                    let (member_result_1, member_result_2) = match result.match_result {
+                if track_ratings && match_info.result.is_none() {
+                    let (member_result_1, member_result_2) = match match_result {
                         MatchResult::Team1 => (Decimal::one(), Decimal::zero()),
                         MatchResult::Team2 => (Decimal::zero(), Decimal::one()),
                     };
@@ -502,100 +1528,110 @@ pub fn process_matches(
                     ));
                 }
 
-                match_info.result = Some(result.match_result.clone());
+                match_info.result = Some(match_result.clone());
 
                 // Determine the winning and losing teams
-                let (winner_team, loser_team) = match result.match_result {
+                let (winner_team, loser_team) = match match_result {
                     MatchResult::Team1 => (match_info.team_1.clone(), match_info.team_2.clone()),
                     MatchResult::Team2 => (match_info.team_2.clone(), match_info.team_1.clone()),
                 };
 
                 // Update the next match with the losing team in double elimination and third place matches
                 if let Some(next_match_loser) = match_info.next_match_loser {
-                    updates.push((next_match_loser, loser_team, previous_team.clone()));
+                    updates.push((next_match_loser, loser_team, previous_loser));
                 }
 
                 // Update the next match with the winning team
                 if let Some(next_match_winner) = match_info.next_match_winner {
-                    updates.push((next_match_winner, winner_team, previous_team));
+                    updates.push((next_match_winner, winner_team, previous_winner));
                 }
 
                 Ok(match_info)
             },
         )?;
 
-        // If we're processing the last match of a double elim tournament, then we should add a rebuttal match if the loser's bracket won
-        if match_.match_number == tournament.extension.total_matches
-            && matches!(
-                tournament.extension.elimination_type,
-                EliminationType::DoubleElimination
-            )
-            && match_.is_losers_bracket.is_none()
-        {
-            let loser_final = MATCHES.load(
-                deps.storage,
-                (tournament_id.u128(), match_.match_number.u128() - 1),
-            )?;
+        // The grand final of a double elimination tournament is the last match, or the match before its reset
+        if *ext.elimination_type.bracket() == EliminationType::DoubleElimination {
+            let bracket_reset = ext.has_bracket_reset();
+            let total_matches = ext.total_matches;
+            let has_reset = match_.next_match_winner == Some(total_matches)
+                && match_.next_match_loser == Some(total_matches);
 
-            let grand_final_winner = match match_.result.as_ref().unwrap() {
-                MatchResult::Team1 => match_.team_1.clone(),
-                MatchResult::Team2 => match_.team_2.clone(),
-            }
-            .unwrap();
+            let is_grand_final = match_.is_losers_bracket.is_none()
+                && (match_.match_number == total_matches || has_reset);
 
-            let loser_final_winner = match loser_final.result.unwrap() {
-                MatchResult::Team1 => loser_final.team_1,
-                MatchResult::Team2 => loser_final.team_2,
-            }
-            .unwrap();
-
-            if grand_final_winner == loser_final_winner {
-                tournament.extension.total_matches += Uint128::one();
-                MATCHES.save(
-                    deps.storage,
-                    (
-                        tournament_id.u128(),
-                        tournament.extension.total_matches.u128(),
-                    ),
-                    &Match {
-                        match_number: tournament.extension.total_matches,
-                        team_1: match_.team_1.clone(),
-                        team_2: match_.team_2.clone(),
-                        result: None,
-                        next_match_winner: None,
-                        next_match_loser: None,
-                        is_losers_bracket: Some(true),
-                    },
+            if let Some(result) = match_.result.as_ref().filter(|_| is_grand_final) {
+                let loser_final = MATCHES.load(
+                    storage,
+                    (tournament_id.u128(), match_.match_number.u128() - 1),
                 )?;
 
-                match_.next_match_loser = Some(tournament.extension.total_matches);
-                match_.next_match_winner = Some(tournament.extension.total_matches);
+                let grand_final_winner = match result {
+                    MatchResult::Team1 => match_.team_1.clone(),
+                    MatchResult::Team2 => match_.team_2.clone(),
+                }
+                .unwrap();
 
-                MATCHES.save(
-                    deps.storage,
-                    (tournament_id.u128(), match_.match_number.u128()),
-                    &match_,
-                )?;
+                let loser_final_winner = match loser_final.result.unwrap() {
+                    MatchResult::Team1 => loser_final.team_1,
+                    MatchResult::Team2 => loser_final.team_2,
+                }
+                .unwrap();
+
+                if grand_final_winner == loser_final_winner {
+                    // The loser's bracket champion has their first loss, so a bracket reset is played if enabled
+                    if bracket_reset && !has_reset {
+                        ext.total_matches += Uint128::one();
+                        MATCHES.save(
+                            storage,
+                            (tournament_id.u128(), ext.total_matches.u128()),
+                            &Match {
+                                match_number: ext.total_matches,
+                                team_1: match_.team_1.clone(),
+                                team_2: match_.team_2.clone(),
+                                result: None,
+                                next_match_winner: None,
+                                next_match_loser: None,
+                                is_losers_bracket: Some(true),
+                                best_of: match_.best_of,
+                                games: None,
+                                placement: None,
+                            },
+                        )?;
+
+                        match_.next_match_loser = Some(ext.total_matches);
+                        match_.next_match_winner = Some(ext.total_matches);
+
+                        MATCHES.save(
+                            storage,
+                            (tournament_id.u128(), match_.match_number.u128()),
+                            &match_,
+                        )?;
+                    }
+                } else if has_reset {
+                    // The grand final result was changed, so the bracket reset is no longer needed
+                    let reset_match =
+                        MATCHES.load(storage, (tournament_id.u128(), total_matches.u128()))?;
+                    if reset_match.result.is_some() {
+                        ext.processed_matches -= Uint128::one();
+                    }
+                    MATCHES.remove(storage, (tournament_id.u128(), total_matches.u128()));
+                    ext.total_matches -= Uint128::one();
+                    updates.retain(|x| x.0 != total_matches);
+
+                    match_.next_match_loser = None;
+                    match_.next_match_winner = None;
+
+                    MATCHES.save(
+                        storage,
+                        (tournament_id.u128(), match_.match_number.u128()),
+                        &match_,
+                    )?;
+                }
             }
         }
     }
 
-    // Trigger rating adjustments
-    let mut sub_msgs = vec![];
-    if let Some(category_id) = tournament.category_id {
-        if CompetitionModule::default().query_is_dao_member(
-            deps.as_ref(),
-            &tournament.host,
-            tournament.start_height,
-        ) {
-            sub_msgs.push(CompetitionModule::default().trigger_rating_adjustment(
-                deps.storage,
-                category_id,
-                member_results,
-            )?);
-        }
-    }
-
     // Apply updates to the next matches
     let mut index = 0;
     while index < updates.len() {
@@ -603,7 +1639,7 @@ pub fn process_matches(
         index += 1;
 
         MATCHES.update(
-            deps.storage,
+            storage,
             (tournament_id.u128(), target_match_number.u128()),
             |target_match| -> StdResult<_> {
                 let mut target_match = target_match.ok_or_else(|| {
@@ -637,82 +1673,186 @@ pub fn process_matches(
     }
 
     // Update processed matches count
-    tournament.extension.processed_matches += Uint128::new(newly_processed_matches);
+    ext.processed_matches += Uint128::new(newly_processed_matches);
 
-    competition_module
-        .competitions
-        .save(deps.storage, tournament_id.u128(), &tournament)?;
+    Ok(member_results)
+}
 
-    // Trigger distribution if all matches are processed
-    let response = if tournament.extension.processed_matches >= tournament.extension.total_matches {
-        // Trigger the distribution logic here
-        trigger_distribution(deps, tournament)?
-    } else {
-        Response::new()
+fn trigger_distribution(deps: DepsMut, tournament: Tournament) -> Result<Response, ContractError> {
+    let placements = bracket_placements(deps.storage, tournament.id.u128(), &tournament.extension)?;
+
+    // Carried shares are paid first, and the bracket's distribution is scaled to the rest
+    let mut member_percentages: Vec<MemberPercentage<Addr>> = tournament
+        .extension
+        .carried_distribution
+        .clone()
+        .unwrap_or_default();
+    let bracket_share = Decimal::one().checked_sub(
+        member_percentages
+            .iter()
+            .map(|x| x.percentage)
+            .sum::<Decimal>(),
+    )?;
+
+    // Members sharing a placement tier split the percentages of the places it covers evenly
+    let mut percentages = tournament.extension.distribution.iter();
+    for tier in placements.iter() {
+        let tier_percentage = percentages
+            .by_ref()
+            .take(tier.len())
+            .sum::<Decimal>()
+            .checked_mul(bracket_share)?;
+        if tier_percentage.is_zero() {
+            continue;
+        }
+        let percentage =
+            tier_percentage.checked_div(Decimal::from_ratio(tier.len() as u128, 1u128))?;
+
+        for placement in tier {
+            match member_percentages.iter_mut().find(|x| x.addr == *placement) {
+                Some(member_percentage) => {
+                    member_percentage.percentage =
+                        member_percentage.percentage.checked_add(percentage)?;
+                }
+                None => member_percentages.push(MemberPercentage {
+                    addr: placement.clone(),
+                    percentage,
+                }),
+            }
+        }
+    }
+
+    // Set remainder address to first place
+    let remainder_addr = placements[0][0].to_owned();
+
+    // Give any rounding remainder from the scaled shares to first place
+    let remainder_percentage = Decimal::one().checked_sub(
+        member_percentages
+            .iter()
+            .map(|x| x.percentage)
+            .sum::<Decimal>(),
+    )?;
+    if !remainder_percentage.is_zero() {
+        if let Some(member_percentage) = member_percentages
+            .iter_mut()
+            .find(|x| x.addr == remainder_addr)
+        {
+            member_percentage.percentage = member_percentage
+                .percentage
+                .checked_add(remainder_percentage)?;
+        }
+    }
+
+    let distribution = Distribution {
+        member_percentages,
+        remainder_addr,
+        fixed_amounts: vec![],
+        remainder_shares: vec![],
     };
 
-    Ok(response
-        .add_attribute("action", "process_matches")
-        .add_submessages(sub_msgs))
+    Ok(CompetitionModule::default().inner_process(
+        deps,
+        &tournament,
+        Some(distribution),
+        None,
+        None,
+    )?)
 }
 
-fn trigger_distribution(deps: DepsMut, tournament: Tournament) -> Result<Response, ContractError> {
-    let mut placements: Vec<Addr> = Vec::new();
+/// Returns the winner and loser of a match with a result
+fn match_outcome(match_: &Match) -> Option<(Addr, Addr)> {
+    match (&match_.team_1, &match_.team_2, &match_.result) {
+        (Some(team_1), Some(team_2), Some(MatchResult::Team1)) => {
+            Some((team_1.clone(), team_2.clone()))
+        }
+        (Some(team_1), Some(team_2), Some(MatchResult::Team2)) => {
+            Some((team_2.clone(), team_1.clone()))
+        }
+        _ => None,
+    }
+}
 
-    match tournament.extension.elimination_type {
+/// Returns the members of a completed bracket in order of placement
+/// Each tier holds the members sharing a placement, which is a single member unless same-round losers share their places
+pub fn bracket_placements(
+    storage: &dyn Storage,
+    tournament_id: u128,
+    ext: &TournamentExt,
+) -> StdResult<Vec<Vec<Addr>>> {
+    let mut placements: Vec<Vec<Addr>> = Vec::new();
+
+    match *ext.elimination_type.bracket() {
         EliminationType::SingleElimination {
             play_third_place_match,
         } => {
-            // Load the final match
-            let final_match = MATCHES.load(
-                deps.storage,
-                (
-                    tournament.id.u128(),
-                    tournament.extension.total_matches.u128(),
-                ),
-            )?;
-            let (first_place, second_place) = match final_match.result.as_ref().unwrap() {
-                MatchResult::Team1 => (
-                    final_match.team_1.as_ref().unwrap(),
-                    final_match.team_2.as_ref().unwrap(),
-                ),
-                MatchResult::Team2 => (
-                    final_match.team_2.as_ref().unwrap(),
-                    final_match.team_1.as_ref().unwrap(),
-                ),
-            };
-            placements.push(first_place.to_owned());
-            placements.push(second_place.to_owned());
+            let matches = MATCHES
+                .prefix(tournament_id)
+                .range(storage, None, None, cosmwasm_std::Order::Ascending)
+                .collect::<StdResult<BTreeMap<_, _>>>()?;
+
+            // The final match
+            let final_match = &matches[&ext.total_matches.u128()];
+            let (first_place, second_place) = match_outcome(final_match).unwrap();
+            placements.push(vec![first_place]);
+            placements.push(vec![second_place]);
 
             if play_third_place_match {
-                // Load the third place match
-                let third_place_match = MATCHES.load(
-                    deps.storage,
-                    (
-                        tournament.id.u128(),
-                        tournament.extension.total_matches.u128() - 1,
-                    ),
-                )?;
-                let (third_place, fourth_place) = match third_place_match.result.as_ref().unwrap() {
-                    MatchResult::Team1 => (
-                        third_place_match.team_1.as_ref().unwrap(),
-                        third_place_match.team_2.as_ref().unwrap(),
-                    ),
-                    MatchResult::Team2 => (
-                        third_place_match.team_2.as_ref().unwrap(),
-                        third_place_match.team_1.as_ref().unwrap(),
-                    ),
-                };
-                placements.push(third_place.to_owned());
-                placements.push(fourth_place.to_owned());
+                // The third place match
+                let third_place_match = &matches[&(ext.total_matches.u128() - 1)];
+                let (third_place, fourth_place) = match_outcome(third_place_match).unwrap();
+                placements.push(vec![third_place]);
+                placements.push(vec![fourth_place]);
+            }
+
+            match ext.placements {
+                Some(PlacementMode::SharedTiers) => {
+                    // Losers are grouped by how many rounds their match was from the final
+                    let mut tiers: BTreeMap<usize, Vec<Addr>> = BTreeMap::new();
+                    for match_ in matches.values() {
+                        if match_.placement.is_some()
+                            || match_.next_match_loser.is_some()
+                            || match_.next_match_winner.is_none()
+                        {
+                            continue;
+                        }
+
+                        let mut rounds = 0;
+                        let mut next_match = match_.next_match_winner;
+                        while let Some(match_number) = next_match {
+                            rounds += 1;
+                            next_match = matches[&match_number.u128()].next_match_winner;
+                        }
+
+                        if let Some((_, loser)) = match_outcome(match_) {
+                            tiers.entry(rounds).or_default().push(loser);
+                        }
+                    }
+
+                    placements.extend(tiers.into_values());
+                }
+                Some(PlacementMode::FifthToEighth | PlacementMode::Full) => {
+                    // Only the last match of each placement bracket decides places
+                    let mut deciding_matches = matches
+                        .values()
+                        .filter(|x| x.placement.is_some() && x.next_match_winner.is_none())
+                        .collect_vec();
+                    deciding_matches.sort_by_key(|x| x.placement);
+
+                    for match_ in deciding_matches {
+                        let (winner, loser) = match_outcome(match_).unwrap();
+                        placements.push(vec![winner]);
+                        placements.push(vec![loser]);
+                    }
+                }
+                None => {}
             }
         }
         EliminationType::DoubleElimination => {
             // Load the final matches
             // [Rebuttal?, final, and losers final]
             let final_matches = MATCHES
-                .prefix(tournament.id.u128())
-                .range(deps.storage, None, None, cosmwasm_std::Order::Descending)
+                .prefix(tournament_id)
+                .range(storage, None, None, cosmwasm_std::Order::Descending)
                 .take(3)
                 .map(|x| x.map(|y| y.1))
                 .collect::<StdResult<Vec<_>>>()?;
@@ -722,48 +1862,19 @@ fn trigger_distribution(deps: DepsMut, tournament: Tournament) -> Result<Respons
                 None => (&final_matches[0], &final_matches[1]),
             };
 
-            let (first_place, second_place) = match final_match.result.as_ref().unwrap() {
-                MatchResult::Team1 => (
-                    final_match.team_1.as_ref().unwrap(),
-                    final_match.team_2.as_ref().unwrap(),
-                ),
-                MatchResult::Team2 => (
-                    final_match.team_2.as_ref().unwrap(),
-                    final_match.team_1.as_ref().unwrap(),
-                ),
-            };
-            placements.push(first_place.to_owned());
-            placements.push(second_place.to_owned());
+            let (first_place, second_place) = match_outcome(final_match).unwrap();
+            placements.push(vec![first_place]);
+            placements.push(vec![second_place]);
 
-            let third_place = match losers_final_match.result.as_ref().unwrap() {
-                MatchResult::Team1 => losers_final_match.team_2.as_ref().unwrap(),
-                MatchResult::Team2 => losers_final_match.team_1.as_ref().unwrap(),
-            };
-            placements.push(third_place.to_owned());
+            let (_, third_place) = match_outcome(losers_final_match).unwrap();
+            placements.push(vec![third_place]);
+        }
+        EliminationType::RoundRobinGroups { .. } => {
+            return Err(StdError::generic_err(
+                "A group stage must be followed by an elimination bracket",
+            ));
         }
     }
 
-    // Implement the distribution logic here using the `placements` vector
-    let mut member_percentages: Vec<MemberPercentage<Addr>> = Vec::new();
-    for (percentage, placement) in tournament
-        .extension
-        .distribution
-        .iter()
-        .zip(placements.iter())
-    {
-        member_percentages.push(MemberPercentage {
-            addr: placement.clone(),
-            percentage: *percentage,
-        });
-    }
-
-    // Set remainder address to first place
-    let remainder_addr = placements.first().unwrap().to_owned();
-
-    let distribution = Distribution {
-        member_percentages,
-        remainder_addr,
-    };
-
-    Ok(CompetitionModule::default().inner_process(deps, &tournament, Some(distribution))?)
+    Ok(placements)
 }
@@ -1,13 +1,19 @@
-use crate::state::{EliminationType, MatchResult, TournamentExt};
+use std::collections::BTreeSet;
+
+use crate::state::{
+    EliminationType, GroupMatch, GroupMatchResult, GroupStage, MatchResult, PlacementMode, Seeding,
+    SeriesConfig, TournamentExt,
+};
 use arena_interface::{
     competition::{
         msg::{ExecuteBase, InstantiateBase, MigrateBase, QueryBase, ToCompetitionExt},
-        state::{Competition, CompetitionResponse},
+        state::{Competition, CompetitionResponse, MatchDispute},
     },
     group,
 };
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Decimal, Empty, StdError, StdResult, Uint128, Uint64};
+use cosmwasm_std::{Addr, Decimal, Empty, HexBinary, StdError, StdResult, Uint128, Uint64};
+use cw_balance::MemberPercentage;
 
 #[cw_serde]
 #[derive(cw_orch::ExecuteFns)]
@@ -16,6 +22,46 @@ pub enum ExecuteExt {
         tournament_id: Uint128,
         match_results: Vec<MatchResultMsg>,
     },
+    /// The bracket is seeded from the group standings once every group match has a result
+    ProcessGroupMatch {
+        tournament_id: Uint128,
+        group: Uint64,
+        match_results: Vec<GroupMatchResultMsg>,
+    },
+    /// Corrects a processed match's result while its dependent matches have not been played
+    CorrectMatch {
+        tournament_id: Uint128,
+        match_number: Uint128,
+        match_result: MatchResult,
+    },
+    /// Disputes a match that the sender plays in, and proposes its result to the arena DAO
+    /// The match and the matches that depend on it are frozen, but the rest of the bracket keeps running
+    #[cw_orch(payable)]
+    DisputeMatch {
+        tournament_id: Uint128,
+        match_number: Uint128,
+        match_result: MatchResult,
+        title: String,
+        description: String,
+        evidence: Vec<String>,
+    },
+    /// Callable only by the arena DAO to decide a disputed match's result
+    ResolveDispute {
+        tournament_id: Uint128,
+        match_number: Uint128,
+        match_result: MatchResult,
+    },
+    /// Dismisses a disputed match, such as after its proposal was rejected, and keeps the match's current result
+    /// Callable by the host or the arena DAO
+    DismissDispute {
+        tournament_id: Uint128,
+        match_number: Uint128,
+    },
+    /// Callable only by the arena DAO to submit the randomness of a randomly seeded tournament, which generates its bracket
+    SubmitRandomness {
+        tournament_id: Uint128,
+        randomness: HexBinary,
+    },
     InstantiateTournament {},
 }
 
@@ -38,6 +84,24 @@ pub enum QueryExt {
         tournament_id: Uint128,
         match_number: Uint128,
     },
+    #[returns(Option<MatchDispute>)]
+    MatchDispute {
+        tournament_id: Uint128,
+        match_number: Uint128,
+    },
+    #[returns(Vec<GroupResponse>)]
+    Groups { tournament_id: Uint128 },
+    #[returns(Vec<GroupStanding>)]
+    GroupStandings {
+        tournament_id: Uint128,
+        group: Uint64,
+    },
+    #[returns(Vec<GroupMatch>)]
+    GroupMatches {
+        tournament_id: Uint128,
+        group: Uint64,
+        round_number: Option<Uint64>,
+    },
 }
 
 impl From<QueryExt> for QueryMsg {
@@ -52,6 +116,27 @@ pub struct MatchResultMsg {
     pub match_result: MatchResult,
 }
 
+#[cw_serde]
+pub struct GroupMatchResultMsg {
+    pub match_number: Uint128,
+    pub match_result: GroupMatchResult,
+}
+
+#[cw_serde]
+pub struct GroupResponse {
+    pub group: Uint64,
+    pub members: Vec<Addr>,
+}
+
+#[cw_serde]
+pub struct GroupStanding {
+    pub member: Addr,
+    pub points: Uint64,
+    pub wins: Uint64,
+    pub draws: Uint64,
+    pub losses: Uint64,
+}
+
 #[cw_serde]
 #[serde(untagged)]
 pub enum MigrateMsg {
@@ -63,12 +148,26 @@ pub enum MigrateMsg {
 #[cw_serde]
 pub struct SudoMsg {
     pub matches: Vec<crate::state::Match>,
+    pub group_response: GroupResponse,
+    pub group_standing: GroupStanding,
+    pub group_match: GroupMatch,
+    pub match_dispute: MatchDispute,
 }
 
 #[cw_serde]
 pub struct TournamentInstantiateExt {
     pub elimination_type: EliminationType, // Enum for single or double elimination
     pub distribution: Vec<Decimal>,
+    pub series: Option<SeriesConfig>, // Best-of-N series lengths, single games if not set
+    /// Shares of the prize pool paid to members before the bracket's distribution, such as a league's regular season prizes
+    /// The bracket's distribution is paid from the rest of the prize pool
+    pub carried_distribution: Option<Vec<MemberPercentage<String>>>,
+    /// How the teams are seeded into the bracket, the group's seed order if not set
+    pub seeding: Option<Seeding<String>>,
+    /// How places beyond the final and third place match are decided in single elimination
+    pub placements: Option<PlacementMode>,
+    /// Whether a double elimination bracket plays a second grand final if the loser's bracket champion wins the first, true if not set
+    pub bracket_reset: Option<bool>,
 }
 
 impl ToCompetitionExt<TournamentExt> for TournamentInstantiateExt {
@@ -88,25 +187,106 @@ impl ToCompetitionExt<TournamentExt> for TournamentInstantiateExt {
             });
         }
 
-        let max_placements = match self.elimination_type {
+        // Teams advancing from a group stage are seeded into the bracket
+        if let EliminationType::RoundRobinGroups {
+            group_size,
+            advance_per_group,
+            ..
+        } = &self.elimination_type
+        {
+            if *group_size < Uint64::new(2) {
+                return Err(StdError::generic_err(
+                    "Each group should have at least 2 teams",
+                ));
+            }
+
+            let group_count = self
+                .elimination_type
+                .group_count(team_count)
+                .unwrap_or_default();
+            if advance_per_group.is_zero() || team_count / group_count <= *advance_per_group {
+                return Err(StdError::generic_err(
+                    "Each group must have more teams than it advances to the bracket",
+                ));
+            }
+        }
+        let bracket_teams = self.elimination_type.bracket_teams(team_count);
+        if bracket_teams < Uint64::new(2) {
+            return Err(StdError::generic_err(
+                "At least 2 teams should advance to the bracket",
+            ));
+        }
+
+        let max_placements = match *self.elimination_type.bracket() {
             EliminationType::SingleElimination {
                 play_third_place_match,
             } => {
-                if play_third_place_match {
-                    if team_count < Uint64::new(4) {
-                        return Err(StdError::GenericErr {
-                            msg: "At least 4 teams should be provided for a 3rd place match"
-                                .to_string(),
-                        });
-                    }
+                if play_third_place_match && bracket_teams < Uint64::new(4) {
+                    return Err(StdError::GenericErr {
+                        msg: "At least 4 teams should be provided for a 3rd place match"
+                            .to_string(),
+                    });
+                }
 
-                    Uint64::min(team_count, Uint64::new(4))
-                } else {
-                    Uint64::new(2)
+                match self.placements {
+                    Some(PlacementMode::SharedTiers) => bracket_teams,
+                    Some(placements) => {
+                        if !play_third_place_match {
+                            return Err(StdError::generic_err(
+                                "Placement matches require a 3rd place match",
+                            ));
+                        }
+                        if placements == PlacementMode::FifthToEighth
+                            && bracket_teams < Uint64::new(8)
+                        {
+                            return Err(StdError::generic_err(
+                                "At least 8 teams should be provided for 5th to 8th place matches",
+                            ));
+                        }
+                        if placements == PlacementMode::Full
+                            && !bracket_teams.u64().is_power_of_two()
+                        {
+                            return Err(StdError::generic_err(
+                                "Full placement matches require a power of two teams",
+                            ));
+                        }
+
+                        match placements {
+                            PlacementMode::FifthToEighth => Uint64::new(8),
+                            _ => bracket_teams,
+                        }
+                    }
+                    None => {
+                        if play_third_place_match {
+                            Uint64::min(bracket_teams, Uint64::new(4))
+                        } else {
+                            Uint64::new(2)
+                        }
+                    }
+                }
+            }
+            EliminationType::DoubleElimination => {
+                if self.placements.is_some() {
+                    return Err(StdError::generic_err(
+                        "Placements can only be configured for single elimination",
+                    ));
                 }
+
+                Uint64::min(bracket_teams, Uint64::new(3))
+            }
+            EliminationType::RoundRobinGroups { .. } => {
+                return Err(StdError::generic_err(
+                    "A group stage must be followed by an elimination bracket",
+                ));
             }
-            EliminationType::DoubleElimination => Uint64::min(team_count, Uint64::new(3)),
         };
+        if self.bracket_reset.is_some()
+            && *self.elimination_type.bracket() != EliminationType::DoubleElimination
+        {
+            return Err(StdError::generic_err(
+                "A bracket reset can only be configured for double elimination",
+            ));
+        }
 
         if Uint64::new(self.distribution.len() as u64) > max_placements {
             return Err(StdError::GenericErr {
@@ -117,26 +297,107 @@ impl ToCompetitionExt<TournamentExt> for TournamentInstantiateExt {
         if self.distribution.iter().sum::<Decimal>() != Decimal::one() {
             return Err(StdError::generic_err("The distribution must sum up to 1"));
         }
+        if let Some(series) = &self.series {
+            if std::iter::once(series.best_of)
+                .chain(series.stage_overrides.iter().map(|x| x.best_of))
+                .any(|x| x % 2 == 0)
+            {
+                return Err(StdError::generic_err(
+                    "A series must have an odd number of games",
+                ));
+            }
+        }
 
-        let total_matches = if let EliminationType::SingleElimination {
-            play_third_place_match,
-        } = self.elimination_type
-        {
-            (team_count - Uint64::one())
-                + if play_third_place_match {
-                    Uint64::one()
-                } else {
-                    Uint64::zero()
+        let carried_distribution = self
+            .carried_distribution
+            .as_ref()
+            .map(|member_percentages| -> StdResult<_> {
+                if member_percentages
+                    .iter()
+                    .map(|x| x.percentage)
+                    .sum::<Decimal>()
+                    > Decimal::one()
+                {
+                    return Err(StdError::generic_err(
+                        "The carried distribution cannot sum up to more than 1",
+                    ));
                 }
-        } else {
-            Uint64::new(2) * (team_count - Uint64::one()) // + R (rebuttal match)
-        };
+                if !deps.querier.query_wasm_smart::<bool>(
+                    group_contract.to_string(),
+                    &group::QueryMsg::IsValidDistribution {
+                        addrs: member_percentages.iter().map(|x| x.addr.clone()).collect(),
+                    },
+                )? {
+                    return Err(StdError::generic_err(
+                        "The carried distribution must contain only members of the competition",
+                    ));
+                }
+
+                member_percentages
+                    .iter()
+                    .map(|x| x.into_checked(deps))
+                    .collect::<StdResult<Vec<_>>>()
+            })
+            .transpose()?;
+
+        let seeding = self
+            .seeding
+            .as_ref()
+            .map(|seeding| -> StdResult<_> {
+                Ok(match seeding {
+                    Seeding::GroupSeed => Seeding::GroupSeed,
+                    Seeding::Random {} => Seeding::Random {},
+                    Seeding::Rating => Seeding::Rating,
+                    Seeding::Manual { teams } => {
+                        let teams = teams
+                            .iter()
+                            .map(|x| deps.api.addr_validate(x))
+                            .collect::<StdResult<Vec<_>>>()?;
+
+                        if Uint64::new(teams.len() as u64) != team_count
+                            || teams.iter().collect::<BTreeSet<_>>().len() != teams.len()
+                            || !deps.querier.query_wasm_smart::<bool>(
+                                group_contract.to_string(),
+                                &group::QueryMsg::IsValidDistribution {
+                                    addrs: teams.iter().map(|x| x.to_string()).collect(),
+                                },
+                            )?
+                        {
+                            return Err(StdError::generic_err(
+                                "Manual seeding must contain every team exactly once",
+                            ));
+                        }
+
+                        Seeding::Manual { teams }
+                    }
+                })
+            })
+            .transpose()?;
+
+        let total_matches = self.elimination_type.total_matches(team_count)
+            + self
+                .placements
+                .map(|x| x.placement_matches(bracket_teams))
+                .unwrap_or_default();
 
         Ok(TournamentExt {
             distribution: self.distribution.clone(),
-            elimination_type: self.elimination_type,
+            elimination_type: self.elimination_type.clone(),
             total_matches: total_matches.into(),
             processed_matches: Uint128::zero(),
+            series: self.series.clone(),
+            carried_distribution,
+            seeding,
+            placements: self.placements,
+            // The group matches are counted when the groups are drawn
+            group_stage: self
+                .elimination_type
+                .group_count(team_count)
+                .map(|_| GroupStage {
+                    group_matches: Uint128::zero(),
+                    processed_group_matches: Uint128::zero(),
+                }),
+            bracket_reset: self.bracket_reset,
         })
     }
 }
@@ -1,7 +1,17 @@
-use cosmwasm_std::{Deps, Order, StdResult, Uint128};
-use cw_storage_plus::Bound;
+use std::collections::BTreeMap;
 
-use crate::state::{Match, MATCHES};
+use arena_interface::competition::state::MatchDispute;
+use cosmwasm_std::{Addr, Deps, Order, StdResult, Uint128, Uint64};
+use cw_storage_plus::{Bound, Map};
+
+use crate::{
+    msg::{GroupResponse, GroupStanding},
+    state::{GroupMatch, GroupMatchResult, Match, GROUPS, GROUP_MATCHES, MATCHES, MATCH_DISPUTES},
+};
+
+/// Group matches award points like most group stages, 3 for a win and 1 for a draw
+const GROUP_WIN_POINTS: u64 = 3;
+const GROUP_DRAW_POINTS: u64 = 1;
 
 pub fn query_bracket(
     deps: Deps,
@@ -20,3 +30,135 @@ pub fn query_bracket(
 pub fn query_match(deps: Deps, tournament_id: Uint128, match_number: Uint128) -> StdResult<Match> {
     MATCHES.load(deps.storage, (tournament_id.u128(), match_number.u128()))
 }
+
+pub fn query_match_dispute(
+    deps: Deps,
+    tournament_id: Uint128,
+    match_number: Uint128,
+) -> StdResult<Option<MatchDispute>> {
+    MATCH_DISPUTES.may_load(deps.storage, (tournament_id.u128(), match_number.u128()))
+}
+
+pub fn query_groups(deps: Deps, tournament_id: Uint128) -> StdResult<Vec<GroupResponse>> {
+    group_responses(deps, &GROUPS, tournament_id)
+}
+
+/// Lists a competition's groups from a module's group storage.
+pub fn group_responses(
+    deps: Deps,
+    groups: &Map<(u128, u64), Vec<Addr>>,
+    competition_id: Uint128,
+) -> StdResult<Vec<GroupResponse>> {
+    groups
+        .prefix(competition_id.u128())
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|x| {
+            x.map(|(group, members)| GroupResponse {
+                group: Uint64::new(group),
+                members,
+            })
+        })
+        .collect()
+}
+
+/// Calculates the standings of a group.
+///
+/// # Details
+/// - A win is worth 3 points and a draw 1 point.
+/// - The standings are sorted by points, then by wins.
+/// - Remaining ties keep the group's seed order.
+pub fn query_group_standings(
+    deps: Deps,
+    tournament_id: Uint128,
+    group: Uint64,
+) -> StdResult<Vec<GroupStanding>> {
+    let members = GROUPS.load(deps.storage, (tournament_id.u128(), group.u64()))?;
+
+    let mut records = empty_standings(&members);
+
+    for m in GROUP_MATCHES
+        .prefix((tournament_id.u128(), group.u64()))
+        .range(deps.storage, None, None, Order::Ascending)
+    {
+        let (_, m) = m?;
+
+        if let Some(result) = m.result {
+            let (winner, loser) = match result {
+                GroupMatchResult::Team1 => (m.team_1, m.team_2),
+                GroupMatchResult::Team2 => (m.team_2, m.team_1),
+                GroupMatchResult::Draw => {
+                    for team in [m.team_1, m.team_2] {
+                        if let Some(record) = records.get_mut(&team) {
+                            record.points =
+                                record.points.checked_add(Uint64::new(GROUP_DRAW_POINTS))?;
+                            record.draws = record.draws.checked_add(Uint64::one())?;
+                        }
+                    }
+                    continue;
+                }
+            };
+
+            if let Some(record) = records.get_mut(&winner) {
+                record.points = record.points.checked_add(Uint64::new(GROUP_WIN_POINTS))?;
+                record.wins = record.wins.checked_add(Uint64::one())?;
+            }
+            if let Some(record) = records.get_mut(&loser) {
+                record.losses = record.losses.checked_add(Uint64::one())?;
+            }
+        }
+    }
+
+    Ok(rank_standings(&members, records))
+}
+
+/// Starts an empty standing for each member of a group.
+pub fn empty_standings(members: &[Addr]) -> BTreeMap<Addr, GroupStanding> {
+    members
+        .iter()
+        .map(|member| {
+            (
+                member.clone(),
+                GroupStanding {
+                    member: member.clone(),
+                    points: Uint64::zero(),
+                    wins: Uint64::zero(),
+                    draws: Uint64::zero(),
+                    losses: Uint64::zero(),
+                },
+            )
+        })
+        .collect()
+}
+
+/// Sorts a group's standings by points, then by wins.
+///
+/// Remaining ties keep the group's seed order because the sort is stable.
+pub fn rank_standings(
+    members: &[Addr],
+    mut records: BTreeMap<Addr, GroupStanding>,
+) -> Vec<GroupStanding> {
+    let mut standings: Vec<GroupStanding> = members
+        .iter()
+        .filter_map(|member| records.remove(member))
+        .collect();
+    standings.sort_by(|a, b| b.points.cmp(&a.points).then(b.wins.cmp(&a.wins)));
+
+    standings
+}
+
+pub fn query_group_matches(
+    deps: Deps,
+    tournament_id: Uint128,
+    group: Uint64,
+    round_number: Option<Uint64>,
+) -> StdResult<Vec<GroupMatch>> {
+    GROUP_MATCHES
+        .prefix((tournament_id.u128(), group.u64()))
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|x| x.map(|y| y.1))
+        .filter(|x| match (x, round_number) {
+            (Ok(m), Some(round_number)) => m.round_number == round_number,
+            _ => true,
+        })
+        .collect()
+}
@@ -1,5 +1,8 @@
+use arena_interface::competition::state::MatchDispute;
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Decimal, Uint128};
+use cosmwasm_std::{Addr, Decimal, HexBinary, Uint128, Uint64};
+use cw_address_like::AddressLike;
+use cw_balance::MemberPercentage;
 use cw_storage_plus::Map;
 
 #[cw_serde]
@@ -11,6 +14,12 @@ pub struct Match {
     pub next_match_winner: Option<Uint128>, // Next match for the winner
     pub next_match_loser: Option<Uint128>,  // Next match for the loser (used in double elimination)
     pub is_losers_bracket: Option<bool>, // Is match a part of the loser's bracket (used in double elimination)
+    pub best_of: Option<u32>,            // Number of games in the series, a single game if not set
+    pub games: Option<Vec<MatchResult>>, // Game-by-game results of the series
+    /// The first place contested by a placement bracket's match
+    /// The winner and loser of a placement match without a next match take this place and the next
+    #[serde(default)]
+    pub placement: Option<u32>,
 }
 
 #[cw_serde]
@@ -25,14 +34,240 @@ pub struct TournamentExt {
     pub distribution: Vec<Decimal>,
     pub total_matches: Uint128,
     pub processed_matches: Uint128,
+    pub series: Option<SeriesConfig>,
+    /// Shares of the prize pool paid before the bracket's distribution
+    #[serde(default)]
+    pub carried_distribution: Option<Vec<MemberPercentage<Addr>>>,
+    /// How the teams are seeded into the bracket, the group's seed order if not set
+    #[serde(default)]
+    pub seeding: Option<Seeding<Addr>>,
+    /// How places beyond the final and third place match are decided in single elimination
+    #[serde(default)]
+    pub placements: Option<PlacementMode>,
+    /// The progress of the round-robin groups played before the bracket
+    #[serde(default)]
+    pub group_stage: Option<GroupStage>,
+    /// Whether a double elimination bracket plays a second grand final if the loser's bracket champion wins the first
+    #[serde(default)]
+    pub bracket_reset: Option<bool>,
 }
 
+impl TournamentExt {
+    /// Double elimination brackets play a bracket reset unless it is disabled
+    pub fn has_bracket_reset(&self) -> bool {
+        self.bracket_reset.unwrap_or(true)
+    }
+}
+
+#[cw_serde]
+pub struct GroupStage {
+    pub group_matches: Uint128,
+    pub processed_group_matches: Uint128,
+}
+
+impl GroupStage {
+    pub fn is_complete(&self) -> bool {
+        self.processed_group_matches >= self.group_matches
+    }
+}
+
+#[cw_serde]
+pub struct GroupMatch {
+    pub match_number: Uint128,
+    pub round_number: Uint64,
+    pub team_1: Addr,
+    pub team_2: Addr,
+    pub result: Option<GroupMatchResult>,
+}
+
+#[cw_serde]
+pub enum GroupMatchResult {
+    Team1,
+    Team2,
+    Draw,
+}
+
+/// How the places below the semifinals are decided in a single elimination bracket
 #[cw_serde]
 #[derive(Copy)]
+pub enum PlacementMode {
+    /// Teams eliminated in the same round share a placement tier, and the tier's prizes are split evenly
+    SharedTiers,
+    /// The quarterfinal losers play placement matches for 5th through 8th place
+    FifthToEighth,
+    /// Placement matches are played for every place, which requires a power of two teams
+    Full,
+}
+
+impl PlacementMode {
+    /// Whether placement matches are played for the places starting at `place`
+    pub fn plays_for(&self, place: u64) -> bool {
+        match self {
+            PlacementMode::SharedTiers => false,
+            PlacementMode::FifthToEighth => place <= 8,
+            PlacementMode::Full => true,
+        }
+    }
+
+    /// The number of placement matches below the third place match
+    pub fn placement_matches(&self, team_count: Uint64) -> Uint64 {
+        let bracket_size = team_count.u64().next_power_of_two();
+
+        // The losers of each round before the semifinals play for the places after the teams still in
+        let mut matches = 0;
+        let mut losers = 4;
+        while losers < bracket_size {
+            if self.plays_for(losers + 1) {
+                matches += self.placement_bracket_matches(losers, losers + 1);
+            }
+            losers *= 2;
+        }
+
+        Uint64::new(matches)
+    }
+
+    fn placement_bracket_matches(&self, entrants: u64, first_place: u64) -> u64 {
+        if entrants == 2 {
+            return 1;
+        }
+
+        let half = entrants / 2;
+        half + self.placement_bracket_matches(half, first_place)
+            + if self.plays_for(first_place + half) {
+                self.placement_bracket_matches(half, first_place + half)
+            } else {
+                0
+            }
+    }
+}
+
+/// How the teams are ordered into the bracket's seeds
+/// Seeds are placed so that the top seeds can only meet in the latest possible round, and seeds 1 and 2 only in the final
+#[cw_serde]
+pub enum Seeding<T: AddressLike> {
+    /// The group contract's seed order
+    GroupSeed,
+    /// Teams are ordered by the hash of the randomness and their address
+    /// The randomness is submitted by the arena DAO after creation, so the host cannot choose it, and the bracket is generated once it is submitted
+    Random {},
+    /// Teams are ordered by their rating in the tournament's category, and unrated teams are seeded last
+    Rating,
+    /// Teams are seeded in the given order, which must contain every team
+    Manual { teams: Vec<T> },
+}
+
+#[cw_serde]
+pub struct SeriesConfig {
+    /// The number of games in a series unless overridden by a stage, must be odd
+    pub best_of: u32,
+    /// Series lengths for specific stages of the bracket
+    pub stage_overrides: Vec<StageBestOf>,
+}
+
+impl SeriesConfig {
+    pub fn best_of(&self, stage: &BracketStage) -> u32 {
+        self.stage_overrides
+            .iter()
+            .find(|x| x.stage == *stage)
+            .map(|x| x.best_of)
+            .unwrap_or(self.best_of)
+    }
+}
+
+#[cw_serde]
+pub struct StageBestOf {
+    pub stage: BracketStage,
+    pub best_of: u32,
+}
+
+#[cw_serde]
+pub enum BracketStage {
+    /// A winners bracket round counted back from the final, where 1 is the round before the final
+    WinnersRound { rounds_before_final: u32 },
+    /// Every match in the loser's bracket of a double elimination tournament
+    LosersBracket,
+    /// The third place match of a single elimination tournament
+    ThirdPlaceMatch,
+    /// Every match of a single elimination tournament's placement brackets below third place
+    PlacementMatch,
+    /// The final, or the grand final of a double elimination tournament
+    Final,
+}
+
+#[cw_serde]
 pub enum EliminationType {
-    SingleElimination { play_third_place_match: bool },
+    SingleElimination {
+        play_third_place_match: bool,
+    },
     DoubleElimination,
+    /// Teams play round-robin groups, and the top teams of each group are seeded into the `then` bracket
+    RoundRobinGroups {
+        /// The most teams in a group, the teams are spread evenly across the fewest groups that fit them
+        group_size: Uint64,
+        advance_per_group: Uint64,
+        then: Box<EliminationType>,
+    },
+}
+
+impl EliminationType {
+    /// The bracket played after the group stage, or the elimination type itself without groups
+    pub fn bracket(&self) -> &EliminationType {
+        match self {
+            EliminationType::RoundRobinGroups { then, .. } => then,
+            _ => self,
+        }
+    }
+
+    /// The number of round-robin groups, if there is a group stage
+    pub fn group_count(&self, team_count: Uint64) -> Option<Uint64> {
+        match self {
+            EliminationType::RoundRobinGroups { group_size, .. } => Some(Uint64::new(
+                team_count.u64().div_ceil(group_size.u64().max(1)),
+            )),
+            _ => None,
+        }
+    }
+
+    /// The number of teams seeded into the bracket
+    pub fn bracket_teams(&self, team_count: Uint64) -> Uint64 {
+        match self {
+            EliminationType::RoundRobinGroups {
+                advance_per_group, ..
+            } => self.group_count(team_count).unwrap_or_default() * *advance_per_group,
+            _ => team_count,
+        }
+    }
+
+    /// The number of matches in a bracket, not including a bracket reset or the group stage
+    pub fn total_matches(&self, team_count: Uint64) -> Uint64 {
+        match self {
+            EliminationType::SingleElimination {
+                play_third_place_match,
+            } => {
+                (team_count - Uint64::one())
+                    + if *play_third_place_match {
+                        Uint64::one()
+                    } else {
+                        Uint64::zero()
+                    }
+            }
+            EliminationType::DoubleElimination => Uint64::new(2) * (team_count - Uint64::one()),
+            EliminationType::RoundRobinGroups { then, .. } => {
+                then.total_matches(self.bracket_teams(team_count))
+            }
+        }
+    }
 }
 
 /// (Tournament Id, Match Number)
 pub const MATCHES: Map<(u128, u128), Match> = Map::new("tournament_matches");
+/// (Tournament Id, Match Number) -> The open dispute on the match
+pub const MATCH_DISPUTES: Map<(u128, u128), MatchDispute> = Map::new("tournament_match_disputes");
+
+/// Tournament Id -> The randomness submitted by the arena DAO for random seeding
+pub const RANDOMNESS: Map<u128, HexBinary> = Map::new("tournament_randomness");
+
+/// (Tournament Id, Group Number) -> The group's teams in seed order
+pub const GROUPS: Map<(u128, u64), Vec<Addr>> = Map::new("tournament_groups");
+/// (Tournament Id, Group Number, Match Number)
+pub const GROUP_MATCHES: Map<(u128, u64, u128), GroupMatch> = Map::new("tournament_group_matches");
@@ -1,26 +1,33 @@
 use std::collections::HashSet;
 
 use arena_interface::{
-    competition::msg::MigrateBase,
+    competition::msg::{MigrateBase, QueryBase},
     group::{self, MemberMsg},
     ratings::MemberResult,
 };
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    Addr, Binary, Decimal, Deps, DepsMut, Empty, Env, MessageInfo, Reply, Response, StdResult,
-    SubMsg, Uint64,
+    to_json_binary, Addr, Binary, Decimal, Deps, DepsMut, Empty, Env, MessageInfo, Reply, Response,
+    StdResult, SubMsg, Uint64,
 };
 use cw2::{ensure_from_older_version, set_contract_version};
+use cw_balance::Distribution;
 use cw_competition_base::{contract::CompetitionModuleContract, error::CompetitionError};
 
-use crate::msg::{
-    ExecuteExt, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryExt, QueryMsg, Wager, WagerExt,
-    WagerInstantiateExt,
+use crate::{
+    execute,
+    msg::{
+        ExecuteExt, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryExt, QueryMsg, Wager, WagerExt,
+        WagerInstantiateExt,
+    },
+    query,
 };
 
 pub(crate) const CONTRACT_NAME: &str = "crates.io:arena-wager-module";
 pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+/// One week
+pub(crate) const DEFAULT_REFUND_GRACE_PERIOD: u64 = 604_800;
 pub type CompetitionModule<'a> =
     CompetitionModuleContract<'a, Empty, ExecuteExt, QueryExt, WagerExt, WagerInstantiateExt>;
 
@@ -44,21 +51,91 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response, CompetitionError> {
     match msg {
+        ExecuteMsg::CreateCompetition {
+            host,
+            category_id,
+            escrow,
+            name,
+            description,
+            date,
+            duration,
+            rules,
+            rulesets,
+            banner,
+            group_contract,
+            instantiate_extension,
+        } => {
+            if let Some(stakes) = &instantiate_extension.stakes {
+                execute::validate_stake_dues(deps.as_ref(), &escrow, stakes)?;
+            }
+
+            CompetitionModule::default().execute_create_competition(
+                &mut deps,
+                &env,
+                &info,
+                host,
+                category_id,
+                escrow,
+                name,
+                description,
+                date,
+                duration,
+                rules,
+                rulesets,
+                banner,
+                group_contract,
+                instantiate_extension,
+            )
+        }
         ExecuteMsg::ProcessCompetition {
             competition_id,
             distribution,
+            nft_allocations,
+            vesting,
         } => CompetitionModule::default().execute_process_competition(
             deps.branch(),
             info,
             competition_id,
             distribution,
+            nft_allocations,
+            vesting,
             Some(post_processing),
         ),
+        ExecuteMsg::Extension { msg } => match msg {
+            ExecuteExt::SubmitClaim {
+                wager_id,
+                distribution,
+            } => execute::submit_claim(deps, env, info, wager_id, distribution),
+            ExecuteExt::ProcessWinner { wager_id, winner } => {
+                execute::process_winner(deps, info, wager_id, winner)
+            }
+            ExecuteExt::ChooseSide { wager_id, side } => {
+                execute::choose_side(deps, info, wager_id, side)
+            }
+            ExecuteExt::ProcessSide { wager_id, side } => {
+                execute::process_side(deps, info, wager_id, side)
+            }
+            ExecuteExt::Expire { wager_id } => execute::expire(deps, env, wager_id),
+        },
         _ => CompetitionModule::default().execute(deps, env, info, msg),
     }
 }
 
 fn post_processing(deps: DepsMut, competition: &Wager) -> Result<Option<SubMsg>, CompetitionError> {
+    // This will be in state
+    let result = CompetitionModule::default()
+        .competition_result
+        .load(deps.storage, competition.id.u128())?;
+
+    rating_adjustment(deps, competition, result)
+}
+
+/// Triggers a rating adjustment for 2 member wagers, where a result of None is a draw
+pub(crate) fn rating_adjustment(
+    deps: DepsMut,
+    competition: &Wager,
+    result: Option<Distribution<Addr>>,
+) -> Result<Option<SubMsg>, CompetitionError> {
     if !CompetitionModule::default().query_is_dao_member(
         deps.as_ref(),
         &competition.host,
@@ -86,11 +163,6 @@ fn post_processing(deps: DepsMut, competition: &Wager) -> Result<Option<SubMsg>,
                 .map(|x| x.addr)
                 .collect();
 
-            // This will be in state
-            let result = CompetitionModule::default()
-                .competition_result
-                .load(deps.storage, competition.id.u128())?;
-
             return Ok(match result {
                 Some(result) => {
                     let registered_members_set: HashSet<_> = registered_members.iter().collect();
@@ -160,7 +232,13 @@ pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, Competitio
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
-    CompetitionModule::default().query(deps, env, msg)
+    match msg {
+        QueryBase::QueryExtension { msg } => match msg {
+            QueryExt::Claims { wager_id } => to_json_binary(&query::claims(deps, wager_id)?),
+            QueryExt::SidePools { wager_id } => to_json_binary(&query::side_pools(deps, wager_id)?),
+        },
+        _ => CompetitionModule::default().query(deps, env, msg),
+    }
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
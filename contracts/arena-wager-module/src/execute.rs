@@ -0,0 +1,533 @@
+use std::collections::BTreeMap;
+
+use arena_interface::{
+    competition::{msg::EscrowContractInfo, state::CompetitionStatus},
+    escrow,
+    group::{self, MemberMsg},
+};
+use cosmwasm_std::{
+    from_json, to_json_binary, Addr, BankMsg, Decimal, Deps, DepsMut, Env, MessageInfo, Order,
+    Response, StdError, StdResult, Uint128, Uint64, WasmMsg,
+};
+use cw_balance::{Distribution, MemberBalanceChecked, MemberPercentage};
+use cw_competition_base::error::CompetitionError;
+
+use crate::{
+    contract::{rating_adjustment, CompetitionModule, DEFAULT_REFUND_GRACE_PERIOD},
+    msg::{Wager, WagerStake},
+    query::side_stakes,
+    state::{CLAIMS, SIDES},
+};
+
+/// Submits a member's claimed result for a wager.
+///
+/// # Details
+/// - Only members of an active wager can submit a claim, and a member can replace their own claim.
+/// - Once every member has submitted a matching claim, the wager is processed with that result.
+/// - If the claim conflicts with another member's claim, the wager is jailed immediately.
+/// - Every submitted claim is stored as the sender's competition evidence, and a conflict's proposed result is the sender's claim.
+pub fn submit_claim(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    wager_id: Uint128,
+    distribution: Option<Distribution<String>>,
+) -> Result<Response, CompetitionError> {
+    let competition_module = CompetitionModule::default();
+    let wager = competition_module
+        .competitions
+        .may_load(deps.storage, wager_id.u128())?
+        .ok_or(CompetitionError::UnknownCompetitionId { id: wager_id })?;
+
+    if !matches!(wager.status, CompetitionStatus::Active { .. }) {
+        return Err(CompetitionError::InvalidCompetitionStatus {
+            current_status: wager.status,
+        });
+    }
+    if !deps.querier.query_wasm_smart::<bool>(
+        wager.group_contract.to_string(),
+        &group::QueryMsg::IsMember {
+            addr: info.sender.to_string(),
+        },
+    )? {
+        return Err(CompetitionError::Unauthorized {});
+    }
+
+    // Members are sorted so equivalent claims compare as equal
+    let claim = distribution
+        .map(|x| -> StdResult<_> {
+            let mut distribution = x.into_checked(deps.as_ref())?;
+            distribution
+                .member_percentages
+                .sort_by(|a, b| a.addr.cmp(&b.addr));
+            distribution
+                .fixed_amounts
+                .sort_by(|a, b| a.addr.cmp(&b.addr));
+            distribution
+                .remainder_shares
+                .sort_by(|a, b| a.addr.cmp(&b.addr));
+
+            Ok(distribution)
+        })
+        .transpose()?;
+    if let Some(claim) = &claim {
+        if !deps.querier.query_wasm_smart::<bool>(
+            wager.group_contract.to_string(),
+            &group::QueryMsg::IsValidDistribution {
+                addrs: claim.addrs().map(|x| x.to_string()).collect(),
+            },
+        )? {
+            return Err(CompetitionError::StdError(StdError::generic_err(
+                "The distribution must contain only members of the wager",
+            )));
+        }
+    }
+
+    CLAIMS.save(deps.storage, (wager_id.u128(), &info.sender), &claim)?;
+    competition_module.inner_add_evidence(
+        deps.storage,
+        &env,
+        wager_id,
+        &info.sender,
+        vec![match &claim {
+            Some(distribution) => format!("Claimed result: {}", distribution),
+            None => "Claimed result: Draw".to_string(),
+        }],
+    )?;
+
+    let claims = CLAIMS
+        .prefix(wager_id.u128())
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    if claims.iter().any(|(_, x)| !claims_agree(x, &claim)) {
+        let sender = info.sender.to_string();
+        return Ok(competition_module
+            .inner_jail(
+                deps,
+                &env,
+                info,
+                wager_id,
+                format!("Conflicting claims for {}", wager.name),
+                "The wager's members submitted conflicting claims, which are kept as evidence"
+                    .to_string(),
+                claim.as_ref().map(|x| x.into_unchecked()),
+                false,
+            )?
+            .add_attribute("action", "submit_claim")
+            .add_attribute("claimant", sender)
+            .add_attribute("result", "conflict"));
+    }
+
+    let members_count: Uint64 = deps.querier.query_wasm_smart(
+        wager.group_contract.to_string(),
+        &group::QueryMsg::MembersCount {},
+    )?;
+
+    // Funds are only used as a proposal deposit, so they are returned
+    let mut response = Response::new();
+    if !info.funds.is_empty() {
+        response = response.add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: info.funds,
+        });
+    }
+
+    if claims.len() as u64 == members_count.u64() {
+        // Agreed draws are refunded in proportion to the stakes
+        let distribution = match &claim {
+            Some(claim) => Some(claim.clone()),
+            None => wager
+                .extension
+                .stakes
+                .as_deref()
+                .map(|stakes| stake_distribution(deps.as_ref(), &wager, stakes))
+                .transpose()?,
+        };
+
+        let mut process_response =
+            competition_module.inner_process(deps.branch(), &wager, distribution, None, None)?;
+        if let Some(sub_msg) = rating_adjustment(deps.branch(), &wager, claim)? {
+            process_response = process_response.add_submessage(sub_msg);
+        }
+
+        response = process_response.add_submessages(response.messages);
+    }
+
+    Ok(response
+        .add_attribute("action", "submit_claim")
+        .add_attribute("claimant", info.sender)
+        .add_attribute("claims", claims.len().to_string()))
+}
+
+/// Claims agree when they give each member the same share of the wager.
+/// The remainder address only receives the rounding remainder, so it is not compared.
+fn claims_agree(a: &Option<Distribution<Addr>>, b: &Option<Distribution<Addr>>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => {
+            a.member_percentages == b.member_percentages
+                && a.fixed_amounts == b.fixed_amounts
+                && a.remainder_shares == b.remainder_shares
+        }
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+/// Processes a wager from a single winner.
+///
+/// # Details
+/// - The winner takes the pot minus fees.
+/// - A winner of None is a draw, which refunds each member in proportion to their stake.
+///   Without stakes, the escrow splits the pot evenly.
+pub fn process_winner(
+    mut deps: DepsMut,
+    info: MessageInfo,
+    wager_id: Uint128,
+    winner: Option<String>,
+) -> Result<Response, CompetitionError> {
+    let competition_module = CompetitionModule::default();
+    let wager = competition_module
+        .competitions
+        .may_load(deps.storage, wager_id.u128())?
+        .ok_or(CompetitionError::UnknownCompetitionId { id: wager_id })?;
+    competition_module.inner_validate_auth(&info.sender, &wager, false)?;
+
+    let (distribution, rating_result) = match winner {
+        Some(winner) => {
+            let winner = deps.api.addr_validate(&winner)?;
+            if !deps.querier.query_wasm_smart::<bool>(
+                wager.group_contract.to_string(),
+                &group::QueryMsg::IsMember {
+                    addr: winner.to_string(),
+                },
+            )? {
+                return Err(CompetitionError::StdError(StdError::generic_err(format!(
+                    "{} is not a member of the wager",
+                    winner
+                ))));
+            }
+
+            let distribution = Distribution {
+                member_percentages: vec![MemberPercentage {
+                    addr: winner.clone(),
+                    percentage: Decimal::one(),
+                }],
+                remainder_addr: winner,
+                fixed_amounts: vec![],
+                remainder_shares: vec![],
+            };
+            (Some(distribution.clone()), Some(distribution))
+        }
+        None => (
+            wager
+                .extension
+                .stakes
+                .as_deref()
+                .map(|stakes| stake_distribution(deps.as_ref(), &wager, stakes))
+                .transpose()?,
+            None,
+        ),
+    };
+
+    let mut response =
+        competition_module.inner_process(deps.branch(), &wager, distribution, None, None)?;
+    if let Some(sub_msg) = rating_adjustment(deps.branch(), &wager, rating_result)? {
+        response = response.add_submessage(sub_msg);
+    }
+
+    Ok(response
+        .add_attribute("action", "process_winner")
+        .add_attribute("wager_id", wager_id.to_string()))
+}
+
+/// Backs one of the wager's sides with the sent funds.
+///
+/// # Details
+/// - Sides can only be chosen by members while the wager is pending, so choices are locked once it is fully funded.
+/// - The funds are deposited into the member's escrow balance, which is their stake in the side pool.
+/// - A member can add to their stake, but cannot move it to another side.
+pub fn choose_side(
+    deps: DepsMut,
+    info: MessageInfo,
+    wager_id: Uint128,
+    side: u32,
+) -> Result<Response, CompetitionError> {
+    let wager = CompetitionModule::default()
+        .competitions
+        .may_load(deps.storage, wager_id.u128())?
+        .ok_or(CompetitionError::UnknownCompetitionId { id: wager_id })?;
+
+    if !matches!(wager.status, CompetitionStatus::Pending) {
+        return Err(CompetitionError::InvalidCompetitionStatus {
+            current_status: wager.status,
+        });
+    }
+    let sides = wager
+        .extension
+        .sides
+        .as_ref()
+        .ok_or_else(|| StdError::generic_err("The wager does not have sides"))?;
+    let label = sides
+        .get(side as usize)
+        .ok_or_else(|| StdError::generic_err(format!("Side {} does not exist", side)))?;
+    if !deps.querier.query_wasm_smart::<bool>(
+        wager.group_contract.to_string(),
+        &group::QueryMsg::IsMember {
+            addr: info.sender.to_string(),
+        },
+    )? {
+        return Err(CompetitionError::Unauthorized {});
+    }
+    if info.funds.len() != 1 {
+        return Err(CompetitionError::StdError(StdError::generic_err(
+            "A side must be backed with a single native coin",
+        )));
+    }
+    if let Some(backed_side) = SIDES.may_load(deps.storage, (wager_id.u128(), &info.sender))? {
+        if backed_side != side {
+            return Err(CompetitionError::StdError(StdError::generic_err(format!(
+                "Already backing side {}",
+                backed_side
+            ))));
+        }
+    }
+
+    SIDES.save(deps.storage, (wager_id.u128(), &info.sender), &side)?;
+
+    // The deposit is credited to the backer's escrow balance, which is their stake in the side pool
+    let msg = WasmMsg::Execute {
+        contract_addr: wager.escrow.to_string(),
+        msg: to_json_binary(&escrow::ExecuteMsg::ReceiveNativeFor {
+            addr: info.sender.to_string(),
+        })?,
+        funds: info.funds,
+    };
+
+    Ok(Response::new()
+        .add_attribute("action", "choose_side")
+        .add_attribute("backer", info.sender)
+        .add_attribute("side", label)
+        .add_message(msg))
+}
+
+/// Processes a wager from its winning side.
+///
+/// # Details
+/// - The pot minus fees is split across the winning side's backers in proportion to their escrow balances.
+/// - A side of None, or a side without backers, is a draw, which refunds each member in proportion to their stake.
+/// - Members who never chose a side cannot win.
+pub fn process_side(
+    mut deps: DepsMut,
+    info: MessageInfo,
+    wager_id: Uint128,
+    side: Option<u32>,
+) -> Result<Response, CompetitionError> {
+    let competition_module = CompetitionModule::default();
+    let wager = competition_module
+        .competitions
+        .may_load(deps.storage, wager_id.u128())?
+        .ok_or(CompetitionError::UnknownCompetitionId { id: wager_id })?;
+    competition_module.inner_validate_auth(&info.sender, &wager, false)?;
+
+    let sides = wager
+        .extension
+        .sides
+        .as_ref()
+        .ok_or_else(|| StdError::generic_err("The wager does not have sides"))?;
+
+    let backers = match side {
+        Some(side) => {
+            if side as usize >= sides.len() {
+                return Err(CompetitionError::StdError(StdError::generic_err(format!(
+                    "Side {} does not exist",
+                    side
+                ))));
+            }
+
+            side_stakes(deps.as_ref(), &wager, side)?
+        }
+        None => vec![],
+    };
+
+    let (distribution, rating_result) = if backers.is_empty() {
+        (
+            wager
+                .extension
+                .stakes
+                .as_deref()
+                .map(|stakes| stake_distribution(deps.as_ref(), &wager, stakes))
+                .transpose()?,
+            None,
+        )
+    } else {
+        let distribution = stake_distribution(deps.as_ref(), &wager, &backers)?;
+        (Some(distribution.clone()), Some(distribution))
+    };
+
+    let mut response =
+        competition_module.inner_process(deps.branch(), &wager, distribution, None, None)?;
+    if let Some(sub_msg) = rating_adjustment(deps.branch(), &wager, rating_result)? {
+        response = response.add_submessage(sub_msg);
+    }
+
+    Ok(response
+        .add_attribute("action", "process_side")
+        .add_attribute("wager_id", wager_id.to_string())
+        .add_attribute(
+            "side",
+            side.and_then(|x| sides.get(x as usize))
+                .map(String::as_str)
+                .unwrap_or("Draw"),
+        ))
+}
+
+/// Refunds a wager that nobody processed or jailed after its expiration and refund grace period.
+/// The escrow is unlocked without any tax, so each member can withdraw their full balance and sponsors can reclaim their sponsorships.
+pub fn expire(deps: DepsMut, env: Env, wager_id: Uint128) -> Result<Response, CompetitionError> {
+    let competition_module = CompetitionModule::default();
+    let wager = competition_module
+        .competitions
+        .may_load(deps.storage, wager_id.u128())?
+        .ok_or(CompetitionError::UnknownCompetitionId { id: wager_id })?;
+
+    let grace_period = wager
+        .extension
+        .refund_grace_period
+        .unwrap_or(DEFAULT_REFUND_GRACE_PERIOD);
+
+    Ok(competition_module
+        .inner_refund(deps, &env, wager_id, grace_period)?
+        .add_attribute("grace_period", grace_period.to_string()))
+}
+
+/// Ensures the escrow's dues are proportional to the stakes, so each member deposits their stake's share of the pot.
+/// Dues of NFTs cannot be split by stakes, so they are rejected.
+pub fn validate_stake_dues(
+    deps: Deps,
+    escrow: &EscrowContractInfo,
+    stakes: &[WagerStake<String>],
+) -> StdResult<()> {
+    let dues = match escrow {
+        EscrowContractInfo::Existing { addr, .. } => deps.querier.query_wasm_smart(
+            addr,
+            &escrow::QueryMsg::InitialDues {
+                start_after: None,
+                // One more than the stakes, so a due without a stake is found
+                limit: Some(stakes.len() as u32 + 1),
+            },
+        )?,
+        EscrowContractInfo::New { msg, .. } => from_json::<escrow::InstantiateMsg>(msg)?
+            .dues
+            .into_iter()
+            .map(|x| x.into_checked(deps))
+            .collect::<StdResult<Vec<MemberBalanceChecked>>>()?,
+    };
+    if dues.len() != stakes.len() {
+        return Err(StdError::generic_err(
+            "Every staked member must have a due in the escrow",
+        ));
+    }
+
+    let mut reference: Option<(Uint128, BTreeMap<String, Uint128>)> = None;
+    for stake in stakes {
+        let addr = deps.api.addr_validate(&stake.addr)?;
+        let due = dues
+            .iter()
+            .find(|x| x.addr == addr)
+            .ok_or_else(|| StdError::generic_err(format!("{} does not have a due", addr)))?;
+        if due.balance.cw721.is_some() {
+            return Err(StdError::generic_err("Staked wagers cannot have NFT dues"));
+        }
+
+        let amounts: BTreeMap<String, Uint128> = due
+            .balance
+            .native
+            .iter()
+            .flatten()
+            .map(|x| (x.denom.clone(), x.amount))
+            .chain(
+                due.balance
+                    .cw20
+                    .iter()
+                    .flatten()
+                    .map(|x| (x.address.to_string(), x.amount)),
+            )
+            .collect();
+
+        match &reference {
+            None => reference = Some((stake.stake, amounts)),
+            Some((reference_stake, reference_amounts)) => {
+                if amounts.len() != reference_amounts.len()
+                    || amounts.iter().any(|(key, amount)| {
+                        reference_amounts.get(key).map_or(true, |reference_amount| {
+                            amount.full_mul(*reference_stake)
+                                != reference_amount.full_mul(stake.stake)
+                        })
+                    })
+                {
+                    return Err(StdError::generic_err(
+                        "The escrow's dues must be proportional to the stakes",
+                    ));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Splits the pot in proportion to each member's stake.
+/// Rounding remainders go to the remainder address, which is the staked member seeded first in the group like the escrow's default distribution.
+fn stake_distribution(
+    deps: Deps,
+    wager: &Wager,
+    stakes: &[WagerStake<Addr>],
+) -> StdResult<Distribution<Addr>> {
+    let total_stake = stakes
+        .iter()
+        .try_fold(Uint128::zero(), |acc, x| acc.checked_add(x.stake))?;
+
+    let mut member_percentages: Vec<MemberPercentage<Addr>> = stakes
+        .iter()
+        .map(|x| MemberPercentage {
+            addr: x.addr.clone(),
+            percentage: Decimal::from_ratio(x.stake, total_stake),
+        })
+        .collect();
+
+    let members: Vec<MemberMsg<Addr>> = deps.querier.query_wasm_smart(
+        wager.group_contract.to_string(),
+        &group::QueryMsg::Members {
+            start_after: None,
+            limit: None,
+        },
+    )?;
+    let remainder_addr = members
+        .into_iter()
+        .map(|x| x.addr)
+        .find(|addr| stakes.iter().any(|x| x.addr == *addr))
+        .unwrap_or_else(|| stakes[0].addr.clone());
+
+    let remainder_percentage = Decimal::one().checked_sub(
+        member_percentages
+            .iter()
+            .map(|x| x.percentage)
+            .sum::<Decimal>(),
+    )?;
+    if let Some(member_percentage) = member_percentages
+        .iter_mut()
+        .find(|x| x.addr == remainder_addr)
+    {
+        member_percentage.percentage = member_percentage
+            .percentage
+            .checked_add(remainder_percentage)?;
+    }
+
+    Ok(Distribution {
+        member_percentages,
+        remainder_addr,
+        fixed_amounts: vec![],
+        remainder_shares: vec![],
+    })
+}
@@ -1,2 +1,5 @@
 pub mod contract;
+pub mod execute;
 pub mod msg;
+pub mod query;
+pub mod state;
@@ -1,13 +1,49 @@
-use arena_interface::competition::{
-    msg::{ExecuteBase, InstantiateBase, MigrateBase, QueryBase, ToCompetitionExt},
-    state::{Competition, CompetitionResponse},
+use std::collections::HashSet;
+
+use arena_interface::{
+    competition::{
+        msg::{ExecuteBase, InstantiateBase, MigrateBase, QueryBase, ToCompetitionExt},
+        state::{Competition, CompetitionResponse},
+    },
+    group,
 };
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::Empty;
+use cosmwasm_std::{Addr, Empty, StdError, StdResult, Uint128, Uint64};
+use cw_address_like::AddressLike;
+use cw_balance::Distribution;
 
 #[cw_serde]
 #[derive(cw_orch::ExecuteFns)]
-pub enum ExecuteExt {}
+pub enum ExecuteExt {
+    /// Submits the sender's claimed result for an active wager
+    /// A distribution of None claims a draw
+    /// The wager is processed once every member submits a matching claim
+    /// A conflicting claim jails the wager, and any funds are forwarded as the proposal deposit
+    #[cw_orch(payable)]
+    SubmitClaim {
+        wager_id: Uint128,
+        distribution: Option<Distribution<String>>,
+    },
+    /// Processes the wager from a single winner
+    /// The winner takes the pot minus fees, and a winner of None is a draw
+    ProcessWinner {
+        wager_id: Uint128,
+        winner: Option<String>,
+    },
+    /// Backs one of the wager's sides while it is being funded
+    /// The funds are deposited into the sender's escrow balance, which is their stake in the side pool
+    #[cw_orch(payable)]
+    ChooseSide { wager_id: Uint128, side: u32 },
+    /// Processes the wager from a winning side
+    /// The pot is split across the side's backers in proportion to their stakes, and a side of None is a draw
+    ProcessSide {
+        wager_id: Uint128,
+        side: Option<u32>,
+    },
+    /// Refunds an active wager that was not processed or jailed within the refund grace period after its expiration
+    /// This can be called by anyone
+    Expire { wager_id: Uint128 },
+}
 
 impl From<ExecuteExt> for ExecuteMsg {
     fn from(msg: ExecuteExt) -> Self {
@@ -17,7 +53,12 @@ impl From<ExecuteExt> for ExecuteMsg {
 
 #[cw_serde]
 #[derive(QueryResponses, cw_orch::QueryFns)]
-pub enum QueryExt {}
+pub enum QueryExt {
+    #[returns(Vec<WagerClaim>)]
+    Claims { wager_id: Uint128 },
+    #[returns(Vec<SidePool>)]
+    SidePools { wager_id: Uint128 },
+}
 
 impl From<QueryExt> for QueryMsg {
     fn from(msg: QueryExt) -> Self {
@@ -31,11 +72,60 @@ pub enum MigrateMsg {
     Base(MigrateBase),
 }
 
+/// This is used to completely generate schema types
+/// QueryExt response types are hidden by the QueryBase mapping to Binary output
+#[cw_serde]
+pub struct SudoMsg {
+    pub wager_claim: WagerClaim,
+    pub side_pool: SidePool,
+}
+
+#[cw_serde]
+pub struct WagerClaim {
+    pub addr: Addr,
+    pub distribution: Option<Distribution<Addr>>,
+}
+
+#[cw_serde]
+pub struct SidePool {
+    pub side: u32,
+    pub label: String,
+    pub backers: Vec<Addr>,
+    /// The sum of the backers' escrow balances
+    pub total_stake: Uint128,
+}
+
+/// A member's share of the pot
+/// Stakes are relative, so 2:1 odds can be described as stakes of 2 and 1
+/// The escrow's dues must be in the same proportion as the stakes
+#[cw_serde]
+pub struct WagerStake<T: AddressLike> {
+    pub addr: T,
+    pub stake: Uint128,
+}
+
 #[cw_serde]
-pub struct WagerInstantiateExt {}
+pub struct WagerInstantiateExt {
+    /// The stake of every member, which is validated against the escrow's dues on creation
+    /// If set, the winner takes the pot minus fees and draws are refunded in proportion to the stakes
+    pub stakes: Option<Vec<WagerStake<String>>>,
+    /// The labels of the wager's sides, such as home, draw and away
+    /// If set, members back a side and the winning side's backers split the pot
+    pub sides: Option<Vec<String>>,
+    /// Seconds after the wager's expiration before anyone can trigger a refund
+    /// Defaults to a week
+    pub refund_grace_period: Option<u64>,
+}
 
 #[cw_serde]
-pub struct WagerExt {}
+pub struct WagerExt {
+    #[serde(default)]
+    pub stakes: Option<Vec<WagerStake<Addr>>>,
+    #[serde(default)]
+    pub sides: Option<Vec<String>>,
+    #[serde(default)]
+    pub refund_grace_period: Option<u64>,
+}
 
 pub type InstantiateMsg = InstantiateBase<Empty>;
 pub type ExecuteMsg = ExecuteBase<ExecuteExt, WagerInstantiateExt>;
@@ -46,9 +136,64 @@ pub type WagerResponse = CompetitionResponse<WagerExt>;
 impl ToCompetitionExt<WagerExt> for WagerInstantiateExt {
     fn to_competition_ext(
         &self,
-        _deps: cosmwasm_std::Deps,
-        _group_contract: &cosmwasm_std::Addr,
+        deps: cosmwasm_std::Deps,
+        group_contract: &cosmwasm_std::Addr,
     ) -> cosmwasm_std::StdResult<WagerExt> {
-        Ok(WagerExt {})
+        let stakes = match &self.stakes {
+            Some(stakes) => {
+                let stakes = stakes
+                    .iter()
+                    .map(|x| {
+                        Ok(WagerStake {
+                            addr: deps.api.addr_validate(&x.addr)?,
+                            stake: x.stake,
+                        })
+                    })
+                    .collect::<StdResult<Vec<_>>>()?;
+
+                let unique_members: HashSet<&Addr> = stakes.iter().map(|x| &x.addr).collect();
+                if unique_members.len() != stakes.len() {
+                    return Err(StdError::generic_err("Each member can only have one stake"));
+                }
+                if stakes.iter().any(|x| x.stake.is_zero()) {
+                    return Err(StdError::generic_err("Stakes must be greater than 0"));
+                }
+
+                let members_count: Uint64 = deps.querier.query_wasm_smart(
+                    group_contract.to_string(),
+                    &group::QueryMsg::MembersCount {},
+                )?;
+                if members_count.u64() != stakes.len() as u64
+                    || !deps.querier.query_wasm_smart::<bool>(
+                        group_contract.to_string(),
+                        &group::QueryMsg::IsValidDistribution {
+                            addrs: stakes.iter().map(|x| x.addr.to_string()).collect(),
+                        },
+                    )?
+                {
+                    return Err(StdError::generic_err(
+                        "A stake must be provided for every member",
+                    ));
+                }
+
+                Some(stakes)
+            }
+            None => None,
+        };
+
+        if let Some(sides) = &self.sides {
+            if sides.len() < 2 {
+                return Err(StdError::generic_err("At least 2 sides should be provided"));
+            }
+            if sides.iter().collect::<HashSet<_>>().len() != sides.len() {
+                return Err(StdError::generic_err("Side labels must be unique"));
+            }
+        }
+
+        Ok(WagerExt {
+            stakes,
+            sides: self.sides.clone(),
+            refund_grace_period: self.refund_grace_period,
+        })
     }
 }
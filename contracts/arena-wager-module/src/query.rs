@@ -0,0 +1,90 @@
+use arena_interface::escrow;
+use cosmwasm_std::{Addr, Deps, Order, StdError, StdResult, Uint128};
+use cw_balance::BalanceVerified;
+
+use crate::{
+    contract::CompetitionModule,
+    msg::{SidePool, Wager, WagerClaim, WagerStake},
+    state::{CLAIMS, SIDES},
+};
+
+pub fn claims(deps: Deps, wager_id: Uint128) -> StdResult<Vec<WagerClaim>> {
+    CLAIMS
+        .prefix(wager_id.u128())
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|x| x.map(|(addr, distribution)| WagerClaim { addr, distribution }))
+        .collect()
+}
+
+pub fn side_pools(deps: Deps, wager_id: Uint128) -> StdResult<Vec<SidePool>> {
+    let wager = CompetitionModule::default()
+        .competitions
+        .load(deps.storage, wager_id.u128())?;
+    let sides = wager
+        .extension
+        .sides
+        .as_ref()
+        .ok_or_else(|| StdError::generic_err("The wager does not have sides"))?;
+
+    sides
+        .iter()
+        .enumerate()
+        .map(|(i, label)| {
+            let stakes = side_stakes(deps, &wager, i as u32)?;
+            let total_stake = stakes
+                .iter()
+                .try_fold(Uint128::zero(), |acc, x| acc.checked_add(x.stake))?;
+
+            Ok(SidePool {
+                side: i as u32,
+                label: label.clone(),
+                backers: stakes.into_iter().map(|x| x.addr).collect(),
+                total_stake,
+            })
+        })
+        .collect()
+}
+
+/// Gets the stakes of a side's backers from their balances in the escrow.
+/// Side pools hold a single native denomination, so the stakes can be compared.
+pub fn side_stakes(deps: Deps, wager: &Wager, side: u32) -> StdResult<Vec<WagerStake<Addr>>> {
+    let total_balance: Option<BalanceVerified> = deps
+        .querier
+        .query_wasm_smart(wager.escrow.to_string(), &escrow::QueryMsg::TotalBalance {})?;
+    let denom = match total_balance {
+        None => return Ok(vec![]),
+        Some(BalanceVerified {
+            native: Some(native),
+            cw20: None,
+            cw721: None,
+        }) if native.len() == 1 => native[0].denom.clone(),
+        Some(_) => {
+            return Err(StdError::generic_err(
+                "Side pools can only be split for a single native denomination",
+            ))
+        }
+    };
+
+    SIDES
+        .prefix(wager.id.u128())
+        .range(deps.storage, None, None, Order::Ascending)
+        .filter(|x| !matches!(x, Ok((_, backed_side)) if *backed_side != side))
+        .map(|x| {
+            let (addr, _) = x?;
+            let balance: Option<BalanceVerified> = deps.querier.query_wasm_smart(
+                wager.escrow.to_string(),
+                &escrow::QueryMsg::Balance {
+                    addr: addr.to_string(),
+                },
+            )?;
+            let stake = balance
+                .and_then(|x| x.native)
+                .and_then(|x| x.into_iter().find(|y| y.denom == denom))
+                .map(|x| x.amount)
+                .unwrap_or_default();
+
+            Ok(WagerStake { addr, stake })
+        })
+        .filter(|x| !matches!(x, Ok(stake) if stake.stake.is_zero()))
+        .collect()
+}
@@ -0,0 +1,10 @@
+use cosmwasm_std::Addr;
+use cw_balance::Distribution;
+use cw_storage_plus::Map;
+
+/// (Wager Id, Member) -> The member's claimed result
+/// A claim of None is a draw
+pub const CLAIMS: Map<(u128, &Addr), Option<Distribution<Addr>>> = Map::new("wager_claims");
+
+/// (Wager Id, Member) -> The index of the side the member backs
+pub const SIDES: Map<(u128, &Addr), u32> = Map::new("wager_sides");
@@ -3,12 +3,13 @@ use std::marker::PhantomData;
 #[allow(unused_imports)]
 use crate::competition::state::{CompetitionResponse, CompetitionStatus, Config, Evidence};
 use crate::{
+    escrow::Vesting,
     fees::FeeInformation,
     group::{self},
 };
 use cosmwasm_schema::{cw_serde, schemars::JsonSchema, QueryResponses};
 use cosmwasm_std::{Addr, Binary, Deps, StdResult, Timestamp, Uint128};
-use cw_balance::Distribution;
+use cw_balance::{Distribution, NftAllocation};
 use cw_ownable::{cw_ownable_execute, cw_ownable_query};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
@@ -60,6 +61,12 @@ pub enum ExecuteBase<ExecuteExt, CompetitionInstantiateExt> {
     ProcessCompetition {
         competition_id: Uint128,
         distribution: Option<Distribution<String>>,
+        /// Assigns specific NFTs in the escrow to recipients, such as a trophy for 1st place
+        #[serde(default)]
+        nft_allocations: Option<Vec<NftAllocation<String>>>,
+        /// Releases the distributed balances over a vesting schedule instead of immediately
+        #[serde(default)]
+        vesting: Option<Vesting<String>>,
     },
     Extension {
         msg: ExecuteExt,
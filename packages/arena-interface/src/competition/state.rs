@@ -16,6 +16,8 @@ pub enum CompetitionStatus {
     Jailed {
         activation_height: u64,
     },
+    /// The competition expired without a result, and its escrow was unlocked for members to withdraw
+    Refunded,
 }
 
 impl fmt::Display for CompetitionStatus {
@@ -29,6 +31,7 @@ impl fmt::Display for CompetitionStatus {
                 activation_height: _,
             } => write!(f, "Active"),
             CompetitionStatus::Inactive => write!(f, "Inactive"),
+            CompetitionStatus::Refunded => write!(f, "Refunded"),
         }
     }
 }
@@ -155,3 +158,11 @@ pub struct Evidence {
     pub content: String,
     pub submit_time: Timestamp,
 }
+
+/// A dispute on a single match, which freezes the match until a DAO proposal decides its result
+#[cw_serde]
+pub struct MatchDispute {
+    pub originator: Addr,
+    /// The ids of the dispute's evidence in the competition's evidence
+    pub evidence_ids: Vec<Uint128>,
+}
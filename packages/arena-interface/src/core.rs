@@ -209,6 +209,10 @@ pub struct ProposeMessage {
     pub description: String,
     pub distribution: Option<Distribution<String>>,
     pub originator: String,
+    /// A message executed on the competition module instead of processing the competition
+    /// This is used to resolve a dispute without jailing the competition
+    #[serde(default)]
+    pub msg: Option<Binary>,
 }
 
 #[cw_serde]
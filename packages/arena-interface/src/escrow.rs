@@ -1,11 +1,13 @@
 use crate::fees::FeeInformation;
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Binary, Coin};
+use cosmwasm_std::{ensure, Addr, Binary, Coin, Decimal, Deps, StdError, StdResult, Timestamp};
 use cw20::Cw20ReceiveMsg;
 use cw721::Cw721ReceiveMsg;
+use cw_address_like::AddressLike;
 #[allow(unused_imports)]
 use cw_balance::{
     BalanceVerified, Distribution, MemberBalanceChecked, MemberBalanceUnchecked, MemberPercentage,
+    NftAllocation,
 };
 use cw_ownable::{cw_ownable_execute, cw_ownable_query};
 
@@ -13,6 +15,9 @@ use cw_ownable::{cw_ownable_execute, cw_ownable_query};
 pub struct InstantiateMsg {
     pub dues: Vec<MemberBalanceUnchecked>,
     pub is_enrollment: bool,
+    /// Allows unclaimed balances to be swept after a period following the distribution
+    #[serde(default)]
+    pub claim_deadline: Option<ClaimDeadline<String>>,
 }
 
 #[cw_ownable_execute]
@@ -31,8 +36,22 @@ pub enum ExecuteMsg {
     },
     #[cw_orch(payable)]
     ReceiveNative {},
+    /// Called by the owner to receive native tokens into a member's balance
+    #[cw_orch(payable)]
+    ReceiveNativeFor {
+        addr: String,
+    },
+    /// Cw20 and cw721 tokens are sponsorships if the receive msg is `ReceiveMsg::Sponsor {}`
     Receive(Cw20ReceiveMsg),
     ReceiveNft(Cw721ReceiveMsg),
+    /// Contributes native tokens to the prize pool without paying any member's dues
+    #[cw_orch(payable)]
+    SponsorNative {},
+    /// Returns a sponsor's contribution if the escrow is unlocked without a distribution
+    SponsorWithdraw {
+        cw20_msg: Option<Binary>,
+        cw721_msg: Option<Binary>,
+    },
     Distribute {
         /// How the escrow funds will be distributed
         /// If none, the funds will be split evenly across members with the 1 seed receiving remainders
@@ -43,11 +62,19 @@ pub enum ExecuteMsg {
         layered_fees: Option<Vec<FeeInformation<String>>>,
         activation_height: Option<u64>,
         group_contract: String,
+        /// Releases the distributed balances over time instead of all at once
+        #[serde(default)]
+        vesting: Option<Vesting<String>>,
+        /// Assigns specific NFTs to recipients before the distribution is applied to the rest
+        #[serde(default)]
+        nft_allocations: Option<Vec<NftAllocation<String>>>,
     },
     Lock {
         value: bool,
         transfer_ownership: Option<TransferEscrowOwnershipMsg>,
     },
+    /// Sends every balance left after the claim deadline to the claim deadline's receiver
+    SweepUnclaimed {},
 }
 
 #[cw_ownable_query]
@@ -83,6 +110,32 @@ pub enum QueryMsg {
     IsLocked {},
     #[returns(DumpStateResponse)]
     DumpState { addr: Option<String> },
+    #[returns(ClaimableResponse)]
+    Claimable { addr: String },
+    #[returns(Option<BalanceVerified>)]
+    Sponsorship { addr: String },
+    #[returns(Vec<MemberBalanceChecked>)]
+    Sponsorships {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(Option<BalanceVerified>)]
+    TotalSponsorship {},
+    /// The time after which unclaimed balances can be swept, which is set on distribution
+    #[returns(Option<Timestamp>)]
+    ClaimExpiration {},
+    /// The balances that were swept from each member after the claim deadline
+    #[returns(Vec<MemberBalanceChecked>)]
+    Swept {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+}
+
+/// The message attached to cw20 and cw721 sends
+#[cw_serde]
+pub enum ReceiveMsg {
+    Sponsor {},
 }
 
 #[cw_serde]
@@ -93,6 +146,162 @@ pub struct DumpStateResponse {
     pub due: Option<BalanceVerified>,
 }
 
+#[cw_serde]
+pub struct ClaimableResponse {
+    /// The balance that can be withdrawn now, including vested prizes
+    pub claimable: BalanceVerified,
+    /// Prizes that have vested, including any already withdrawn
+    pub vested: BalanceVerified,
+    /// Prizes that are still vesting
+    pub unvested: BalanceVerified,
+}
+
+/// A linear release of a distributed balance, starting from the distribution
+#[cw_serde]
+pub struct VestingSchedule {
+    /// Seconds after the distribution before anything is released
+    pub cliff: u64,
+    /// Seconds after the distribution until everything is released
+    pub duration: u64,
+}
+
+impl VestingSchedule {
+    pub fn validate(&self) -> StdResult<()> {
+        ensure!(
+            self.duration > 0,
+            StdError::generic_err("The vesting duration must be greater than 0")
+        );
+        ensure!(
+            self.cliff <= self.duration,
+            StdError::generic_err("The vesting cliff cannot be after the vesting duration")
+        );
+
+        Ok(())
+    }
+
+    /// The share of the balance released by `time` for a distribution at `start`
+    pub fn vested_ratio(&self, start: Timestamp, time: Timestamp) -> Decimal {
+        let elapsed = time.seconds().saturating_sub(start.seconds());
+
+        if elapsed < self.cliff {
+            Decimal::zero()
+        } else if elapsed >= self.duration {
+            Decimal::one()
+        } else {
+            Decimal::from_ratio(elapsed, self.duration)
+        }
+    }
+}
+
+#[cw_serde]
+pub struct MemberVestingSchedule<T: AddressLike> {
+    pub addr: T,
+    pub schedule: VestingSchedule,
+}
+
+/// How distributed balances are released
+/// Fungible tokens are released linearly, and NFTs are released once the schedule ends
+#[cw_serde]
+pub enum Vesting<T: AddressLike> {
+    /// Every member's balance follows the same schedule
+    Global(VestingSchedule),
+    /// Only the listed members' balances vest, and everyone else can withdraw immediately
+    PerMember(Vec<MemberVestingSchedule<T>>),
+}
+
+impl Vesting<String> {
+    pub fn into_checked(&self, deps: Deps) -> StdResult<Vesting<Addr>> {
+        Ok(match self {
+            Vesting::Global(schedule) => {
+                schedule.validate()?;
+
+                Vesting::Global(schedule.clone())
+            }
+            Vesting::PerMember(schedules) => Vesting::PerMember(
+                schedules
+                    .iter()
+                    .map(|x| {
+                        x.schedule.validate()?;
+
+                        Ok(MemberVestingSchedule {
+                            addr: deps.api.addr_validate(&x.addr)?,
+                            schedule: x.schedule.clone(),
+                        })
+                    })
+                    .collect::<StdResult<_>>()?,
+            ),
+        })
+    }
+}
+
+impl Vesting<Addr> {
+    pub fn into_unchecked(&self) -> Vesting<String> {
+        match self {
+            Vesting::Global(schedule) => Vesting::Global(schedule.clone()),
+            Vesting::PerMember(schedules) => Vesting::PerMember(
+                schedules
+                    .iter()
+                    .map(|x| MemberVestingSchedule {
+                        addr: x.addr.to_string(),
+                        schedule: x.schedule.clone(),
+                    })
+                    .collect(),
+            ),
+        }
+    }
+
+    /// The schedule that a member's distributed balance follows, if it vests
+    pub fn schedule(&self, addr: &Addr) -> Option<&VestingSchedule> {
+        match self {
+            Vesting::Global(schedule) => Some(schedule),
+            Vesting::PerMember(schedules) => schedules
+                .iter()
+                .find(|x| x.addr == *addr)
+                .map(|x| &x.schedule),
+        }
+    }
+
+    /// Seconds after the distribution until every schedule has released its balances
+    pub fn duration(&self) -> u64 {
+        match self {
+            Vesting::Global(schedule) => schedule.duration,
+            Vesting::PerMember(schedules) => schedules
+                .iter()
+                .map(|x| x.schedule.duration)
+                .max()
+                .unwrap_or_default(),
+        }
+    }
+}
+
+#[cw_serde]
+pub struct ClaimDeadline<T: AddressLike> {
+    /// Seconds after the distribution that members have to withdraw their balances
+    /// If the distribution vests, the deadline starts once every vesting schedule has ended
+    pub duration: u64,
+    /// Receives the unclaimed balances
+    /// Defaults to the arena DAO of the owner competition module
+    pub receiver: Option<T>,
+}
+
+impl ClaimDeadline<String> {
+    pub fn into_checked(&self, deps: Deps) -> StdResult<ClaimDeadline<Addr>> {
+        ensure!(
+            self.duration > 0,
+            StdError::generic_err("The claim deadline duration must be greater than 0")
+        );
+
+        Ok(ClaimDeadline {
+            duration: self.duration,
+            receiver: self
+                .receiver
+                .as_ref()
+                .map(|x| deps.api.addr_validate(x))
+                .transpose()?,
+        })
+    }
+}
+
 #[cw_serde]
 pub struct TransferEscrowOwnershipMsg {
     pub addr: String,
@@ -3,3 +3,46 @@ use cosmwasm_std::{BlockInfo, Timestamp};
 pub fn is_expired(current: &BlockInfo, date: &Timestamp, duration: u64) -> bool {
     current.time > date.plus_seconds(duration)
 }
+
+/// Generates a round-robin schedule using the circle method
+///
+/// Each round is a list of pairings by the teams' 0-based index, and a team without a pairing sits out the round.
+pub fn round_robin_schedule(team_count: usize) -> Vec<Vec<(usize, usize)>> {
+    let mut teams_list = (1..=team_count).collect::<Vec<_>>();
+    let rounds = if team_count % 2 != 0 {
+        teams_list.push(0); // Using 0 as dummy team
+
+        team_count + 1
+    } else {
+        team_count
+    };
+
+    // Split teams into two groups
+    let (x, y) = teams_list.split_at(rounds / 2);
+    let mut x = x.to_vec();
+    let mut y = y.to_vec();
+
+    let mut schedule = vec![];
+    for i in 0..rounds - 1 {
+        let mut pairings = Vec::new();
+
+        // Rotate teams between x and y after the first round
+        if i != 0 {
+            let first_y = y.remove(0);
+            x.insert(1, first_y);
+            let last_x = x.pop().unwrap();
+            y.push(last_x);
+        }
+
+        // Create pairings for the round
+        for j in 0..x.len() {
+            if x[j] != 0 && y[j] != 0 {
+                pairings.push((x[j] - 1, y[j] - 1)); // adjust index for 0-based array
+            }
+        }
+
+        schedule.push(pairings);
+    }
+
+    schedule
+}
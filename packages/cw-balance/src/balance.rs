@@ -46,6 +46,24 @@ fn fold_native_coins(coins: Vec<Coin>) -> StdResult<BTreeMap<String, Uint128>> {
         })
 }
 
+/// Adds a balance to a member's entry, creating the entry if the member has none yet
+fn credit_member(
+    split_balances: &mut Vec<MemberBalanceChecked>,
+    addr: &Addr,
+    balance: BalanceVerified,
+) -> StdResult<()> {
+    if let Some(member_balance) = split_balances.iter_mut().find(|mb| mb.addr == *addr) {
+        member_balance.balance = member_balance.balance.checked_add(&balance)?;
+    } else {
+        split_balances.push(MemberBalanceChecked {
+            addr: addr.clone(),
+            balance,
+        });
+    }
+
+    Ok(())
+}
+
 fn fold_cw20_coins(coins: Vec<Cw20Coin>, deps: Deps) -> StdResult<BTreeMap<Addr, Uint128>> {
     coins
         .into_iter()
@@ -276,8 +294,37 @@ impl BalanceVerified {
         &self,
         distribution: &Distribution<Addr>,
     ) -> Result<Vec<MemberBalanceChecked>, BalanceError> {
-        let mut split_balances = Vec::with_capacity(distribution.member_percentages.len());
-        let (native, cw20, cw721) = self.to_maps();
+        let mut split_balances = Vec::with_capacity(
+            distribution.member_percentages.len() + distribution.fixed_amounts.len(),
+        );
+
+        // Pay the fixed amounts before applying any percentages
+        let total_fixed =
+            distribution
+                .fixed_amounts
+                .iter()
+                .try_fold(BalanceVerified::new(), |acc, x| {
+                    acc.checked_add(&BalanceVerified {
+                        native: Some(x.amount.clone()),
+                        cw20: None,
+                        cw721: None,
+                    })
+                })?;
+        let available = self.checked_sub_fixed(&total_fixed)?;
+
+        for member_amount in &distribution.fixed_amounts {
+            credit_member(
+                &mut split_balances,
+                &member_amount.addr,
+                BalanceVerified::from_maps(
+                    Some(fold_native_coins(member_amount.amount.clone())?),
+                    None,
+                    None,
+                ),
+            )?;
+        }
+
+        let (native, cw20, cw721) = available.to_maps();
 
         // Calculate split balances
         for member_percentage in &distribution.member_percentages {
@@ -314,10 +361,7 @@ impl BalanceVerified {
             };
 
             let split_balance = BalanceVerified::from_maps(native_split, cw20_split, cw721_split);
-            split_balances.push(MemberBalanceChecked {
-                addr: member_percentage.addr.clone(),
-                balance: split_balance,
-            });
+            credit_member(&mut split_balances, &member_percentage.addr, split_balance)?;
         }
 
         // Calculate remainders
@@ -326,25 +370,59 @@ impl BalanceVerified {
             .try_fold(BalanceVerified::new(), |acc, mb| {
                 acc.checked_add(&mb.balance)
             })?;
-        let remainders = self.checked_sub(&total_split)?;
+        let mut remainders = self.checked_sub(&total_split)?;
+
+        // Split the remainders by shares
+        if !distribution.remainder_shares.is_empty() && !remainders.is_empty() {
+            let total_shares = distribution
+                .remainder_shares
+                .iter()
+                .try_fold(Uint128::zero(), |acc, x| acc.checked_add(x.shares.into()))?;
+            let shared = remainders.clone();
+
+            for member_share in &distribution.remainder_shares {
+                let share_balance = shared.checked_mul_floor(Decimal::checked_from_ratio(
+                    member_share.shares,
+                    total_shares,
+                )?)?;
+
+                remainders = remainders.checked_sub(&share_balance)?;
+                credit_member(&mut split_balances, &member_share.addr, share_balance)?;
+            }
+        }
 
         // Distribute remainders
         if !remainders.is_empty() {
-            if let Some(member_balance) = split_balances
-                .iter_mut()
-                .find(|mb| mb.addr == distribution.remainder_addr)
-            {
-                member_balance.balance = member_balance.balance.checked_add(&remainders)?;
-            } else {
-                split_balances.push(MemberBalanceChecked {
-                    addr: distribution.remainder_addr.clone(),
-                    balance: remainders,
+            credit_member(
+                &mut split_balances,
+                &distribution.remainder_addr,
+                remainders,
+            )?;
+        }
+
+        Ok(split_balances)
+    }
+
+    /// Subtracts the fixed amounts of a distribution, erroring with the denom that cannot be covered
+    fn checked_sub_fixed(&self, fixed: &BalanceVerified) -> Result<Self, BalanceError> {
+        let (native, _, _) = self.to_maps();
+        let native = native.unwrap_or_default();
+
+        for coin in fixed.native.iter().flatten() {
+            let available = native.get(&coin.denom).copied().unwrap_or_default();
+
+            if coin.amount > available {
+                return Err(BalanceError::FixedAmountsExceedBalance {
+                    denom: coin.denom.clone(),
+                    required: coin.amount,
+                    available,
                 });
             }
         }
 
-        Ok(split_balances)
+        self.checked_sub(fixed)
     }
+
     pub fn transmit_all(
         &self,
         deps: Deps,
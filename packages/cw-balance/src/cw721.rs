@@ -1,7 +1,10 @@
 use std::fmt;
 
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Deps, StdResult};
+use cosmwasm_std::{Addr, Deps, StdError, StdResult};
+use cw_address_like::AddressLike;
+
+use crate::BalanceVerified;
 
 #[cw_serde]
 pub struct Cw721CollectionVerified {
@@ -45,3 +48,60 @@ impl fmt::Display for Cw721CollectionVerified {
         )
     }
 }
+
+/// Assigns a specific NFT to a recipient instead of following the distribution
+#[cw_serde]
+pub struct NftAllocation<T: AddressLike> {
+    pub addr: T,
+    pub collection: T,
+    pub token_id: String,
+}
+
+impl NftAllocation<String> {
+    pub fn into_checked(&self, deps: Deps) -> StdResult<NftAllocation<Addr>> {
+        Ok(NftAllocation {
+            addr: deps.api.addr_validate(&self.addr)?,
+            collection: deps.api.addr_validate(&self.collection)?,
+            token_id: self.token_id.clone(),
+        })
+    }
+}
+
+impl NftAllocation<Addr> {
+    pub fn into_unchecked(&self) -> NftAllocation<String> {
+        NftAllocation {
+            addr: self.addr.to_string(),
+            collection: self.collection.to_string(),
+            token_id: self.token_id.clone(),
+        }
+    }
+
+    pub fn balance(&self) -> BalanceVerified {
+        BalanceVerified {
+            native: None,
+            cw20: None,
+            cw721: Some(vec![Cw721CollectionVerified {
+                address: self.collection.clone(),
+                token_ids: vec![self.token_id.clone()],
+            }]),
+        }
+    }
+
+    /// The combined balance of all allocations, which cannot assign the same NFT twice
+    pub fn total_balance(allocations: &[NftAllocation<Addr>]) -> StdResult<BalanceVerified> {
+        let mut unique_tokens = std::collections::HashSet::new();
+
+        allocations
+            .iter()
+            .try_fold(BalanceVerified::new(), |acc, allocation| {
+                if !unique_tokens.insert((&allocation.collection, &allocation.token_id)) {
+                    return Err(StdError::generic_err(format!(
+                        "CW721 token {} of contract {} is allocated more than once",
+                        allocation.token_id, allocation.collection
+                    )));
+                }
+
+                acc.checked_add(&allocation.balance())
+            })
+    }
+}
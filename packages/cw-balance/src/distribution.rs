@@ -1,5 +1,5 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{to_json_string, Addr, Decimal, Deps, StdError, StdResult};
+use cosmwasm_std::{to_json_string, Addr, Coin, Decimal, Deps, StdError, StdResult, Uint64};
 use cw_address_like::AddressLike;
 use serde::Serialize;
 use std::fmt::Display;
@@ -19,15 +19,59 @@ impl MemberPercentage<String> {
     }
 }
 
+/// A fixed prize in native tokens, paid out before any percentages
 #[cw_serde]
+pub struct MemberAmount<T: AddressLike> {
+    pub addr: T,
+    pub amount: Vec<Coin>,
+}
+
+impl MemberAmount<String> {
+    pub fn into_checked(&self, deps: Deps) -> StdResult<MemberAmount<Addr>> {
+        Ok(MemberAmount {
+            addr: deps.api.addr_validate(&self.addr)?,
+            amount: self.amount.clone(),
+        })
+    }
+}
+
+/// A weighted share of whatever is left after the fixed amounts and percentages
+#[cw_serde]
+pub struct MemberShare<T: AddressLike> {
+    pub addr: T,
+    pub shares: Uint64,
+}
+
+impl MemberShare<String> {
+    pub fn into_checked(&self, deps: Deps) -> StdResult<MemberShare<Addr>> {
+        Ok(MemberShare {
+            addr: deps.api.addr_validate(&self.addr)?,
+            shares: self.shares,
+        })
+    }
+}
+
+/// Fixed amounts are paid first, then the member percentages are applied to the rest.
+/// Anything left after that is split by the remainder shares, and any rounding goes to the remainder address.
+#[cw_serde]
+// The defaulted fields would otherwise require T: Default
+#[serde(bound(deserialize = "T: serde::Deserialize<'de>"))]
 pub struct Distribution<T: AddressLike> {
     pub member_percentages: Vec<MemberPercentage<T>>,
     pub remainder_addr: T,
+    #[serde(default)]
+    pub fixed_amounts: Vec<MemberAmount<T>>,
+    /// If set, the member percentages can sum to less than 1
+    #[serde(default)]
+    pub remainder_shares: Vec<MemberShare<T>>,
 }
 
 impl Distribution<String> {
     pub fn into_checked(&self, deps: Deps) -> StdResult<Distribution<Addr>> {
-        if self.member_percentages.is_empty() {
+        if self.member_percentages.is_empty()
+            && self.fixed_amounts.is_empty()
+            && self.remainder_shares.is_empty()
+        {
             return Err(StdError::generic_err("Member percentages cannot be empty"));
         }
 
@@ -41,14 +85,52 @@ impl Distribution<String> {
             },
         );
 
-        if total_weight != Decimal::one() {
-            return Err(StdError::generic_err("Total weight must be equal to 1"));
+        if self.remainder_shares.is_empty() {
+            if !self.member_percentages.is_empty() && total_weight != Decimal::one() {
+                return Err(StdError::generic_err("Total weight must be equal to 1"));
+            }
+        } else if total_weight > Decimal::one() {
+            return Err(StdError::generic_err(
+                "Total weight cannot exceed 1 with remainder shares",
+            ));
         }
 
         if unique_members.len() != self.member_percentages.len() {
             return Err(StdError::generic_err("All members must be unique"));
         }
 
+        let unique_fixed_members = self
+            .fixed_amounts
+            .iter()
+            .map(|x| &x.addr)
+            .collect::<std::collections::HashSet<_>>();
+        if unique_fixed_members.len() != self.fixed_amounts.len() {
+            return Err(StdError::generic_err(
+                "All members with fixed amounts must be unique",
+            ));
+        }
+        if self
+            .fixed_amounts
+            .iter()
+            .any(|x| x.amount.is_empty() || x.amount.iter().any(|coin| coin.amount.is_zero()))
+        {
+            return Err(StdError::generic_err("Fixed amounts cannot be empty"));
+        }
+
+        let unique_share_members = self
+            .remainder_shares
+            .iter()
+            .map(|x| &x.addr)
+            .collect::<std::collections::HashSet<_>>();
+        if unique_share_members.len() != self.remainder_shares.len() {
+            return Err(StdError::generic_err(
+                "All members with remainder shares must be unique",
+            ));
+        }
+        if self.remainder_shares.iter().any(|x| x.shares.is_zero()) {
+            return Err(StdError::generic_err("Remainder shares cannot be zero"));
+        }
+
         Ok(Distribution::<Addr> {
             member_percentages: self
                 .member_percentages
@@ -56,6 +138,16 @@ impl Distribution<String> {
                 .map(|x| x.into_checked(deps))
                 .collect::<StdResult<_>>()?,
             remainder_addr: deps.api.addr_validate(&self.remainder_addr)?,
+            fixed_amounts: self
+                .fixed_amounts
+                .iter()
+                .map(|x| x.into_checked(deps))
+                .collect::<StdResult<_>>()?,
+            remainder_shares: self
+                .remainder_shares
+                .iter()
+                .map(|x| x.into_checked(deps))
+                .collect::<StdResult<_>>()?,
         })
     }
 }
@@ -81,6 +173,32 @@ impl Distribution<Addr> {
                 })
                 .collect(),
             remainder_addr: self.remainder_addr.to_string(),
+            fixed_amounts: self
+                .fixed_amounts
+                .iter()
+                .map(|x| MemberAmount::<String> {
+                    addr: x.addr.to_string(),
+                    amount: x.amount.clone(),
+                })
+                .collect(),
+            remainder_shares: self
+                .remainder_shares
+                .iter()
+                .map(|x| MemberShare::<String> {
+                    addr: x.addr.to_string(),
+                    shares: x.shares,
+                })
+                .collect(),
         }
     }
+
+    /// Every address that can receive part of the distribution
+    pub fn addrs(&self) -> impl Iterator<Item = &Addr> {
+        self.member_percentages
+            .iter()
+            .map(|x| &x.addr)
+            .chain(self.fixed_amounts.iter().map(|x| &x.addr))
+            .chain(self.remainder_shares.iter().map(|x| &x.addr))
+            .chain(std::iter::once(&self.remainder_addr))
+    }
 }
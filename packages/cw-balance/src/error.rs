@@ -1,6 +1,6 @@
 use cosmwasm_std::{
     CheckedFromRatioError, CheckedMultiplyFractionError, DecimalRangeExceeded, OverflowError,
-    StdError,
+    StdError, Uint128,
 };
 use thiserror::Error;
 
@@ -20,4 +20,11 @@ pub enum BalanceError {
 
     #[error("{0}")]
     DecimalRangeExceeded(#[from] DecimalRangeExceeded),
+
+    #[error("Fixed amounts of {required} {denom} exceed the available {available} {denom}")]
+    FixedAmountsExceedBalance {
+        denom: String,
+        required: Uint128,
+        available: Uint128,
+    },
 }
@@ -6,8 +6,8 @@ mod member_balance;
 mod util;
 
 pub use balance::{BalanceUnchecked, BalanceVerified};
-pub use cw721::{Cw721Collection, Cw721CollectionVerified};
-pub use distribution::{Distribution, MemberPercentage};
+pub use cw721::{Cw721Collection, Cw721CollectionVerified, NftAllocation};
+pub use distribution::{Distribution, MemberAmount, MemberPercentage, MemberShare};
 pub use error::BalanceError;
 pub use member_balance::{MemberBalanceChecked, MemberBalanceUnchecked};
 pub use util::is_contract;
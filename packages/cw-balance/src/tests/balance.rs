@@ -1,4 +1,6 @@
-use crate::{BalanceVerified, Cw721CollectionVerified, Distribution, MemberPercentage};
+use crate::{
+    BalanceVerified, Cw721CollectionVerified, Distribution, MemberPercentage, NftAllocation,
+};
 use cosmwasm_std::{Addr, Coin, Decimal, StdError, Uint128};
 use cw20::Cw20CoinVerified;
 
@@ -422,6 +424,8 @@ fn test_split_with_invalid_distribution() {
             },
         ],
         remainder_addr: Addr::unchecked("remainder"),
+        fixed_amounts: vec![],
+        remainder_shares: vec![],
     };
 
     let result = balance.split(&distribution);
@@ -534,3 +538,32 @@ fn test_checked_sub_with_partial_amounts() {
         vec!["2".to_string()]
     );
 }
+
+#[test]
+fn test_nft_allocations_total_balance() {
+    let allocations = vec![
+        NftAllocation {
+            addr: Addr::unchecked("addr_a"),
+            collection: Addr::unchecked("trophies"),
+            token_id: "1".to_string(),
+        },
+        NftAllocation {
+            addr: Addr::unchecked("addr_b"),
+            collection: Addr::unchecked("trophies"),
+            token_id: "2".to_string(),
+        },
+    ];
+
+    let total = NftAllocation::<Addr>::total_balance(&allocations).unwrap();
+    assert_eq!(
+        total.cw721,
+        Some(vec![Cw721CollectionVerified {
+            address: Addr::unchecked("trophies"),
+            token_ids: vec!["1".to_string(), "2".to_string()],
+        }])
+    );
+
+    // The same NFT cannot be allocated twice
+    let duplicate_allocations = vec![allocations[0].clone(), allocations[0].clone()];
+    assert!(NftAllocation::<Addr>::total_balance(&duplicate_allocations).is_err());
+}
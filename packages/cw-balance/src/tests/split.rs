@@ -1,6 +1,8 @@
 use crate::cw721::Cw721CollectionVerified;
-use crate::{BalanceVerified, Distribution, MemberPercentage};
-use cosmwasm_std::{Addr, Coin, Decimal, Uint128};
+use crate::{
+    BalanceError, BalanceVerified, Distribution, MemberAmount, MemberPercentage, MemberShare,
+};
+use cosmwasm_std::{coins, Addr, Coin, Decimal, Uint128, Uint64};
 use cw20::Cw20CoinVerified;
 
 #[test]
@@ -48,6 +50,8 @@ fn test_split_balances() {
             },
         ],
         remainder_addr: addr_c.clone(),
+        fixed_amounts: vec![],
+        remainder_shares: vec![],
     };
 
     let split_result = balance.split(&distribution).unwrap();
@@ -201,8 +205,105 @@ fn test_split_balances_invalid_percentages() {
             },
         ],
         remainder_addr: addr_c,
+        fixed_amounts: vec![],
+        remainder_shares: vec![],
     };
 
     let result = balance.split(&distribution);
     assert!(result.is_err());
 }
+
+#[test]
+fn test_split_balances_fixed_amounts_and_shares() {
+    let addr_a = Addr::unchecked("addr_a");
+    let addr_b = Addr::unchecked("addr_b");
+    let addr_c = Addr::unchecked("addr_c");
+    let addr_d = Addr::unchecked("addr_d");
+
+    let balance = BalanceVerified {
+        native: Some(coins(1100, "usdc")),
+        cw20: None,
+        cw721: None,
+    };
+
+    // 1st gets 500 and 2nd gets 200, then 1st gets 10% of the rest, and the remainder is split 3:1
+    let distribution = Distribution {
+        member_percentages: vec![MemberPercentage {
+            addr: addr_a.clone(),
+            percentage: Decimal::percent(10),
+        }],
+        remainder_addr: addr_d.clone(),
+        fixed_amounts: vec![
+            MemberAmount {
+                addr: addr_a.clone(),
+                amount: coins(500, "usdc"),
+            },
+            MemberAmount {
+                addr: addr_b.clone(),
+                amount: coins(200, "usdc"),
+            },
+        ],
+        remainder_shares: vec![
+            MemberShare {
+                addr: addr_c.clone(),
+                shares: Uint64::new(3),
+            },
+            MemberShare {
+                addr: addr_d.clone(),
+                shares: Uint64::one(),
+            },
+        ],
+    };
+
+    let split_result = balance.split(&distribution).unwrap();
+    assert_eq!(split_result.len(), 4);
+
+    let amount = |addr: &Addr| {
+        split_result
+            .iter()
+            .find(|mb| mb.addr == *addr)
+            .unwrap()
+            .balance
+            .native
+            .as_ref()
+            .unwrap()[0]
+            .amount
+    };
+
+    assert_eq!(amount(&addr_a), Uint128::new(540)); // 500 + 10% of 400
+    assert_eq!(amount(&addr_b), Uint128::new(200));
+    assert_eq!(amount(&addr_c), Uint128::new(270)); // 3/4 of 360
+    assert_eq!(amount(&addr_d), Uint128::new(90)); // 1/4 of 360
+}
+
+#[test]
+fn test_split_balances_fixed_amounts_exceed_balance() {
+    let addr_a = Addr::unchecked("addr_a");
+    let addr_b = Addr::unchecked("addr_b");
+
+    let balance = BalanceVerified {
+        native: Some(coins(400, "usdc")),
+        cw20: None,
+        cw721: None,
+    };
+
+    let distribution = Distribution {
+        member_percentages: vec![],
+        remainder_addr: addr_b,
+        fixed_amounts: vec![MemberAmount {
+            addr: addr_a,
+            amount: coins(500, "usdc"),
+        }],
+        remainder_shares: vec![],
+    };
+
+    let result = balance.split(&distribution);
+    assert_eq!(
+        result.unwrap_err(),
+        BalanceError::FixedAmountsExceedBalance {
+            denom: "usdc".to_string(),
+            required: Uint128::new(500),
+            available: Uint128::new(400),
+        }
+    );
+}
@@ -11,7 +11,7 @@ use arena_interface::{
         },
         state::{
             Competition, CompetitionResponse, CompetitionStatus, CompetitionV2_3, Config, Evidence,
-            TempCompetition,
+            MatchDispute, TempCompetition,
         },
         stats::{
             MemberStatsMsg, StatAggregationType, StatMsg, StatTableEntry, StatType, StatValue,
@@ -19,6 +19,7 @@ use arena_interface::{
         },
     },
     core::{ProposeMessage, TaxConfigurationResponse},
+    escrow::Vesting,
     fees::FeeInformation,
     group::{self, GroupContractInfo},
     helpers::is_expired,
@@ -30,7 +31,7 @@ use cosmwasm_std::{
     Deps, DepsMut, Empty, Env, MessageInfo, Order, Reply, Response, StdError, StdResult, Storage,
     SubMsg, Timestamp, Uint128, WasmMsg,
 };
-use cw_balance::Distribution;
+use cw_balance::{Distribution, NftAllocation};
 use cw_ownable::{get_ownership, initialize_owner};
 use cw_storage_plus::{
     Bound, Index, IndexList, IndexedMap, Item, Map, MultiIndex, SnapshotMap, Strategy,
@@ -333,7 +334,17 @@ impl<
             ExecuteBase::ProcessCompetition {
                 competition_id,
                 distribution,
-            } => self.execute_process_competition(deps, info, competition_id, distribution, None),
+                nft_allocations,
+                vesting,
+            } => self.execute_process_competition(
+                deps,
+                info,
+                competition_id,
+                distribution,
+                nft_allocations,
+                vesting,
+                None,
+            ),
             ExecuteBase::UpdateOwnership(action) => {
                 let ownership =
                     cw_ownable::update_ownership(deps, &env.block, &info.sender, action)?;
@@ -452,19 +463,38 @@ impl<
             CompetitionError::Unauthorized {}
         );
 
+        let evidence_id =
+            self.inner_add_evidence(deps.storage, &env, competition_id, &info.sender, evidence)?;
+
+        Ok(Response::new()
+            .add_attribute("action", "submit_evidence")
+            .add_attribute("sender", info.sender.to_string())
+            .add_attribute("evidence_count", evidence_id))
+    }
+
+    /// Saves evidence submitted by `submit_user` on a competition, and returns the competition's new evidence count.
+    /// Validating the competition and the user is left to the caller.
+    pub fn inner_add_evidence(
+        &self,
+        storage: &mut dyn Storage,
+        env: &Env,
+        competition_id: Uint128,
+        submit_user: &Addr,
+        evidence: Vec<String>,
+    ) -> StdResult<Uint128> {
         let mut evidence_id = self
             .competition_evidence_count
-            .may_load(deps.storage, competition_id.u128())?
+            .may_load(storage, competition_id.u128())?
             .unwrap_or_default();
 
         for item in evidence {
             self.competition_evidence.save(
-                deps.storage,
+                storage,
                 (competition_id.u128(), evidence_id.u128()),
                 &Evidence {
                     id: evidence_id,
-                    submit_user: info.sender.clone(),
-                    content: item.to_string(),
+                    submit_user: submit_user.clone(),
+                    content: item,
                     submit_time: env.block.time,
                 },
             )?;
@@ -473,12 +503,9 @@ impl<
         }
 
         self.competition_evidence_count
-            .save(deps.storage, competition_id.u128(), &evidence_id)?;
+            .save(storage, competition_id.u128(), &evidence_id)?;
 
-        Ok(Response::new()
-            .add_attribute("action", "submit_evidence")
-            .add_attribute("sender", info.sender.to_string())
-            .add_attribute("evidence_count", evidence_id))
+        Ok(evidence_id)
     }
 
     pub fn execute_activate_from_escrow(
@@ -532,6 +559,32 @@ impl<
         title: String,
         description: String,
         distribution: Option<Distribution<String>>,
+    ) -> Result<Response, CompetitionError> {
+        self.inner_jail(
+            deps,
+            &env,
+            info,
+            competition_id,
+            title,
+            description,
+            distribution,
+            true,
+        )
+    }
+
+    /// Jails a competition and creates a DAO proposal through the Arena Core to resolve it.
+    /// Active competitions can only be jailed before expiration if `require_expiration` is false.
+    #[allow(clippy::too_many_arguments)]
+    pub fn inner_jail(
+        &self,
+        deps: DepsMut,
+        env: &Env,
+        info: MessageInfo,
+        competition_id: Uint128,
+        title: String,
+        description: String,
+        distribution: Option<Distribution<String>>,
+        require_expiration: bool,
     ) -> Result<Response, CompetitionError> {
         // Ensure Module has an owner
         let ownership = get_ownership(deps.storage)?;
@@ -548,7 +601,9 @@ impl<
                 // Validate competition status
                 let activation_height = match competition.status {
                     CompetitionStatus::Active { activation_height } => {
-                        if !is_expired(&env.block, &competition.date, competition.duration) {
+                        if require_expiration
+                            && !is_expired(&env.block, &competition.date, competition.duration)
+                        {
                             return Err(CompetitionError::CompetitionNotExpired {});
                         }
 
@@ -574,6 +629,7 @@ impl<
                     description,
                     distribution,
                     originator: info.sender.to_string(),
+                    msg: None,
                 },
             })?,
             funds: info.funds,
@@ -586,6 +642,113 @@ impl<
             .add_message(msg))
     }
 
+    /// Creates a dispute on a single match of an active competition and a DAO proposal through the Arena Core to resolve it.
+    /// The competition is not jailed, and the proposal executes `resolve_msg` on this module.
+    /// The dispute's evidence is saved with the competition's evidence, so it is kept once the dispute is resolved.
+    /// Validating that the sender takes part in the match is left to the module.
+    #[allow(clippy::too_many_arguments)]
+    pub fn inner_dispute_match(
+        &self,
+        deps: DepsMut,
+        env: &Env,
+        info: MessageInfo,
+        competition: &Competition<CompetitionExt>,
+        title: String,
+        description: String,
+        evidence: Vec<String>,
+        resolve_msg: Binary,
+    ) -> Result<(MatchDispute, CosmosMsg), CompetitionError> {
+        // Ensure Module has an owner
+        let ownership = get_ownership(deps.storage)?;
+        let arena_core = ownership.owner.ok_or(CompetitionError::OwnershipError(
+            cw_ownable::OwnershipError::NoOwner,
+        ))?;
+
+        if !matches!(competition.status, CompetitionStatus::Active { .. }) {
+            return Err(CompetitionError::InvalidCompetitionStatus {
+                current_status: competition.status.clone(),
+            });
+        }
+
+        let first_evidence_id = self
+            .competition_evidence_count
+            .may_load(deps.storage, competition.id.u128())?
+            .unwrap_or_default();
+        let evidence_count =
+            self.inner_add_evidence(deps.storage, env, competition.id, &info.sender, evidence)?;
+        let dispute = MatchDispute {
+            originator: info.sender.clone(),
+            evidence_ids: (first_evidence_id.u128()..evidence_count.u128())
+                .map(Uint128::new)
+                .collect(),
+        };
+
+        // Create the proposal
+        let msg = CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: arena_core.to_string(),
+            msg: to_json_binary(&arena_interface::core::ExecuteMsg::Propose {
+                msg: ProposeMessage {
+                    competition_id: competition.id,
+                    title,
+                    description,
+                    distribution: None,
+                    originator: info.sender.to_string(),
+                    msg: Some(resolve_msg),
+                },
+            })?,
+            funds: info.funds,
+        });
+
+        Ok((dispute, msg))
+    }
+
+    /// Refunds an active competition that was not processed or jailed within `grace_period` seconds of its expiration.
+    /// The escrow is unlocked without a distribution, so members can withdraw their full balances.
+    pub fn inner_refund(
+        &self,
+        deps: DepsMut,
+        env: &Env,
+        competition_id: Uint128,
+        grace_period: u64,
+    ) -> Result<Response, CompetitionError> {
+        let competition = self
+            .competitions
+            .update(deps.storage, competition_id.u128(), |x| {
+                let mut competition =
+                    x.ok_or(CompetitionError::UnknownCompetitionId { id: competition_id })?;
+
+                if !matches!(competition.status, CompetitionStatus::Active { .. }) {
+                    return Err(CompetitionError::InvalidCompetitionStatus {
+                        current_status: competition.status,
+                    });
+                }
+                if !is_expired(
+                    &env.block,
+                    &competition.date,
+                    competition.duration.saturating_add(grace_period),
+                ) {
+                    return Err(CompetitionError::CompetitionNotExpired {});
+                }
+
+                competition.status = CompetitionStatus::Refunded;
+                Ok(competition)
+            })?;
+
+        let msg = CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: competition.escrow.to_string(),
+            msg: to_json_binary(&arena_interface::escrow::ExecuteMsg::Lock {
+                value: false,
+                transfer_ownership: None,
+            })?,
+            funds: vec![],
+        });
+
+        Ok(Response::new()
+            .add_attribute("action", "refund")
+            .add_attribute("competition_id", competition_id)
+            .add_message(msg))
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn execute_create_competition(
         &self,
@@ -613,21 +776,24 @@ impl<
 
         // Determine host
         let host = if let Some(host) = host {
-            let is_enrollment_module: bool = deps.querier.query_wasm_smart(
-                arena_core.to_string(),
-                &arena_interface::core::QueryMsg::QueryExtension {
-                    msg: arena_interface::core::QueryExt::IsValidEnrollmentModule {
-                        addr: info.sender.to_string(),
+            // The module can also create competitions for a host through its own messages
+            if info.sender != env.contract.address {
+                let is_enrollment_module: bool = deps.querier.query_wasm_smart(
+                    arena_core.to_string(),
+                    &arena_interface::core::QueryMsg::QueryExtension {
+                        msg: arena_interface::core::QueryExt::IsValidEnrollmentModule {
+                            addr: info.sender.to_string(),
+                        },
                     },
-                },
-            )?;
+                )?;
 
-            ensure!(
-                is_enrollment_module,
-                CompetitionError::StdError(StdError::generic_err(
-                    "Only a valid enrollment module can specify a host."
-                ))
-            );
+                ensure!(
+                    is_enrollment_module,
+                    CompetitionError::StdError(StdError::generic_err(
+                        "Only a valid enrollment module can specify a host."
+                    ))
+                );
+            }
 
             deps.api.addr_validate(&host)?
         } else {
@@ -811,13 +977,15 @@ impl<
         }
     }
 
-    #[allow(clippy::type_complexity)]
+    #[allow(clippy::type_complexity, clippy::too_many_arguments)]
     pub fn execute_process_competition(
         &self,
         mut deps: DepsMut,
         info: MessageInfo,
         competition_id: Uint128,
         distribution: Option<Distribution<String>>,
+        nft_allocations: Option<Vec<NftAllocation<String>>>,
+        vesting: Option<Vesting<String>>,
         post_processing: Option<
             fn(
                 deps: DepsMut,
@@ -839,10 +1007,23 @@ impl<
             .as_ref()
             .map(|some| some.into_checked(deps.as_ref()))
             .transpose()?;
+        let validated_nft_allocations = nft_allocations
+            .map(|x| {
+                x.iter()
+                    .map(|allocation| allocation.into_checked(deps.as_ref()))
+                    .collect::<StdResult<Vec<_>>>()
+            })
+            .transpose()?;
+        let validated_vesting = vesting.map(|x| x.into_checked(deps.as_ref())).transpose()?;
 
         // Process the competition
-        let mut response =
-            self.inner_process(deps.branch(), &competition, validated_distribution)?;
+        let mut response = self.inner_process(
+            deps.branch(),
+            &competition,
+            validated_distribution,
+            validated_nft_allocations,
+            validated_vesting,
+        )?;
 
         // Post-processing
         if let Some(post_processing) = post_processing {
@@ -897,6 +1078,8 @@ impl<
         deps: DepsMut,
         competition: &Competition<CompetitionExt>,
         distribution: Option<Distribution<Addr>>,
+        nft_allocations: Option<Vec<NftAllocation<Addr>>>,
+        vesting: Option<Vesting<Addr>>,
     ) -> Result<Response, CompetitionError> {
         // Set the result
         self.competition_result
@@ -904,6 +1087,12 @@ impl<
 
         // Get a distribution for messaging
         let distribution_msg = distribution.as_ref().map(|x| x.into_unchecked());
+        let nft_allocations_msg = nft_allocations.as_ref().map(|x| {
+            x.iter()
+                .map(|allocation| allocation.into_unchecked())
+                .collect()
+        });
+        let vesting_msg = vesting.as_ref().map(|x| x.into_unchecked());
 
         // Handle distribution, tax, and fees
         // Get Arena Tax config
@@ -949,6 +1138,8 @@ impl<
                             layered_fees,
                             activation_height: Some(activation_height),
                             group_contract: competition.group_contract.to_string(),
+                            vesting: vesting_msg,
+                            nft_allocations: nft_allocations_msg,
                         })?,
                         funds: vec![],
                     }),
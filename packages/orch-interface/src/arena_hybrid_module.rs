@@ -0,0 +1,29 @@
+use arena_hybrid_module::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use cw_orch::interface;
+use cw_orch::prelude::*;
+
+pub const CONTRACT_ID: &str = "arena_hybrid_module";
+
+#[interface(InstantiateMsg, ExecuteMsg, QueryMsg, MigrateMsg, id = CONTRACT_ID)]
+pub struct ArenaHybridModuleContract;
+
+impl<Chain> Uploadable for ArenaHybridModuleContract<Chain> {
+    /// Return the path to the wasm file corresponding to the contract
+    fn wasm(_chain: &ChainInfoOwned) -> WasmPath {
+        artifacts_dir_from_workspace!()
+            .find_wasm_path(CONTRACT_ID)
+            .unwrap()
+    }
+    /// Returns a CosmWasm contract wrapper
+    fn wrapper() -> Box<dyn MockContract<Empty>> {
+        Box::new(
+            ContractWrapper::new_with_empty(
+                arena_hybrid_module::contract::execute,
+                arena_hybrid_module::contract::instantiate,
+                arena_hybrid_module::contract::query,
+            )
+            .with_migrate(arena_hybrid_module::contract::migrate)
+            .with_reply(arena_hybrid_module::contract::reply),
+        )
+    }
+}
@@ -7,12 +7,20 @@ pub mod arena_discord_identity;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod arena_escrow;
 #[cfg(not(target_arch = "wasm32"))]
+pub mod arena_ffa_module;
+#[cfg(not(target_arch = "wasm32"))]
 pub mod arena_group;
 #[cfg(not(target_arch = "wasm32"))]
+pub mod arena_hybrid_module;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod arena_ladder_module;
+#[cfg(not(target_arch = "wasm32"))]
 pub mod arena_league_module;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod arena_payment_registry;
 #[cfg(not(target_arch = "wasm32"))]
+pub mod arena_swiss_module;
+#[cfg(not(target_arch = "wasm32"))]
 pub mod arena_token_gateway;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod arena_tournament_module;
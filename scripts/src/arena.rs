@@ -3,9 +3,11 @@ use cw_orch::prelude::*;
 use orch_interface::{
     arena_competition_enrollment::ArenaCompetitionEnrollmentContract,
     arena_core::ArenaCoreContract, arena_escrow::ArenaEscrowContract,
-    arena_group::ArenaGroupContract, arena_league_module::ArenaLeagueModuleContract,
+    arena_ffa_module::ArenaFfaModuleContract, arena_group::ArenaGroupContract,
+    arena_hybrid_module::ArenaHybridModuleContract, arena_ladder_module::ArenaLadderModuleContract,
+    arena_league_module::ArenaLeagueModuleContract,
     arena_payment_registry::ArenaPaymentRegistryContract,
-    arena_token_gateway::ArenaTokenGatewayContract,
+    arena_swiss_module::ArenaSwissModuleContract, arena_token_gateway::ArenaTokenGatewayContract,
     arena_tournament_module::ArenaTournamentModuleContract,
     arena_wager_module::ArenaWagerModuleContract, cw4_group::Cw4Group,
 };
@@ -17,6 +19,10 @@ pub struct Arena<Chain> {
     pub arena_wager_module: ArenaWagerModuleContract<Chain>,
     pub arena_league_module: ArenaLeagueModuleContract<Chain>,
     pub arena_tournament_module: ArenaTournamentModuleContract<Chain>,
+    pub arena_swiss_module: ArenaSwissModuleContract<Chain>,
+    pub arena_hybrid_module: ArenaHybridModuleContract<Chain>,
+    pub arena_ffa_module: ArenaFfaModuleContract<Chain>,
+    pub arena_ladder_module: ArenaLadderModuleContract<Chain>,
     pub arena_escrow: ArenaEscrowContract<Chain>,
     pub arena_competition_enrollment: ArenaCompetitionEnrollmentContract<Chain>,
     pub arena_token_gateway: ArenaTokenGatewayContract<Chain>,
@@ -33,6 +39,10 @@ impl<Chain: CwEnv> Arena<Chain> {
             arena_wager_module: ArenaWagerModuleContract::new(chain.clone()),
             arena_league_module: ArenaLeagueModuleContract::new(chain.clone()),
             arena_tournament_module: ArenaTournamentModuleContract::new(chain.clone()),
+            arena_swiss_module: ArenaSwissModuleContract::new(chain.clone()),
+            arena_hybrid_module: ArenaHybridModuleContract::new(chain.clone()),
+            arena_ffa_module: ArenaFfaModuleContract::new(chain.clone()),
+            arena_ladder_module: ArenaLadderModuleContract::new(chain.clone()),
             arena_escrow: ArenaEscrowContract::new(chain.clone()),
             arena_competition_enrollment: ArenaCompetitionEnrollmentContract::new(chain.clone()),
             arena_token_gateway: ArenaTokenGatewayContract::new(chain.clone()),
@@ -49,6 +59,10 @@ impl<Chain: CwEnv> Arena<Chain> {
         self.arena_wager_module.upload()?;
         self.arena_league_module.upload()?;
         self.arena_tournament_module.upload()?;
+        self.arena_swiss_module.upload()?;
+        self.arena_hybrid_module.upload()?;
+        self.arena_ffa_module.upload()?;
+        self.arena_ladder_module.upload()?;
         self.arena_competition_enrollment.upload()?;
         self.arena_token_gateway.upload()?;
         self.arena_payment_registry.upload()?;
@@ -4,10 +4,11 @@ pub use dao_dao::*;
 use orch_interface::{
     arena_competition_enrollment::ArenaCompetitionEnrollmentContract,
     arena_core::ArenaCoreContract, arena_discord_identity::ArenaDiscordIdentityContract,
-    arena_escrow::ArenaEscrowContract, arena_group::ArenaGroupContract,
-    arena_league_module::ArenaLeagueModuleContract,
+    arena_escrow::ArenaEscrowContract, arena_ffa_module::ArenaFfaModuleContract,
+    arena_group::ArenaGroupContract, arena_hybrid_module::ArenaHybridModuleContract,
+    arena_ladder_module::ArenaLadderModuleContract, arena_league_module::ArenaLeagueModuleContract,
     arena_payment_registry::ArenaPaymentRegistryContract,
-    arena_token_gateway::ArenaTokenGatewayContract,
+    arena_swiss_module::ArenaSwissModuleContract, arena_token_gateway::ArenaTokenGatewayContract,
     arena_tournament_module::ArenaTournamentModuleContract,
     arena_wager_module::ArenaWagerModuleContract, dao_dao_core::DaoDaoCoreContract,
 };
@@ -187,6 +188,10 @@ fn deploy_competition_modules(daemon: &Daemon) -> anyhow::Result<()> {
     ArenaWagerModuleContract::new(daemon.clone()).upload()?;
     ArenaLeagueModuleContract::new(daemon.clone()).upload()?;
     ArenaTournamentModuleContract::new(daemon.clone()).upload()?;
+    ArenaSwissModuleContract::new(daemon.clone()).upload()?;
+    ArenaHybridModuleContract::new(daemon.clone()).upload()?;
+    ArenaFfaModuleContract::new(daemon.clone()).upload()?;
+    ArenaLadderModuleContract::new(daemon.clone()).upload()?;
     Ok(())
 }
 
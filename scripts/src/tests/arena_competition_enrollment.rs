@@ -27,6 +27,7 @@ fn default_escrow_contract_info(arena: &Arena<MockBech32>) -> anyhow::Result<Esc
         msg: to_json_binary(&escrow::InstantiateMsg {
             dues: vec![],
             is_enrollment: true,
+            claim_deadline: None,
         })?,
         label: "Arena Escrow".to_string(),
         additional_layered_fees: None,
@@ -91,6 +92,7 @@ fn test_competition_enrollment() -> anyhow::Result<()> {
                 play_third_place_match: false,
             },
             distribution: vec![Decimal::percent(60), Decimal::percent(40)],
+            bracket_reset: None,
         },
         group_contract_info: ModuleInstantiateInfo {
             code_id: arena.arena_group.code_id()?,
@@ -214,6 +216,7 @@ fn test_invalid_enrollment() -> anyhow::Result<()> {
                 play_third_place_match: false,
             },
             distribution: vec![Decimal::percent(60), Decimal::percent(40)],
+            bracket_reset: None,
         },
         group_contract_info: ModuleInstantiateInfo {
             code_id: arena.arena_group.code_id()?,
@@ -271,6 +274,7 @@ fn test_enrollment_capacity() -> anyhow::Result<()> {
                 play_third_place_match: false,
             },
             distribution: vec![Decimal::percent(60), Decimal::percent(40)],
+            bracket_reset: None,
         },
         group_contract_info: ModuleInstantiateInfo {
             code_id: arena.arena_group.code_id()?,
@@ -343,6 +347,7 @@ fn test_tournament() -> anyhow::Result<()> {
                 play_third_place_match: false,
             },
             distribution: vec![Decimal::percent(60), Decimal::percent(40)],
+            bracket_reset: None,
         },
         group_contract_info: ModuleInstantiateInfo {
             code_id: arena.arena_group.code_id()?,
@@ -436,6 +441,7 @@ fn test_wager() -> anyhow::Result<()> {
         msg: to_json_binary(&escrow::InstantiateMsg {
             dues: vec![],
             is_enrollment: true,
+            claim_deadline: None,
         })?,
         label: "Arena Escrow".to_string(),
         additional_layered_fees: Some(vec![FeeInformation {
@@ -526,7 +532,11 @@ fn test_wager() -> anyhow::Result<()> {
                     percentage: Decimal::one(),
                 }],
                 remainder_addr: team1.to_string(),
+                fixed_amounts: vec![],
+                remainder_shares: vec![],
             }),
+            None,
+            None,
         )?;
 
     let enrollment = arena
@@ -595,6 +605,10 @@ fn test_successful_league_creation() -> anyhow::Result<()> {
                 Decimal::percent(30),
                 Decimal::percent(20),
             ],
+            round_duration: None,
+            legs: None,
+            tiebreakers: None,
+            playoffs: None,
         },
         group_contract_info: ModuleInstantiateInfo {
             code_id: arena.arena_group.code_id()?,
@@ -669,6 +683,7 @@ fn test_finalize_before_min_members() -> anyhow::Result<()> {
                 play_third_place_match: false,
             },
             distribution: vec![Decimal::percent(60), Decimal::percent(40)],
+            bracket_reset: None,
         },
         group_contract_info: ModuleInstantiateInfo {
             code_id: arena.arena_group.code_id()?,
@@ -778,6 +793,7 @@ fn test_unregistered_competition_enrollment() -> anyhow::Result<()> {
                 play_third_place_match: false,
             },
             distribution: vec![Decimal::percent(60), Decimal::percent(40)],
+            bracket_reset: None,
         },
         group_contract_info: ModuleInstantiateInfo {
             code_id: arena.arena_group.code_id()?,
@@ -873,6 +889,7 @@ fn test_huge_tournament() -> anyhow::Result<()> {
                 play_third_place_match: false,
             },
             distribution: vec![Decimal::percent(60), Decimal::percent(40)],
+            bracket_reset: None,
         },
         group_contract_info: ModuleInstantiateInfo {
             code_id: arena.arena_group.code_id()?,
@@ -178,7 +178,7 @@ fn test_competition_modules() -> anyhow::Result<()> {
 
     // Query competition modules
     let modules = arena.arena_core.competition_modules(None, None, None)?;
-    assert_eq!(modules.len(), 3); // Tournament, Wager, and League modules
+    assert_eq!(modules.len(), 7); // Tournament, Wager, League, Swiss, Hybrid, FFA, and Ladder modules
 
     // Disable a module
     arena.dao_dao.dao_proposal_sudo.set_sender(&admin);
@@ -201,7 +201,7 @@ fn test_competition_modules() -> anyhow::Result<()> {
     let updated_modules = arena
         .arena_core
         .competition_modules(Some(true), None, None)?;
-    assert!(!updated_modules[2].is_enabled); // The disabled modules are sent to the back
+    assert!(!updated_modules[6].is_enabled); // The disabled modules are sent to the back
 
     // Try to disable a non-existent module (should fail)
     let result = arena
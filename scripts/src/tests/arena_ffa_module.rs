@@ -0,0 +1,184 @@
+use arena_ffa_module::msg::{ExecuteExtFns as _, FfaInstantiateExt, FfaQueryExtFns as _};
+use arena_ffa_module::state::MatchStat;
+use arena_interface::competition::msg::QueryBaseFns as _;
+use arena_interface::competition::state::CompetitionStatus;
+use arena_interface::escrow::{ExecuteMsgFns as _, QueryMsgFns as _};
+use cosmwasm_std::{coins, Decimal, Uint128, Uint64};
+use cw_orch::{anyhow, prelude::*};
+
+use crate::tests::helpers::{create_ffa, setup_arena};
+
+use super::{DENOM, PREFIX};
+
+fn ffa_ext(placement_points: Vec<Uint64>) -> FfaInstantiateExt {
+    FfaInstantiateExt {
+        placement_points,
+        kill_points: Uint64::one(),
+        matches: Uint64::new(2),
+        distribution: vec![Decimal::percent(70), Decimal::percent(30)],
+    }
+}
+
+#[test]
+fn test_create_ffa() -> anyhow::Result<()> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+
+    let teams: Vec<_> = (0..4)
+        .map(|i| mock.addr_make_with_balance(format!("team{}", i), coins(10000, DENOM)))
+        .collect::<Result<_, _>>()?;
+
+    arena.arena_ffa_module.set_sender(&admin);
+
+    create_ffa(
+        &mock,
+        &arena,
+        &teams,
+        ffa_ext(vec![Uint64::new(10), Uint64::new(6), Uint64::new(3)]),
+    )?;
+
+    let ffa = arena.arena_ffa_module.competition(Uint128::one())?;
+    assert_eq!(ffa.extension.teams, Uint64::new(4));
+    assert_eq!(ffa.extension.processed_matches, Uint64::zero());
+
+    // Error - a lower placement cannot be worth more points
+    let result = create_ffa(
+        &mock,
+        &arena,
+        &teams,
+        ffa_ext(vec![Uint64::new(3), Uint64::new(6), Uint64::new(10)]),
+    );
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_process_ffa() -> anyhow::Result<()> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+
+    let teams: Vec<_> = (0..4)
+        .map(|i| mock.addr_make_with_balance(format!("team{}", i), coins(10000, DENOM)))
+        .collect::<Result<_, _>>()?;
+    let placements = |order: [usize; 4]| -> Vec<String> {
+        order.iter().map(|&i| teams[i].to_string()).collect()
+    };
+
+    arena.arena_ffa_module.set_sender(&admin);
+
+    let escrow_addr = create_ffa(
+        &mock,
+        &arena,
+        &teams,
+        ffa_ext(vec![
+            Uint64::new(10),
+            Uint64::new(6),
+            Uint64::new(3),
+            Uint64::new(1),
+        ]),
+    )?;
+    arena.arena_escrow.set_address(&escrow_addr);
+
+    // Fund the escrow
+    for team in &teams {
+        arena.arena_escrow.set_sender(team);
+        arena.arena_escrow.receive_native(&coins(1000, DENOM))?;
+    }
+
+    // Error - every member must be placed exactly once
+    let result = arena.arena_ffa_module.process_match(
+        Uint128::one(),
+        Uint64::one(),
+        placements([0, 1, 1, 3]),
+        None,
+    );
+    assert!(result.is_err());
+    let result = arena.arena_ffa_module.process_match(
+        Uint128::one(),
+        Uint64::one(),
+        placements([0, 1, 2]),
+        None,
+    );
+    assert!(result.is_err());
+
+    // Error - the match number is out of range
+    let result = arena.arena_ffa_module.process_match(
+        Uint128::one(),
+        Uint64::new(3),
+        placements([0, 1, 2, 3]),
+        None,
+    );
+    assert!(result.is_err());
+
+    arena.arena_ffa_module.process_match(
+        Uint128::one(),
+        Uint64::one(),
+        placements([0, 1, 2, 3]),
+        Some(vec![MatchStat {
+            addr: teams[1].to_string(),
+            kills: Uint64::new(5),
+            bonus_points: Uint64::zero(),
+        }]),
+    )?;
+
+    let standings = arena.arena_ffa_module.standings(Uint128::one())?;
+    assert_eq!(standings[0].member, teams[1]);
+    assert_eq!(standings[0].points, Uint64::new(11));
+    assert_eq!(standings[1].member, teams[0]);
+    assert_eq!(standings[1].wins, Uint64::one());
+
+    // A resubmitted match replaces its previous result
+    arena.arena_ffa_module.process_match(
+        Uint128::one(),
+        Uint64::one(),
+        placements([0, 1, 2, 3]),
+        Some(vec![MatchStat {
+            addr: teams[1].to_string(),
+            kills: Uint64::new(2),
+            bonus_points: Uint64::new(1),
+        }]),
+    )?;
+
+    let ffa_match = arena
+        .arena_ffa_module
+        .ffa_match(Uint128::one(), Uint64::one())?;
+    assert_eq!(ffa_match.placements[0], teams[0]);
+    assert_eq!(ffa_match.stats[0].kills, Uint64::new(2));
+
+    let ffa = arena.arena_ffa_module.competition(Uint128::one())?;
+    assert_eq!(ffa.extension.processed_matches, Uint64::one());
+
+    arena.arena_ffa_module.process_match(
+        Uint128::one(),
+        Uint64::new(2),
+        placements([0, 2, 1, 3]),
+        None,
+    )?;
+
+    // team0: 20, team1: 6 + 3 + 3 = 12, team2: 9, team3: 2
+    let standings = arena.arena_ffa_module.standings(Uint128::one())?;
+    assert_eq!(
+        standings.iter().map(|x| x.points).collect::<Vec<_>>(),
+        vec![
+            Uint64::new(20),
+            Uint64::new(12),
+            Uint64::new(9),
+            Uint64::new(2)
+        ]
+    );
+
+    // The competition is paid out from the final standings
+    let ffa = arena.arena_ffa_module.competition(Uint128::one())?;
+    assert_eq!(ffa.status, CompetitionStatus::Inactive);
+
+    let balances = arena.arena_escrow.balances(None, None)?;
+    assert_eq!(balances.len(), 2);
+    let winner_balance = arena.arena_escrow.balance(teams[0].to_string())?.unwrap();
+    assert_eq!(
+        winner_balance.native.unwrap()[0].amount,
+        Uint128::new(2660) // 4k * .95 (Arena tax) * .70 (user share)
+    );
+
+    Ok(())
+}
@@ -0,0 +1,218 @@
+use arena_hybrid_module::msg::{
+    ExecuteExtFns as _, GroupMatchResultMsg, HybridInstantiateExt, HybridQueryExtFns as _,
+    PlayoffMatchResultMsg,
+};
+use arena_hybrid_module::state::HybridStage;
+use arena_interface::competition::msg::QueryBaseFns as _;
+use arena_interface::competition::state::CompetitionStatus;
+use arena_interface::escrow::{ExecuteMsgFns as _, QueryMsgFns as _};
+use arena_league_module::state::MatchResult as GroupMatchResult;
+use arena_tournament_module::state::{EliminationType, MatchResult};
+use cosmwasm_std::{coins, Addr, Decimal, Uint128, Uint64};
+use cw_orch::{anyhow, prelude::*};
+
+use crate::tests::helpers::{create_hybrid, setup_arena};
+
+use super::{DENOM, PREFIX};
+
+fn hybrid_ext(group_count: Uint64, advance_per_group: Uint64) -> HybridInstantiateExt {
+    HybridInstantiateExt {
+        match_win_points: Uint64::new(3),
+        match_draw_points: Uint64::new(1),
+        match_lose_points: Uint64::zero(),
+        group_count,
+        advance_per_group,
+        elimination_type: EliminationType::SingleElimination {
+            play_third_place_match: false,
+        },
+        distribution: vec![Decimal::percent(70), Decimal::percent(30)],
+    }
+}
+
+#[test]
+fn test_create_hybrid() -> anyhow::Result<()> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+
+    let teams: Vec<_> = (0..8)
+        .map(|i| mock.addr_make_with_balance(format!("team{}", i), coins(10000, DENOM)))
+        .collect::<Result<_, _>>()?;
+
+    arena.arena_hybrid_module.set_sender(&admin);
+
+    create_hybrid(
+        &mock,
+        &arena,
+        &teams,
+        hybrid_ext(Uint64::new(2), Uint64::new(2)),
+    )?;
+
+    // Members are drawn into groups by a snake draw
+    let groups = arena.arena_hybrid_module.groups(Uint128::one())?;
+    assert_eq!(groups.len(), 2);
+    assert_eq!(
+        groups[0].members,
+        vec![
+            teams[0].clone(),
+            teams[3].clone(),
+            teams[4].clone(),
+            teams[7].clone()
+        ]
+    );
+    assert_eq!(
+        groups[1].members,
+        vec![
+            teams[1].clone(),
+            teams[2].clone(),
+            teams[5].clone(),
+            teams[6].clone()
+        ]
+    );
+
+    // Each group of 4 plays a round-robin
+    let hybrid = arena.arena_hybrid_module.competition(Uint128::one())?;
+    assert_eq!(hybrid.extension.group_matches, Uint128::new(12));
+    assert_eq!(hybrid.extension.stage, HybridStage::GroupStage);
+
+    // Error - a group cannot advance all of its members
+    let result = create_hybrid(
+        &mock,
+        &arena,
+        &teams,
+        hybrid_ext(Uint64::new(2), Uint64::new(4)),
+    );
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_process_hybrid() -> anyhow::Result<()> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+
+    let teams: Vec<_> = (0..8)
+        .map(|i| mock.addr_make_with_balance(format!("team{}", i), coins(10000, DENOM)))
+        .collect::<Result<_, _>>()?;
+
+    arena.arena_hybrid_module.set_sender(&admin);
+
+    let escrow_addr = create_hybrid(
+        &mock,
+        &arena,
+        &teams,
+        hybrid_ext(Uint64::new(2), Uint64::new(2)),
+    )?;
+    arena.arena_escrow.set_address(&escrow_addr);
+
+    // Fund the single escrow
+    for team in &teams {
+        arena.arena_escrow.set_sender(team);
+        arena.arena_escrow.receive_native(&coins(1000, DENOM))?;
+    }
+
+    // Error - the playoffs have not started
+    let result = arena.arena_hybrid_module.process_playoff_match(
+        Uint128::one(),
+        vec![PlayoffMatchResultMsg {
+            match_number: Uint128::one(),
+            match_result: MatchResult::Team1,
+        }],
+    );
+    assert!(result.is_err());
+
+    // The higher seed in each group wins every match
+    let groups = arena.arena_hybrid_module.groups(Uint128::one())?;
+    for group in &groups {
+        let matches = arena
+            .arena_hybrid_module
+            .group_matches(group.group, Uint128::one(), None)?;
+        let position = |addr: &Addr| group.members.iter().position(|x| x == addr).unwrap();
+
+        arena.arena_hybrid_module.process_group_match(
+            group.group,
+            Uint128::one(),
+            matches
+                .iter()
+                .map(|m| GroupMatchResultMsg {
+                    match_number: m.match_number,
+                    match_result: if position(&m.team_1) < position(&m.team_2) {
+                        GroupMatchResult::Team1
+                    } else {
+                        GroupMatchResult::Team2
+                    },
+                })
+                .collect(),
+        )?;
+    }
+
+    let standings = arena
+        .arena_hybrid_module
+        .group_standings(Uint64::one(), Uint128::one())?;
+    assert_eq!(standings[0].member, teams[0]);
+    assert_eq!(standings[0].points, Uint64::new(9));
+    assert_eq!(standings[1].member, teams[3]);
+
+    // The top 2 of each group are seeded into the bracket
+    let hybrid = arena.arena_hybrid_module.competition(Uint128::one())?;
+    assert_eq!(hybrid.extension.stage, HybridStage::Playoffs);
+    let bracket = arena.arena_hybrid_module.bracket(Uint128::one(), None)?;
+    assert_eq!(bracket.len(), 3);
+    let qualifiers = [&teams[0], &teams[1], &teams[2], &teams[3]];
+    assert!(bracket
+        .iter()
+        .filter_map(|x| x.team_1.as_ref())
+        .chain(bracket.iter().filter_map(|x| x.team_2.as_ref()))
+        .all(|x| qualifiers.contains(&x)));
+
+    // Error - the group stage is complete
+    let result = arena.arena_hybrid_module.process_group_match(
+        Uint64::one(),
+        Uint128::one(),
+        vec![GroupMatchResultMsg {
+            match_number: Uint128::one(),
+            match_result: GroupMatchResult::Team2,
+        }],
+    );
+    assert!(result.is_err());
+
+    // Play the semifinals, then the final
+    arena.arena_hybrid_module.process_playoff_match(
+        Uint128::one(),
+        vec![
+            PlayoffMatchResultMsg {
+                match_number: Uint128::one(),
+                match_result: MatchResult::Team1,
+            },
+            PlayoffMatchResultMsg {
+                match_number: Uint128::new(2),
+                match_result: MatchResult::Team1,
+            },
+        ],
+    )?;
+    let final_match = arena.arena_hybrid_module.bracket(Uint128::one(), None)?[2].clone();
+    arena.arena_hybrid_module.process_playoff_match(
+        Uint128::one(),
+        vec![PlayoffMatchResultMsg {
+            match_number: Uint128::new(3),
+            match_result: MatchResult::Team1,
+        }],
+    )?;
+
+    // The competition is paid out once from the single escrow
+    let hybrid = arena.arena_hybrid_module.competition(Uint128::one())?;
+    assert_eq!(hybrid.status, CompetitionStatus::Inactive);
+
+    let balances = arena.arena_escrow.balances(None, None)?;
+    assert_eq!(balances.len(), 2);
+    let winner_balance = arena
+        .arena_escrow
+        .balance(final_match.team_1.unwrap().to_string())?
+        .unwrap();
+    assert_eq!(
+        winner_balance.native.unwrap()[0].amount,
+        Uint128::new(5320) // 8k * .95 (Arena tax) * .70 (user share)
+    );
+
+    Ok(())
+}
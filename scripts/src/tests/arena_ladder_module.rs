@@ -0,0 +1,255 @@
+use arena_interface::competition::msg::QueryBaseFns as _;
+use arena_interface::competition::state::CompetitionStatus;
+use arena_interface::escrow::{ExecuteMsgFns as _, QueryMsgFns as _};
+use arena_ladder_module::msg::{ExecuteExtFns as _, LadderInstantiateExt, LadderQueryExtFns as _};
+use cosmwasm_std::{coins, Addr, Decimal, Uint128, Uint64};
+use cw_orch::{anyhow, prelude::*};
+
+use crate::tests::helpers::{create_ladder, setup_arena};
+use crate::Arena;
+
+use super::{DENOM, PREFIX};
+
+fn ladder_ext(challenge_range: Uint64) -> LadderInstantiateExt {
+    LadderInstantiateExt {
+        challenge_range,
+        accept_window: 3600,
+        snapshot_interval: 86400,
+        snapshots: Uint64::new(2),
+        distribution: vec![Decimal::percent(70), Decimal::percent(30)],
+    }
+}
+
+#[test]
+fn test_create_ladder() -> anyhow::Result<()> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+
+    let teams: Vec<_> = (0..4)
+        .map(|i| mock.addr_make_with_balance(format!("team{}", i), coins(10000, DENOM)))
+        .collect::<Result<_, _>>()?;
+
+    arena.arena_ladder_module.set_sender(&admin);
+
+    create_ladder(&mock, &arena, &teams, ladder_ext(Uint64::new(2)))?;
+
+    // The initial ladder uses the group's seed order
+    let standings = arena.arena_ladder_module.standings(Uint128::one())?;
+    assert_eq!(
+        standings
+            .iter()
+            .map(|x| x.member.clone())
+            .collect::<Vec<_>>(),
+        teams
+    );
+    assert_eq!(standings[0].position, Uint64::one());
+
+    // Error - the challenge range must be at least 1
+    let result = create_ladder(&mock, &arena, &teams, ladder_ext(Uint64::zero()));
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_ladder_challenges() -> anyhow::Result<()> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+
+    let teams: Vec<_> = (0..4)
+        .map(|i| mock.addr_make_with_balance(format!("team{}", i), coins(10000, DENOM)))
+        .collect::<Result<_, _>>()?;
+    let ladder_order = |arena: &Arena<MockBech32>| -> anyhow::Result<Vec<Addr>> {
+        Ok(arena
+            .arena_ladder_module
+            .standings(Uint128::one())?
+            .into_iter()
+            .map(|x| x.member)
+            .collect())
+    };
+
+    arena.arena_ladder_module.set_sender(&admin);
+
+    let escrow_addr = create_ladder(&mock, &arena, &teams, ladder_ext(Uint64::new(2)))?;
+    arena.arena_escrow.set_address(&escrow_addr);
+
+    // Error - challenges cannot be issued before the ladder is active
+    arena.arena_ladder_module.set_sender(&teams[3]);
+    let result = arena
+        .arena_ladder_module
+        .issue_challenge(Uint128::one(), teams[2].to_string());
+    assert!(result.is_err());
+
+    // Fund the escrow
+    for team in &teams {
+        arena.arena_escrow.set_sender(team);
+        arena.arena_escrow.receive_native(&coins(1000, DENOM))?;
+    }
+
+    // Error - the opponent is outside of the challenge range
+    let result = arena
+        .arena_ladder_module
+        .issue_challenge(Uint128::one(), teams[0].to_string());
+    assert!(result.is_err());
+
+    arena
+        .arena_ladder_module
+        .issue_challenge(Uint128::one(), teams[1].to_string())?;
+
+    // Error - a member can only be part of one open challenge
+    arena.arena_ladder_module.set_sender(&teams[2]);
+    let result = arena
+        .arena_ladder_module
+        .issue_challenge(Uint128::one(), teams[1].to_string());
+    assert!(result.is_err());
+
+    // Error - the challenge must be accepted before it is processed
+    arena.arena_ladder_module.set_sender(&admin);
+    let result = arena.arena_ladder_module.process_challenge(
+        Uint128::one(),
+        Uint128::one(),
+        teams[3].to_string(),
+    );
+    assert!(result.is_err());
+
+    arena.arena_ladder_module.set_sender(&teams[1]);
+    arena
+        .arena_ladder_module
+        .accept_challenge(Uint128::one(), Uint128::one())?;
+
+    // The challenger wins and swaps positions
+    arena.arena_ladder_module.set_sender(&admin);
+    arena.arena_ladder_module.process_challenge(
+        Uint128::one(),
+        Uint128::one(),
+        teams[3].to_string(),
+    )?;
+    assert_eq!(
+        ladder_order(&arena)?,
+        vec![
+            teams[0].clone(),
+            teams[3].clone(),
+            teams[2].clone(),
+            teams[1].clone()
+        ]
+    );
+
+    // An unanswered challenge is forfeited after the acceptance deadline
+    arena.arena_ladder_module.set_sender(&teams[2]);
+    arena
+        .arena_ladder_module
+        .issue_challenge(Uint128::one(), teams[0].to_string())?;
+    let open_challenges = arena
+        .arena_ladder_module
+        .open_challenges(Uint128::one(), None, None)?;
+    assert_eq!(open_challenges.len(), 1);
+
+    // Error - the acceptance deadline has not passed
+    let result = arena
+        .arena_ladder_module
+        .claim_forfeit(Uint128::new(2), Uint128::one());
+    assert!(result.is_err());
+
+    mock.wait_seconds(3600)?;
+
+    // Error - the acceptance deadline has passed
+    arena.arena_ladder_module.set_sender(&teams[0]);
+    let result = arena
+        .arena_ladder_module
+        .accept_challenge(Uint128::new(2), Uint128::one());
+    assert!(result.is_err());
+
+    arena
+        .arena_ladder_module
+        .claim_forfeit(Uint128::new(2), Uint128::one())?;
+    assert_eq!(
+        ladder_order(&arena)?,
+        vec![
+            teams[2].clone(),
+            teams[3].clone(),
+            teams[0].clone(),
+            teams[1].clone()
+        ]
+    );
+    assert!(arena
+        .arena_ladder_module
+        .open_challenges(Uint128::one(), None, None)?
+        .is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn test_ladder_snapshots() -> anyhow::Result<()> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+
+    let teams: Vec<_> = (0..4)
+        .map(|i| mock.addr_make_with_balance(format!("team{}", i), coins(10000, DENOM)))
+        .collect::<Result<_, _>>()?;
+
+    arena.arena_ladder_module.set_sender(&admin);
+
+    let escrow_addr = create_ladder(&mock, &arena, &teams, ladder_ext(Uint64::new(1)))?;
+    arena.arena_escrow.set_address(&escrow_addr);
+
+    // Fund the escrow
+    for team in &teams {
+        arena.arena_escrow.set_sender(team);
+        arena.arena_escrow.receive_native(&coins(1000, DENOM))?;
+    }
+
+    // Error - the first snapshot is a snapshot interval after the competition's date
+    let result = arena.arena_ladder_module.take_snapshot(Uint128::one());
+    assert!(result.is_err());
+
+    mock.wait_seconds(86400 * 2)?;
+    arena.arena_ladder_module.take_snapshot(Uint128::one())?;
+    assert_eq!(
+        arena
+            .arena_ladder_module
+            .snapshot(Uint128::one(), Uint64::one())?,
+        teams
+    );
+
+    // Error - the second snapshot is not ready
+    let result = arena.arena_ladder_module.take_snapshot(Uint128::one());
+    assert!(result.is_err());
+
+    // The second seed takes first place before the final snapshot
+    arena.arena_ladder_module.set_sender(&teams[1]);
+    arena
+        .arena_ladder_module
+        .issue_challenge(Uint128::one(), teams[0].to_string())?;
+    arena.arena_ladder_module.set_sender(&teams[0]);
+    arena
+        .arena_ladder_module
+        .accept_challenge(Uint128::one(), Uint128::one())?;
+    arena.arena_ladder_module.set_sender(&admin);
+    arena.arena_ladder_module.process_challenge(
+        Uint128::one(),
+        Uint128::one(),
+        teams[1].to_string(),
+    )?;
+
+    mock.wait_seconds(86400)?;
+    arena.arena_ladder_module.take_snapshot(Uint128::one())?;
+
+    // The final snapshot distributes the prize pool
+    let ladder = arena.arena_ladder_module.competition(Uint128::one())?;
+    assert_eq!(ladder.status, CompetitionStatus::Inactive);
+    assert_eq!(ladder.extension.snapshots_taken, Uint64::new(2));
+
+    // Both members held first and second place for one snapshot each
+    let balances = arena.arena_escrow.balances(None, None)?;
+    assert_eq!(balances.len(), 2);
+    for team in &teams[0..2] {
+        let balance = arena.arena_escrow.balance(team.to_string())?.unwrap();
+        assert_eq!(
+            balance.native.unwrap()[0].amount,
+            Uint128::new(1900) // 4k * .95 (Arena tax) * .50 (user share)
+        );
+    }
+
+    Ok(())
+}
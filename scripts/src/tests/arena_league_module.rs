@@ -3,6 +3,7 @@ use std::str::FromStr;
 use arena_interface::competition::msg::{
     EscrowContractInfo, ExecuteBaseFns as _, QueryBaseFns as _,
 };
+use arena_interface::competition::state::CompetitionStatus;
 use arena_interface::competition::stats::{
     MemberStatsMsg, StatAggregationType, StatMsg, StatType, StatValue, StatValueType,
 };
@@ -11,8 +12,11 @@ use arena_interface::escrow::{ExecuteMsgFns as _, QueryMsgFns as _};
 use arena_interface::group::{self, GroupContractInfo};
 use arena_league_module::msg::{
     ExecuteExtFns as _, LeagueInstantiateExt, LeagueQueryExtFns as _, MatchResultMsg,
+    PlacementDecider, PlayoffMatchResultMsg, SeasonDivisionMsg,
 };
-use arena_league_module::state::{MatchResult, PointAdjustment};
+use arena_league_module::state::{LeaguePlayoffs, LeagueTiebreaker, MatchResult, PointAdjustment};
+use arena_tournament_module::msg::QueryExtFns as _;
+use arena_tournament_module::state::{EliminationType, MatchResult as PlayoffMatchResult};
 use cosmwasm_std::{
     coins, to_json_binary, Addr, Coin, CosmosMsg, Decimal, Int128, Uint128, Uint64, WasmMsg,
 };
@@ -56,6 +60,7 @@ fn test_create_league() -> anyhow::Result<()> {
                     })
                     .collect(),
                 is_enrollment: false,
+                claim_deadline: None,
             })?,
             label: "League Escrow".to_string(),
             additional_layered_fees: None,
@@ -80,6 +85,10 @@ fn test_create_league() -> anyhow::Result<()> {
                 Decimal::percent(30),
                 Decimal::percent(20),
             ],
+            round_duration: None,
+            legs: None,
+            tiebreakers: None,
+            playoffs: None,
         },
         "Test League".to_string(),
         None,
@@ -108,6 +117,7 @@ fn test_create_league() -> anyhow::Result<()> {
             msg: to_json_binary(&arena_interface::escrow::InstantiateMsg {
                 dues: vec![],
                 is_enrollment: false,
+                claim_deadline: None,
             })?,
             label: "League Escrow".to_string(),
             additional_layered_fees: None,
@@ -128,6 +138,10 @@ fn test_create_league() -> anyhow::Result<()> {
             match_draw_points: Uint64::new(1),
             match_lose_points: Uint64::zero(),
             distribution: vec![Decimal::percent(100)],
+            round_duration: None,
+            legs: None,
+            tiebreakers: None,
+            playoffs: None,
         },
         "Invalid League",
         None,
@@ -181,6 +195,7 @@ fn test_process_league_matches() -> anyhow::Result<()> {
                     })
                     .collect(),
                 is_enrollment: false,
+                claim_deadline: None,
             })?,
             label: "League Escrow".to_string(),
             additional_layered_fees: None,
@@ -205,6 +220,10 @@ fn test_process_league_matches() -> anyhow::Result<()> {
                 Decimal::percent(30),
                 Decimal::percent(20),
             ],
+            round_duration: None,
+            legs: None,
+            tiebreakers: None,
+            playoffs: None,
         },
         "Test League".to_string(),
         None,
@@ -375,6 +394,7 @@ fn test_add_point_adjustments() -> anyhow::Result<()> {
                     })
                     .collect(),
                 is_enrollment: false,
+                claim_deadline: None,
             })?,
             label: "League Escrow".to_string(),
             additional_layered_fees: None,
@@ -399,6 +419,10 @@ fn test_add_point_adjustments() -> anyhow::Result<()> {
                 Decimal::percent(30),
                 Decimal::percent(20),
             ],
+            round_duration: None,
+            legs: None,
+            tiebreakers: None,
+            playoffs: None,
         },
         "Test League".to_string(),
         None,
@@ -502,6 +526,7 @@ fn test_create_league_with_odd_number_of_teams() -> anyhow::Result<()> {
                     })
                     .collect(),
                 is_enrollment: false,
+                claim_deadline: None,
             })?,
             label: "Odd League Escrow".to_string(),
             additional_layered_fees: None,
@@ -526,6 +551,10 @@ fn test_create_league_with_odd_number_of_teams() -> anyhow::Result<()> {
                 Decimal::percent(30),
                 Decimal::percent(20),
             ],
+            round_duration: None,
+            legs: None,
+            tiebreakers: None,
+            playoffs: None,
         },
         "Odd League",
         None,
@@ -581,6 +610,7 @@ fn test_process_league_with_ties() -> anyhow::Result<()> {
                     })
                     .collect(),
                 is_enrollment: false,
+                claim_deadline: None,
             })?,
             label: "Tie League Escrow".to_string(),
             additional_layered_fees: None,
@@ -606,6 +636,10 @@ fn test_process_league_with_ties() -> anyhow::Result<()> {
                 Decimal::percent(20),
                 Decimal::percent(10),
             ],
+            round_duration: None,
+            legs: None,
+            tiebreakers: None,
+            playoffs: None,
         },
         "Tie League",
         None,
@@ -775,6 +809,7 @@ fn test_update_distribution() -> anyhow::Result<()> {
                     })
                     .collect(),
                 is_enrollment: false,
+                claim_deadline: None,
             })?,
             label: "Distribution League Escrow".to_string(),
             additional_layered_fees: None,
@@ -800,6 +835,10 @@ fn test_update_distribution() -> anyhow::Result<()> {
                 Decimal::percent(20),
                 Decimal::percent(10),
             ],
+            round_duration: None,
+            legs: None,
+            tiebreakers: None,
+            playoffs: None,
         },
         "Distribution League",
         None,
@@ -861,6 +900,7 @@ fn test_create_huge_league() -> anyhow::Result<()> {
             msg: to_json_binary(&arena_interface::escrow::InstantiateMsg {
                 dues: vec![],
                 is_enrollment: false,
+                claim_deadline: None,
             })?,
             label: "League Escrow".to_string(),
             additional_layered_fees: None,
@@ -881,6 +921,10 @@ fn test_create_huge_league() -> anyhow::Result<()> {
             match_draw_points: Uint64::new(1),
             match_lose_points: Uint64::zero(),
             distribution: vec![Decimal::percent(100)],
+            round_duration: None,
+            legs: None,
+            tiebreakers: None,
+            playoffs: None,
         },
         "Huge League",
         None,
@@ -925,6 +969,7 @@ fn test_process_matches_out_of_order() -> anyhow::Result<()> {
                     })
                     .collect(),
                 is_enrollment: false,
+                claim_deadline: None,
             })?,
             label: "Out of Order League Escrow".to_string(),
             additional_layered_fees: None,
@@ -950,6 +995,10 @@ fn test_process_matches_out_of_order() -> anyhow::Result<()> {
                 Decimal::percent(20),
                 Decimal::percent(10),
             ],
+            round_duration: None,
+            legs: None,
+            tiebreakers: None,
+            playoffs: None,
         },
         "Out of Order League",
         None,
@@ -1010,6 +1059,7 @@ fn test_multiple_point_adjustments() -> anyhow::Result<()> {
                     })
                     .collect(),
                 is_enrollment: false,
+                claim_deadline: None,
             })?,
             label: "Multiple Adjustments League Escrow".to_string(),
             additional_layered_fees: None,
@@ -1035,6 +1085,10 @@ fn test_multiple_point_adjustments() -> anyhow::Result<()> {
                 Decimal::percent(20),
                 Decimal::percent(10),
             ],
+            round_duration: None,
+            legs: None,
+            tiebreakers: None,
+            playoffs: None,
         },
         "Multiple Adjustments League",
         None,
@@ -1175,6 +1229,7 @@ fn test_league_tiebreaking_logic() -> anyhow::Result<()> {
                     },
                 ],
                 is_enrollment: false,
+                claim_deadline: None,
             })?,
             label: "League Escrow".to_string(),
             additional_layered_fees: None,
@@ -1205,6 +1260,10 @@ fn test_league_tiebreaking_logic() -> anyhow::Result<()> {
                 Decimal::percent(15),
                 Decimal::percent(5),
             ],
+            round_duration: None,
+            legs: None,
+            tiebreakers: None,
+            playoffs: None,
         },
         "Tiebreaker Test League".to_string(),
         None,
@@ -1602,6 +1661,7 @@ fn test_league_tiebreaking_logic_with_aggregates() -> anyhow::Result<()> {
                     },
                 ],
                 is_enrollment: false,
+                claim_deadline: None,
             })?,
             label: "League Escrow with Aggregates".to_string(),
             additional_layered_fees: None,
@@ -1632,6 +1692,10 @@ fn test_league_tiebreaking_logic_with_aggregates() -> anyhow::Result<()> {
                 Decimal::percent(15),
                 Decimal::percent(5),
             ],
+            round_duration: None,
+            legs: None,
+            tiebreakers: None,
+            playoffs: None,
         },
         "Tiebreaker Test League with Aggregates".to_string(),
         None,
@@ -1877,3 +1941,1387 @@ fn test_league_tiebreaking_logic_with_aggregates() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_league_forfeits() -> anyhow::Result<()> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+
+    let teams: Vec<_> = (0..4)
+        .map(|i| mock.addr_make_with_balance(format!("team{}", i), coins(10000, DENOM)))
+        .collect::<Result<_, _>>()?;
+
+    arena.arena_league_module.set_sender(&admin);
+
+    let date = mock.block_info()?.time.plus_seconds(86400);
+    let res = arena.arena_league_module.create_competition(
+        date,
+        "A scheduled league".to_string(),
+        86400,
+        EscrowContractInfo::New {
+            code_id: arena.arena_escrow.code_id()?,
+            msg: to_json_binary(&arena_interface::escrow::InstantiateMsg {
+                dues: teams
+                    .iter()
+                    .map(|team| MemberBalanceUnchecked {
+                        addr: team.to_string(),
+                        balance: BalanceUnchecked {
+                            native: Some(vec![Coin::new(1000, DENOM)]),
+                            cw20: None,
+                            cw721: None,
+                        },
+                    })
+                    .collect(),
+                is_enrollment: false,
+                claim_deadline: None,
+            })?,
+            label: "League Escrow".to_string(),
+            additional_layered_fees: None,
+        },
+        GroupContractInfo::New {
+            info: ModuleInstantiateInfo {
+                code_id: arena.arena_group.code_id()?,
+                msg: to_json_binary(&group::InstantiateMsg {
+                    members: teams_to_members(&teams),
+                })?,
+                admin: None,
+                funds: vec![],
+                label: "Arena Group".to_string(),
+            },
+        },
+        LeagueInstantiateExt {
+            match_win_points: Uint64::new(3),
+            match_draw_points: Uint64::new(1),
+            match_lose_points: Uint64::zero(),
+            distribution: vec![Decimal::percent(60), Decimal::percent(40)],
+            round_duration: Some(3600),
+            legs: None,
+            tiebreakers: None,
+            playoffs: None,
+        },
+        "Test League".to_string(),
+        None,
+        None,
+        None,
+        None,
+        None,
+    )?;
+
+    let escrow_addr = res
+        .events
+        .iter()
+        .find_map(|event| {
+            event
+                .attributes
+                .iter()
+                .find(|attr| attr.key == "escrow_addr")
+                .map(|attr| attr.value.clone())
+        })
+        .ok_or_else(|| anyhow::anyhow!("Escrow address not found in events"))?;
+    arena
+        .arena_escrow
+        .set_address(&Addr::unchecked(escrow_addr));
+
+    for team in &teams {
+        arena.arena_escrow.set_sender(team);
+        arena.arena_escrow.receive_native(&coins(1000, DENOM))?;
+    }
+
+    // Rounds are scheduled from the league's date
+    let round_1 = arena
+        .arena_league_module
+        .round(Uint128::one(), Uint64::one())?;
+    assert_eq!(round_1.scheduled_date, Some(date));
+    assert_eq!(round_1.deadline, Some(date.plus_seconds(3600)));
+    let round_2 = arena
+        .arena_league_module
+        .round(Uint128::one(), Uint64::new(2))?;
+    assert_eq!(round_2.scheduled_date, Some(date.plus_seconds(3600)));
+
+    let match_1 = round_1
+        .matches
+        .iter()
+        .find(|m| m.match_number == Uint128::one())
+        .unwrap()
+        .clone();
+    let match_2 = round_1
+        .matches
+        .iter()
+        .find(|m| m.match_number == Uint128::new(2))
+        .unwrap()
+        .clone();
+
+    // Error - only the match's teams can report
+    arena.arena_league_module.set_sender(&match_2.team_1);
+    let result =
+        arena
+            .arena_league_module
+            .report_match(Uint128::one(), Uint128::one(), Uint64::one());
+    assert!(result.is_err());
+
+    // Only team 1 reports for match 1, and nobody reports for match 2
+    arena.arena_league_module.set_sender(&match_1.team_1);
+    arena
+        .arena_league_module
+        .report_match(Uint128::one(), Uint128::one(), Uint64::one())?;
+
+    // Error - the deadline has not passed
+    let result = arena
+        .arena_league_module
+        .process_forfeits(Uint128::one(), Uint64::one());
+    assert!(result.is_err());
+
+    mock.wait_seconds(86400 + 3601)?;
+
+    // Error - reports are closed after the deadline
+    arena.arena_league_module.set_sender(&match_1.team_2);
+    let result =
+        arena
+            .arena_league_module
+            .report_match(Uint128::one(), Uint128::one(), Uint64::one());
+    assert!(result.is_err());
+
+    // Anyone can process the forfeits
+    arena
+        .arena_league_module
+        .process_forfeits(Uint128::one(), Uint64::one())?;
+
+    let round_1 = arena
+        .arena_league_module
+        .round(Uint128::one(), Uint64::one())?;
+    for m in &round_1.matches {
+        if m.match_number == Uint128::one() {
+            assert_eq!(m.result, Some(MatchResult::Team1));
+            assert_eq!(m.forfeits, vec![match_1.team_2.clone()]);
+        } else {
+            assert_eq!(m.result, Some(MatchResult::DoubleForfeit));
+            assert_eq!(m.forfeits.len(), 2);
+        }
+    }
+
+    let leaderboard = arena
+        .arena_league_module
+        .leaderboard(Uint128::one(), None)?;
+    let standing = |addr: &Addr| leaderboard.iter().find(|x| x.member == *addr).unwrap();
+    assert_eq!(standing(&match_1.team_1).points, Int128::new(3));
+    assert_eq!(standing(&match_1.team_1).forfeits, Uint64::zero());
+    assert_eq!(standing(&match_1.team_2).points, Int128::zero());
+    assert_eq!(standing(&match_1.team_2).forfeits, Uint64::one());
+    assert_eq!(standing(&match_2.team_1).forfeits, Uint64::one());
+    assert_eq!(standing(&match_2.team_2).forfeits, Uint64::one());
+    assert_eq!(standing(&match_2.team_2).matches_played, Uint64::one());
+
+    // The host can still override a forfeit
+    arena.arena_league_module.set_sender(&admin);
+    arena.arena_league_module.process_match(
+        Uint128::one(),
+        vec![MatchResultMsg {
+            match_number: Uint128::new(2),
+            match_result: MatchResult::Draw,
+        }],
+        Uint64::one(),
+    )?;
+
+    let leaderboard = arena
+        .arena_league_module
+        .leaderboard(Uint128::one(), None)?;
+    let standing = leaderboard
+        .iter()
+        .find(|x| x.member == match_2.team_1)
+        .unwrap();
+    assert_eq!(standing.points, Int128::one());
+    assert_eq!(standing.forfeits, Uint64::zero());
+
+    // Both teams report for a match of round 2, and nobody reports for the other match
+    let round_2 = arena
+        .arena_league_module
+        .round(Uint128::one(), Uint64::new(2))?;
+    let reported_match = round_2.matches[0].clone();
+    for team in [&reported_match.team_1, &reported_match.team_2] {
+        arena.arena_league_module.set_sender(team);
+        arena.arena_league_module.report_match(
+            Uint128::one(),
+            reported_match.match_number,
+            Uint64::new(2),
+        )?;
+    }
+
+    mock.wait_seconds(3600)?;
+
+    arena
+        .arena_league_module
+        .process_forfeits(Uint128::one(), Uint64::new(2))?;
+
+    // The reported match is resolved as a draw, so it does not block the round
+    let round_2 = arena
+        .arena_league_module
+        .round(Uint128::one(), Uint64::new(2))?;
+    for m in &round_2.matches {
+        if m.match_number == reported_match.match_number {
+            assert_eq!(m.result, Some(MatchResult::Draw));
+            assert!(m.forfeits.is_empty());
+        } else {
+            assert_eq!(m.result, Some(MatchResult::DoubleForfeit));
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_double_round_robin_league() -> anyhow::Result<()> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+
+    let teams: Vec<_> = (0..3)
+        .map(|i| mock.addr_make_with_balance(format!("team{}", i), coins(10000, DENOM)))
+        .collect::<Result<_, _>>()?;
+
+    arena.arena_league_module.set_sender(&admin);
+
+    // Error - at least 1 leg is required
+    let result = arena.arena_league_module.create_competition(
+        mock.block_info()?.time.plus_seconds(86400),
+        "A double round-robin league".to_string(),
+        86400,
+        EscrowContractInfo::Existing {
+            addr: admin.to_string(),
+            additional_layered_fees: None,
+        },
+        GroupContractInfo::New {
+            info: ModuleInstantiateInfo {
+                code_id: arena.arena_group.code_id()?,
+                msg: to_json_binary(&group::InstantiateMsg {
+                    members: teams_to_members(&teams),
+                })?,
+                admin: None,
+                funds: vec![],
+                label: "Arena Group".to_string(),
+            },
+        },
+        LeagueInstantiateExt {
+            match_win_points: Uint64::new(3),
+            match_draw_points: Uint64::new(1),
+            match_lose_points: Uint64::zero(),
+            distribution: vec![Decimal::one()],
+            round_duration: None,
+            legs: Some(0),
+            tiebreakers: None,
+            playoffs: None,
+        },
+        "Test League".to_string(),
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    assert!(result.is_err());
+
+    let res = arena.arena_league_module.create_competition(
+        mock.block_info()?.time.plus_seconds(86400),
+        "A double round-robin league".to_string(),
+        86400,
+        EscrowContractInfo::New {
+            code_id: arena.arena_escrow.code_id()?,
+            msg: to_json_binary(&arena_interface::escrow::InstantiateMsg {
+                dues: teams
+                    .iter()
+                    .map(|team| MemberBalanceUnchecked {
+                        addr: team.to_string(),
+                        balance: BalanceUnchecked {
+                            native: Some(vec![Coin::new(1000, DENOM)]),
+                            cw20: None,
+                            cw721: None,
+                        },
+                    })
+                    .collect(),
+                is_enrollment: false,
+                claim_deadline: None,
+            })?,
+            label: "League Escrow".to_string(),
+            additional_layered_fees: None,
+        },
+        GroupContractInfo::New {
+            info: ModuleInstantiateInfo {
+                code_id: arena.arena_group.code_id()?,
+                msg: to_json_binary(&group::InstantiateMsg {
+                    members: teams_to_members(&teams),
+                })?,
+                admin: None,
+                funds: vec![],
+                label: "Arena Group".to_string(),
+            },
+        },
+        LeagueInstantiateExt {
+            match_win_points: Uint64::new(3),
+            match_draw_points: Uint64::new(1),
+            match_lose_points: Uint64::zero(),
+            distribution: vec![Decimal::one()],
+            round_duration: None,
+            legs: Some(2),
+            tiebreakers: None,
+            playoffs: None,
+        },
+        "Test League".to_string(),
+        None,
+        None,
+        None,
+        None,
+        None,
+    )?;
+
+    let escrow_addr = res
+        .events
+        .iter()
+        .find_map(|event| {
+            event
+                .attributes
+                .iter()
+                .find(|attr| attr.key == "escrow_addr")
+                .map(|attr| attr.value.clone())
+        })
+        .ok_or_else(|| anyhow::anyhow!("Escrow address not found in events"))?;
+    arena
+        .arena_escrow
+        .set_address(&Addr::unchecked(escrow_addr));
+
+    for team in &teams {
+        arena.arena_escrow.set_sender(team);
+        arena.arena_escrow.receive_native(&coins(1000, DENOM))?;
+    }
+
+    let league = arena.arena_league_module.competition(Uint128::one())?;
+    assert_eq!(league.extension.legs, 2);
+    assert_eq!(league.extension.rounds, Uint64::new(6));
+    assert_eq!(league.extension.matches, Uint128::new(6));
+
+    // The second leg swaps home and away
+    for round_number in 1..=3u64 {
+        let first_leg = arena
+            .arena_league_module
+            .round(Uint128::one(), Uint64::new(round_number))?;
+        let second_leg = arena
+            .arena_league_module
+            .round(Uint128::one(), Uint64::new(round_number + 3))?;
+
+        assert_eq!(first_leg.matches[0].team_1, second_leg.matches[0].team_2);
+        assert_eq!(first_leg.matches[0].team_2, second_leg.matches[0].team_1);
+    }
+
+    // The home team wins every match
+    arena.arena_league_module.set_sender(&admin);
+    for round_number in 1..=6u64 {
+        arena.arena_league_module.process_match(
+            Uint128::one(),
+            vec![MatchResultMsg {
+                match_number: Uint128::from(round_number),
+                match_result: MatchResult::Team1,
+            }],
+            Uint64::new(round_number),
+        )?;
+    }
+
+    let leaderboard = arena
+        .arena_league_module
+        .leaderboard(Uint128::one(), None)?;
+    assert_eq!(leaderboard.len(), 3);
+    for member_points in &leaderboard {
+        assert_eq!(member_points.matches_played, Uint64::new(4));
+        assert_eq!(member_points.points, Int128::new(6));
+        assert_eq!(member_points.home.points, Int128::new(6));
+        assert_eq!(member_points.home.matches_played, Uint64::new(2));
+        assert_eq!(member_points.away.points, Int128::zero());
+        assert_eq!(member_points.away.matches_played, Uint64::new(2));
+    }
+
+    let league = arena.arena_league_module.competition(Uint128::one())?;
+    assert_eq!(league.extension.processed_matches, Uint128::new(6));
+
+    Ok(())
+}
+
+#[test]
+fn test_league_head_to_head_tiebreaker() -> anyhow::Result<()> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+
+    let teams: Vec<_> = (0..4)
+        .map(|i| mock.addr_make_with_balance(format!("team{}", i), coins(10000, DENOM)))
+        .collect::<Result<_, _>>()?;
+
+    arena.arena_league_module.set_sender(&admin);
+
+    let res = arena.arena_league_module.create_competition(
+        mock.block_info()?.time.plus_seconds(86400),
+        "A league with head-to-head tiebreakers".to_string(),
+        86400,
+        EscrowContractInfo::New {
+            code_id: arena.arena_escrow.code_id()?,
+            msg: to_json_binary(&arena_interface::escrow::InstantiateMsg {
+                dues: teams
+                    .iter()
+                    .map(|team| MemberBalanceUnchecked {
+                        addr: team.to_string(),
+                        balance: BalanceUnchecked {
+                            native: Some(vec![Coin::new(1000, DENOM)]),
+                            cw20: None,
+                            cw721: None,
+                        },
+                    })
+                    .collect(),
+                is_enrollment: false,
+                claim_deadline: None,
+            })?,
+            label: "League Escrow".to_string(),
+            additional_layered_fees: None,
+        },
+        GroupContractInfo::New {
+            info: ModuleInstantiateInfo {
+                code_id: arena.arena_group.code_id()?,
+                msg: to_json_binary(&group::InstantiateMsg {
+                    members: teams_to_members(&teams),
+                })?,
+                admin: None,
+                funds: vec![],
+                label: "Arena Group".to_string(),
+            },
+        },
+        LeagueInstantiateExt {
+            match_win_points: Uint64::new(3),
+            match_draw_points: Uint64::new(1),
+            match_lose_points: Uint64::zero(),
+            distribution: vec![
+                Decimal::percent(50),
+                Decimal::percent(30),
+                Decimal::percent(20),
+            ],
+            round_duration: None,
+            legs: None,
+            tiebreakers: Some(vec![
+                LeagueTiebreaker::HeadToHead,
+                LeagueTiebreaker::Wins,
+                LeagueTiebreaker::CoinFlip,
+            ]),
+            playoffs: None,
+        },
+        "Test League".to_string(),
+        None,
+        None,
+        None,
+        None,
+        None,
+    )?;
+
+    let escrow_addr = res
+        .events
+        .iter()
+        .find_map(|event| {
+            event
+                .attributes
+                .iter()
+                .find(|attr| attr.key == "escrow_addr")
+                .map(|attr| attr.value.clone())
+        })
+        .ok_or_else(|| anyhow::anyhow!("Escrow address not found in events"))?;
+    arena
+        .arena_escrow
+        .set_address(&Addr::unchecked(escrow_addr));
+
+    for team in &teams {
+        arena.arena_escrow.set_sender(team);
+        arena.arena_escrow.receive_native(&coins(1000, DENOM))?;
+    }
+
+    // Team 0 and team 1 finish on 4 points, but team 0 won their match
+    // (team a, team b) -> winner, where None is a draw
+    let outcomes = [
+        ((0, 1), Some(0)),
+        ((0, 2), None),
+        ((0, 3), Some(3)),
+        ((1, 2), Some(1)),
+        ((1, 3), None),
+        ((2, 3), Some(3)),
+    ];
+    let index = |addr: &Addr| teams.iter().position(|x| x == addr).unwrap();
+
+    arena.arena_league_module.set_sender(&admin);
+    for round_number in 1..=3u64 {
+        let round = arena
+            .arena_league_module
+            .round(Uint128::one(), Uint64::new(round_number))?;
+
+        let match_results = round
+            .matches
+            .iter()
+            .map(|m| {
+                let (team_1, team_2) = (index(&m.team_1), index(&m.team_2));
+                let (_, winner) = outcomes
+                    .iter()
+                    .find(|(pair, _)| *pair == (team_1.min(team_2), team_1.max(team_2)))
+                    .unwrap();
+
+                MatchResultMsg {
+                    match_number: m.match_number,
+                    match_result: match winner {
+                        Some(winner) if *winner == team_1 => MatchResult::Team1,
+                        Some(_) => MatchResult::Team2,
+                        None => MatchResult::Draw,
+                    },
+                }
+            })
+            .collect();
+
+        arena.arena_league_module.process_match(
+            Uint128::one(),
+            match_results,
+            Uint64::new(round_number),
+        )?;
+    }
+
+    let leaderboard = arena
+        .arena_league_module
+        .leaderboard(Uint128::one(), None)?;
+
+    assert_eq!(leaderboard[0].member, teams[3]);
+    assert_eq!(leaderboard[0].points, Int128::new(7));
+    assert_eq!(leaderboard[0].decided_by, None);
+    assert_eq!(leaderboard[1].member, teams[0]);
+    assert_eq!(leaderboard[1].decided_by, Some(PlacementDecider::Points));
+    assert_eq!(leaderboard[2].member, teams[1]);
+    assert_eq!(leaderboard[2].points, leaderboard[1].points);
+    assert_eq!(
+        leaderboard[2].decided_by,
+        Some(PlacementDecider::Tiebreaker {
+            tiebreaker: LeagueTiebreaker::HeadToHead
+        })
+    );
+    assert_eq!(leaderboard[3].member, teams[2]);
+
+    // The payout follows the leaderboard's order
+    let expected_amounts = [1900u128, 1140, 760]; // 3800 (4000 - 5% tax) * 50%, 30%, 20%
+    for (member_points, amount) in leaderboard.iter().zip(expected_amounts) {
+        let balance = arena
+            .arena_escrow
+            .balance(member_points.member.to_string())?;
+        assert_eq!(
+            balance.unwrap().native.unwrap()[0].amount,
+            Uint128::new(amount)
+        );
+    }
+    assert!(arena
+        .arena_escrow
+        .balance(leaderboard[3].member.to_string())?
+        .is_none());
+
+    Ok(())
+}
+
+fn league_escrow(arena_escrow_code_id: u64, teams: &[Addr]) -> anyhow::Result<EscrowContractInfo> {
+    Ok(EscrowContractInfo::New {
+        code_id: arena_escrow_code_id,
+        msg: to_json_binary(&arena_interface::escrow::InstantiateMsg {
+            dues: teams
+                .iter()
+                .map(|team| MemberBalanceUnchecked {
+                    addr: team.to_string(),
+                    balance: BalanceUnchecked {
+                        native: Some(vec![Coin::new(1000, DENOM)]),
+                        cw20: None,
+                        cw721: None,
+                    },
+                })
+                .collect(),
+            is_enrollment: false,
+            claim_deadline: None,
+        })?,
+        label: "League Escrow".to_string(),
+        additional_layered_fees: None,
+    })
+}
+
+fn division_extension() -> LeagueInstantiateExt {
+    LeagueInstantiateExt {
+        match_win_points: Uint64::new(3),
+        match_draw_points: Uint64::new(1),
+        match_lose_points: Uint64::zero(),
+        distribution: vec![Decimal::one()],
+        round_duration: None,
+        legs: None,
+        tiebreakers: None,
+        playoffs: None,
+    }
+}
+
+#[test]
+fn test_league_seasons() -> anyhow::Result<()> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+
+    let teams: Vec<_> = (0..4)
+        .map(|i| mock.addr_make_with_balance(format!("team{}", i), coins(10000, DENOM)))
+        .collect::<Result<_, _>>()?;
+    let escrow_code_id = arena.arena_escrow.code_id()?;
+    let group_code_id = arena.arena_group.code_id()?;
+
+    // Teams 0 and 1 play in the top division, and teams 2 and 3 play in the bottom division
+    arena.arena_league_module.set_sender(&admin);
+    let mut escrows = vec![];
+    for division in teams.chunks(2) {
+        let res = arena.arena_league_module.create_competition(
+            mock.block_info()?.time.plus_seconds(86400),
+            "A season division".to_string(),
+            86400,
+            league_escrow(escrow_code_id, division)?,
+            GroupContractInfo::New {
+                info: ModuleInstantiateInfo {
+                    code_id: group_code_id,
+                    msg: to_json_binary(&group::InstantiateMsg {
+                        members: teams_to_members(division),
+                    })?,
+                    admin: None,
+                    funds: vec![],
+                    label: "Arena Group".to_string(),
+                },
+            },
+            division_extension(),
+            "Division".to_string(),
+            None,
+            None,
+            None,
+            None,
+            None,
+        )?;
+        let escrow_addr = res
+            .events
+            .iter()
+            .find_map(|event| {
+                event
+                    .attributes
+                    .iter()
+                    .find(|attr| attr.key == "escrow_addr")
+                    .map(|attr| attr.value.clone())
+            })
+            .ok_or_else(|| anyhow::anyhow!("Escrow address not found in events"))?;
+        escrows.push(escrow_addr);
+    }
+
+    // Only the host of every division can create the season
+    arena.arena_league_module.set_sender(&teams[0]);
+    let result = arena.arena_league_module.create_season(
+        vec![Uint128::one(), Uint128::new(2)],
+        "Season 1".to_string(),
+        Uint64::one(),
+        Uint64::one(),
+    );
+    assert!(result.is_err());
+
+    arena.arena_league_module.set_sender(&admin);
+    arena.arena_league_module.create_season(
+        vec![Uint128::one(), Uint128::new(2)],
+        "Season 1".to_string(),
+        Uint64::one(),
+        Uint64::one(),
+    )?;
+
+    for (escrow, division) in escrows.iter().zip(teams.chunks(2)) {
+        arena.arena_escrow.set_address(&Addr::unchecked(escrow));
+        for team in division {
+            arena.arena_escrow.set_sender(team);
+            arena.arena_escrow.receive_native(&coins(1000, DENOM))?;
+        }
+    }
+
+    // Movements are only available once every division has been processed
+    let result = arena.arena_league_module.season_movements(Uint128::one());
+    assert!(result.is_err());
+
+    // Team 0 wins the top division, and team 3 wins the bottom division
+    arena.arena_league_module.set_sender(&admin);
+    for (league_id, winner) in [(Uint128::one(), &teams[0]), (Uint128::new(2), &teams[3])] {
+        let round = arena.arena_league_module.round(league_id, Uint64::one())?;
+        arena.arena_league_module.process_match(
+            league_id,
+            vec![MatchResultMsg {
+                match_number: round.matches[0].match_number,
+                match_result: if round.matches[0].team_1 == winner {
+                    MatchResult::Team1
+                } else {
+                    MatchResult::Team2
+                },
+            }],
+            Uint64::one(),
+        )?;
+    }
+
+    let movements = arena.arena_league_module.season_movements(Uint128::one())?;
+    assert_eq!(movements[0].promoted, Vec::<Addr>::new());
+    assert_eq!(movements[0].relegated, vec![teams[1].clone()]);
+    assert_eq!(
+        movements[0].next_members,
+        vec![teams[0].clone(), teams[3].clone()]
+    );
+    assert_eq!(movements[1].promoted, vec![teams[3].clone()]);
+    assert_eq!(movements[1].relegated, Vec::<Addr>::new());
+    assert_eq!(
+        movements[1].next_members,
+        vec![teams[2].clone(), teams[1].clone()]
+    );
+
+    // Create the next season's divisions in one message
+    let divisions = movements
+        .iter()
+        .enumerate()
+        .map(|(i, movement)| {
+            Ok(SeasonDivisionMsg {
+                category_id: None,
+                escrow: league_escrow(escrow_code_id, &movement.next_members)?,
+                name: format!("Season 2 Division {}", i + 1),
+                description: "A season division".to_string(),
+                date: mock.block_info()?.time.plus_seconds(86400),
+                duration: 86400,
+                rules: None,
+                rulesets: None,
+                banner: None,
+                instantiate_extension: division_extension(),
+            })
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    arena.arena_league_module.create_next_season(
+        divisions.clone(),
+        group_code_id,
+        "Season 2".to_string(),
+        Uint128::one(),
+    )?;
+
+    // The next season can only be created once
+    let result = arena.arena_league_module.create_next_season(
+        divisions,
+        group_code_id,
+        "Season 2".to_string(),
+        Uint128::one(),
+    );
+    assert!(result.is_err());
+
+    let season = arena.arena_league_module.season(Uint128::one())?;
+    assert_eq!(season.next_season, Some(Uint128::new(2)));
+
+    let next_season = arena.arena_league_module.season(Uint128::new(2))?;
+    assert_eq!(next_season.previous_season, Some(Uint128::one()));
+    assert_eq!(
+        next_season.divisions,
+        vec![Uint128::new(3), Uint128::new(4)]
+    );
+
+    for (league_id, movement) in next_season.divisions.iter().zip(movements) {
+        let league = arena.arena_league_module.competition(*league_id)?;
+        assert_eq!(league.host, admin);
+        assert_eq!(league.extension.teams, Uint64::new(2));
+
+        let round = arena.arena_league_module.round(*league_id, Uint64::one())?;
+        let mut members = vec![
+            round.matches[0].team_1.clone(),
+            round.matches[0].team_2.clone(),
+        ];
+        let mut expected = movement.next_members;
+        members.sort();
+        expected.sort();
+        assert_eq!(members, expected);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_league_playoffs() -> anyhow::Result<()> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+
+    let teams: Vec<_> = (0..4)
+        .map(|i| mock.addr_make_with_balance(format!("team{}", i), coins(10000, DENOM)))
+        .collect::<Result<_, _>>()?;
+
+    arena.arena_league_module.set_sender(&admin);
+    let mut extension = division_extension();
+    extension.playoffs = Some(LeaguePlayoffs {
+        elimination_type: EliminationType::SingleElimination {
+            play_third_place_match: false,
+        },
+        qualifiers: Uint64::new(4),
+        regular_season_share: Decimal::percent(20),
+        distribution: vec![Decimal::percent(70), Decimal::percent(30)],
+        duration: 7 * 86400,
+    });
+    let res = arena.arena_league_module.create_competition(
+        mock.block_info()?.time.plus_seconds(86400),
+        "A league with playoffs".to_string(),
+        86400,
+        league_escrow(arena.arena_escrow.code_id()?, &teams)?,
+        GroupContractInfo::New {
+            info: ModuleInstantiateInfo {
+                code_id: arena.arena_group.code_id()?,
+                msg: to_json_binary(&group::InstantiateMsg {
+                    members: teams_to_members(&teams),
+                })?,
+                admin: None,
+                funds: vec![],
+                label: "Arena Group".to_string(),
+            },
+        },
+        extension,
+        "Test League".to_string(),
+        None,
+        None,
+        None,
+        None,
+        None,
+    )?;
+
+    let escrow_addr = res
+        .events
+        .iter()
+        .find_map(|event| {
+            event
+                .attributes
+                .iter()
+                .find(|attr| attr.key == "escrow_addr")
+                .map(|attr| attr.value.clone())
+        })
+        .ok_or_else(|| anyhow::anyhow!("Escrow address not found in events"))?;
+    arena
+        .arena_escrow
+        .set_address(&Addr::unchecked(escrow_addr));
+
+    for team in &teams {
+        arena.arena_escrow.set_sender(team);
+        arena.arena_escrow.receive_native(&coins(1000, DENOM))?;
+    }
+
+    // The lower numbered team wins every regular season match, which ends after the league's expiration
+    let index = |addr: &Addr| teams.iter().position(|x| x == addr).unwrap();
+    arena.arena_league_module.set_sender(&admin);
+    mock.wait_seconds(3 * 86400)?;
+    for round_number in 1..=3u64 {
+        let round = arena
+            .arena_league_module
+            .round(Uint128::one(), Uint64::new(round_number))?;
+
+        let match_results = round
+            .matches
+            .iter()
+            .map(|m| MatchResultMsg {
+                match_number: m.match_number,
+                match_result: if index(&m.team_1) < index(&m.team_2) {
+                    MatchResult::Team1
+                } else {
+                    MatchResult::Team2
+                },
+            })
+            .collect();
+
+        arena.arena_league_module.process_match(
+            Uint128::one(),
+            match_results,
+            Uint64::new(round_number),
+        )?;
+    }
+
+    // The playoffs are created on the tournament module with the league's escrow
+    // They start when the regular season ends, even after the league's own duration
+    let league = arena.arena_league_module.competition(Uint128::one())?;
+    assert_eq!(league.status, CompetitionStatus::Inactive);
+
+    let playoff_tournament = arena
+        .arena_league_module
+        .playoff_tournament(Uint128::one())?
+        .unwrap();
+    assert_eq!(
+        playoff_tournament.module,
+        arena.arena_tournament_module.address()?
+    );
+
+    let tournament = arena
+        .arena_tournament_module
+        .competition(playoff_tournament.tournament_id)?;
+    assert_eq!(tournament.escrow, league.escrow);
+    assert!(matches!(
+        tournament.status,
+        CompetitionStatus::Active { .. }
+    ));
+    assert_eq!(tournament.date, mock.block_info()?.time);
+    assert_eq!(tournament.duration, 7 * 86400);
+
+    // Only the league's host can process the playoffs
+    arena.arena_league_module.set_sender(&teams[0]);
+    let result = arena
+        .arena_league_module
+        .process_playoff_match(Uint128::one(), vec![]);
+    assert!(result.is_err());
+
+    // The higher numbered team wins every playoff match
+    arena.arena_league_module.set_sender(&admin);
+    for _ in 0..2 {
+        let match_results = arena
+            .arena_tournament_module
+            .bracket(playoff_tournament.tournament_id, None)?
+            .into_iter()
+            .filter(|m| m.result.is_none())
+            .filter_map(|m| match (&m.team_1, &m.team_2) {
+                (Some(team_1), Some(team_2)) => Some(PlayoffMatchResultMsg {
+                    match_number: m.match_number,
+                    match_result: if index(team_1) > index(team_2) {
+                        PlayoffMatchResult::Team1
+                    } else {
+                        PlayoffMatchResult::Team2
+                    },
+                }),
+                _ => None,
+            })
+            .collect();
+
+        arena
+            .arena_league_module
+            .process_playoff_match(Uint128::one(), match_results)?;
+    }
+
+    // Team 3 wins the final, and the other finalist places second
+    let final_match = arena
+        .arena_tournament_module
+        .bracket(playoff_tournament.tournament_id, None)?
+        .into_iter()
+        .max_by_key(|m| m.match_number)
+        .unwrap();
+    let finalists = [final_match.team_1.unwrap(), final_match.team_2.unwrap()];
+    assert!(finalists.contains(&teams[3]));
+    let second_place = index(finalists.iter().find(|x| **x != teams[3]).unwrap());
+
+    // 3800 (4000 - 5% tax) is split 20% to the regular season winner, and 80% by 70% and 30% to the playoff placements
+    let expected_amounts = [(0, 760u128), (3, 2128), (second_place, 912)];
+    for (team, amount) in expected_amounts {
+        let balance = arena.arena_escrow.balance(teams[team].to_string())?;
+        assert_eq!(
+            balance.unwrap().native.unwrap()[0].amount,
+            Uint128::new(amount)
+        );
+    }
+    let unpaid = 3 - second_place; // Whichever of team 1 and team 2 did not reach the final
+    assert!(arena
+        .arena_escrow
+        .balance(teams[unpaid].to_string())?
+        .is_none());
+
+    Ok(())
+}
+
+#[test]
+fn test_correct_league_match() -> anyhow::Result<()> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+
+    let teams: Vec<_> = (0..4)
+        .map(|i| mock.addr_make_with_balance(format!("team{}", i), coins(10000, DENOM)))
+        .collect::<Result<_, _>>()?;
+
+    arena.arena_league_module.set_sender(&admin);
+    arena.arena_league_module.create_competition(
+        mock.block_info()?.time.plus_seconds(86400),
+        "A league with a corrected match".to_string(),
+        86400,
+        league_escrow(arena.arena_escrow.code_id()?, &teams)?,
+        GroupContractInfo::New {
+            info: ModuleInstantiateInfo {
+                code_id: arena.arena_group.code_id()?,
+                msg: to_json_binary(&group::InstantiateMsg {
+                    members: teams_to_members(&teams),
+                })?,
+                admin: None,
+                funds: vec![],
+                label: "Arena Group".to_string(),
+            },
+        },
+        division_extension(),
+        "Test League".to_string(),
+        None,
+        Some(Uint128::one()),
+        None,
+        None,
+        None,
+    )?;
+
+    let round = arena
+        .arena_league_module
+        .round(Uint128::one(), Uint64::one())?;
+    let corrected_match = &round.matches[0];
+
+    // Unprocessed matches cannot be corrected
+    let result = arena.arena_league_module.correct_match(
+        Uint128::one(),
+        corrected_match.match_number,
+        MatchResult::Team2,
+        Uint64::one(),
+    );
+    assert!(result.is_err());
+
+    arena.arena_league_module.process_match(
+        Uint128::one(),
+        round
+            .matches
+            .iter()
+            .map(|m| MatchResultMsg {
+                match_number: m.match_number,
+                match_result: MatchResult::Team1,
+            })
+            .collect(),
+        Uint64::one(),
+    )?;
+
+    // The same result is rejected
+    let result = arena.arena_league_module.correct_match(
+        Uint128::one(),
+        corrected_match.match_number,
+        MatchResult::Team1,
+        Uint64::one(),
+    );
+    assert!(result.is_err());
+
+    // Only the host and the DAO can correct a match
+    arena.arena_league_module.set_sender(&teams[0]);
+    let result = arena.arena_league_module.correct_match(
+        Uint128::one(),
+        corrected_match.match_number,
+        MatchResult::Team2,
+        Uint64::one(),
+    );
+    assert!(result.is_err());
+
+    arena.arena_league_module.set_sender(&admin);
+    arena.arena_league_module.correct_match(
+        Uint128::one(),
+        corrected_match.match_number,
+        MatchResult::Team2,
+        Uint64::one(),
+    )?;
+
+    // The leaderboard reflects the corrected result
+    let leaderboard = arena
+        .arena_league_module
+        .leaderboard(Uint128::one(), None)?;
+    let points = |member: &Addr| {
+        leaderboard
+            .iter()
+            .find(|member_points| &member_points.member == member)
+            .unwrap()
+            .points
+    };
+    assert_eq!(points(&corrected_match.team_1), Int128::zero());
+    assert_eq!(points(&corrected_match.team_2), Int128::new(3));
+
+    let round = arena
+        .arena_league_module
+        .round(Uint128::one(), Uint64::one())?;
+    assert_eq!(round.matches[0].result, Some(MatchResult::Team2));
+    assert_eq!(round.matches[0].rated_result, Some(MatchResult::Team2));
+
+    // The original win was reversed, so the corrected winner has the higher rating
+    let rating = |member: &Addr| -> anyhow::Result<Decimal> {
+        Ok(arena
+            .arena_core
+            .rating(member.to_string(), Uint128::one())?
+            .unwrap()
+            .value)
+    };
+    assert!(rating(&corrected_match.team_2)? > rating(&corrected_match.team_1)?);
+
+    Ok(())
+}
+
+#[test]
+fn test_league_match_dispute() -> anyhow::Result<()> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+
+    let teams: Vec<_> = (0..3)
+        .map(|i| mock.addr_make_with_balance(format!("team{}", i), coins(10000, DENOM)))
+        .collect::<Result<_, _>>()?;
+
+    let last_round = Uint64::new(3);
+    arena.arena_league_module.set_sender(&admin);
+    let res = arena.arena_league_module.create_competition(
+        mock.block_info()?.time.plus_seconds(86400),
+        "A league with a disputed match".to_string(),
+        86400,
+        league_escrow(arena.arena_escrow.code_id()?, &teams)?,
+        GroupContractInfo::New {
+            info: ModuleInstantiateInfo {
+                code_id: arena.arena_group.code_id()?,
+                msg: to_json_binary(&group::InstantiateMsg {
+                    members: teams_to_members(&teams),
+                })?,
+                admin: None,
+                funds: vec![],
+                label: "Arena Group".to_string(),
+            },
+        },
+        division_extension(),
+        "Test League".to_string(),
+        None,
+        None,
+        None,
+        None,
+        None,
+    )?;
+
+    let escrow_addr = res
+        .events
+        .iter()
+        .find_map(|event| {
+            event
+                .attributes
+                .iter()
+                .find(|attr| attr.key == "escrow_addr")
+                .map(|attr| attr.value.clone())
+        })
+        .ok_or_else(|| anyhow::anyhow!("Escrow address not found in events"))?;
+    arena
+        .arena_escrow
+        .set_address(&Addr::unchecked(escrow_addr));
+    for team in &teams {
+        arena.arena_escrow.set_sender(team);
+        arena.arena_escrow.receive_native(&coins(1000, DENOM))?;
+    }
+
+    let disputed_match = arena
+        .arena_league_module
+        .round(Uint128::one(), last_round)?
+        .matches[0]
+        .clone();
+
+    // The disputing team is the only voter, so its proposal passes with its automatic vote
+    setup_voting_module(
+        &mock,
+        &arena,
+        vec![cw4::Member {
+            addr: disputed_match.team_1.to_string(),
+            weight: 1u64,
+        }],
+    )?;
+
+    arena
+        .arena_league_module
+        .call_as(&disputed_match.team_1)
+        .dispute_match(
+            "The opponent did not show up".to_string(),
+            vec!["https://example.com/lobby".to_string()],
+            Uint128::one(),
+            disputed_match.match_number,
+            MatchResult::Team1,
+            last_round,
+            "Disputed match".to_string(),
+            &[],
+        )?;
+
+    // The disputed match is frozen, but the other rounds can be processed
+    let result = arena.arena_league_module.process_match(
+        Uint128::one(),
+        vec![MatchResultMsg {
+            match_number: disputed_match.match_number,
+            match_result: MatchResult::Team2,
+        }],
+        last_round,
+    );
+    assert!(result.is_err());
+
+    for round_number in 1..=2u64 {
+        let round = arena
+            .arena_league_module
+            .round(Uint128::one(), Uint64::new(round_number))?;
+        arena.arena_league_module.process_match(
+            Uint128::one(),
+            round
+                .matches
+                .iter()
+                .map(|m| MatchResultMsg {
+                    match_number: m.match_number,
+                    match_result: MatchResult::Team1,
+                })
+                .collect(),
+            Uint64::new(round_number),
+        )?;
+    }
+
+    let league = arena.arena_league_module.competition(Uint128::one())?;
+    assert!(matches!(league.status, CompetitionStatus::Active { .. }));
+
+    // Executing the proposal resolves the match, which completes the league
+    mock.wait_blocks(100)?;
+    mock.call_as(&disputed_match.team_1).execute(
+        &dao_proposal_single::msg::ExecuteMsg::Execute { proposal_id: 1 },
+        &[],
+        &arena
+            .dao_dao
+            .dao_core
+            .proposal_modules(None, None)?
+            .iter()
+            .find(|x| x.prefix == "B")
+            .expect("Could not find the Arena Core's proposal module")
+            .address,
+    )?;
+
+    let round = arena
+        .arena_league_module
+        .round(Uint128::one(), last_round)?;
+    assert_eq!(round.matches[0].result, Some(MatchResult::Team1));
+    assert!(arena
+        .arena_league_module
+        .match_dispute(Uint128::one(), disputed_match.match_number, last_round)?
+        .is_none());
+
+    // The dispute's evidence is kept with the league's evidence
+    let evidence = arena
+        .arena_league_module
+        .evidence(Uint128::one(), None, None)?;
+    assert_eq!(evidence.len(), 1);
+    assert_eq!(evidence[0].submit_user, disputed_match.team_1);
+
+    let league = arena.arena_league_module.competition(Uint128::one())?;
+    assert_eq!(league.status, CompetitionStatus::Inactive);
+
+    Ok(())
+}
+
+#[test]
+fn test_rejected_league_match_dispute() -> anyhow::Result<()> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+
+    let teams: Vec<_> = (0..2)
+        .map(|i| mock.addr_make_with_balance(format!("team{}", i), coins(10000, DENOM)))
+        .collect::<Result<_, _>>()?;
+
+    arena.arena_league_module.set_sender(&admin);
+    let res = arena.arena_league_module.create_competition(
+        mock.block_info()?.time.plus_seconds(86400),
+        "A league with a rejected dispute".to_string(),
+        86400,
+        league_escrow(arena.arena_escrow.code_id()?, &teams)?,
+        GroupContractInfo::New {
+            info: ModuleInstantiateInfo {
+                code_id: arena.arena_group.code_id()?,
+                msg: to_json_binary(&group::InstantiateMsg {
+                    members: teams_to_members(&teams),
+                })?,
+                admin: None,
+                funds: vec![],
+                label: "Arena Group".to_string(),
+            },
+        },
+        division_extension(),
+        "Test League".to_string(),
+        None,
+        None,
+        None,
+        None,
+        None,
+    )?;
+
+    let escrow_addr = res
+        .events
+        .iter()
+        .find_map(|event| {
+            event
+                .attributes
+                .iter()
+                .find(|attr| attr.key == "escrow_addr")
+                .map(|attr| attr.value.clone())
+        })
+        .ok_or_else(|| anyhow::anyhow!("Escrow address not found in events"))?;
+    arena
+        .arena_escrow
+        .set_address(&Addr::unchecked(escrow_addr));
+    for team in &teams {
+        arena.arena_escrow.set_sender(team);
+        arena.arena_escrow.receive_native(&coins(1000, DENOM))?;
+    }
+
+    let disputed_match = arena
+        .arena_league_module
+        .round(Uint128::one(), Uint64::one())?
+        .matches[0]
+        .clone();
+
+    // The opponent is the only voter, so it can reject the dispute's proposal
+    setup_voting_module(
+        &mock,
+        &arena,
+        vec![cw4::Member {
+            addr: disputed_match.team_2.to_string(),
+            weight: 1u64,
+        }],
+    )?;
+
+    arena
+        .arena_league_module
+        .call_as(&disputed_match.team_1)
+        .dispute_match(
+            "The opponent did not show up".to_string(),
+            vec![],
+            Uint128::one(),
+            disputed_match.match_number,
+            MatchResult::Team1,
+            Uint64::one(),
+            "Disputed match".to_string(),
+            &[],
+        )?;
+
+    let proposal_module = arena
+        .dao_dao
+        .dao_core
+        .proposal_modules(None, None)?
+        .into_iter()
+        .find(|x| x.prefix == "B")
+        .expect("Could not find the Arena Core's proposal module")
+        .address;
+    mock.call_as(&disputed_match.team_2).execute(
+        &dao_proposal_single::msg::ExecuteMsg::Vote {
+            proposal_id: 1,
+            vote: dao_voting::voting::Vote::No,
+            rationale: None,
+        },
+        &[],
+        &proposal_module,
+    )?;
+    mock.wait_blocks(100)?;
+    let result = mock.call_as(&disputed_match.team_1).execute(
+        &dao_proposal_single::msg::ExecuteMsg::Execute { proposal_id: 1 },
+        &[],
+        &proposal_module,
+    );
+    assert!(result.is_err());
+
+    // Only the host and the DAO can dismiss the dispute
+    let result = arena
+        .arena_league_module
+        .call_as(&disputed_match.team_1)
+        .dismiss_dispute(Uint128::one(), disputed_match.match_number, Uint64::one());
+    assert!(result.is_err());
+
+    arena
+        .arena_league_module
+        .call_as(&arena.dao_dao.dao_core.address()?)
+        .dismiss_dispute(Uint128::one(), disputed_match.match_number, Uint64::one())?;
+
+    // The match can be processed again, which completes the league
+    arena.arena_league_module.process_match(
+        Uint128::one(),
+        vec![MatchResultMsg {
+            match_number: disputed_match.match_number,
+            match_result: MatchResult::Team2,
+        }],
+        Uint64::one(),
+    )?;
+
+    let league = arena.arena_league_module.competition(Uint128::one())?;
+    assert_eq!(league.status, CompetitionStatus::Inactive);
+
+    Ok(())
+}
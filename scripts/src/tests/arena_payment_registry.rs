@@ -30,6 +30,8 @@ fn test_set_and_get_distribution() -> anyhow::Result<()> {
             },
         ],
         remainder_addr: admin.to_string(),
+        fixed_amounts: vec![],
+        remainder_shares: vec![],
     };
 
     let res = arena
@@ -75,6 +77,8 @@ fn test_remove_distribution() -> anyhow::Result<()> {
             },
         ],
         remainder_addr: admin.to_string(),
+        fixed_amounts: vec![],
+        remainder_shares: vec![],
     };
 
     arena
@@ -110,6 +114,8 @@ fn test_multiple_users_distributions() -> anyhow::Result<()> {
             percentage: Decimal::percent(100),
         }],
         remainder_addr: user1.to_string(),
+        fixed_amounts: vec![],
+        remainder_shares: vec![],
     };
     arena
         .arena_payment_registry
@@ -123,6 +129,8 @@ fn test_multiple_users_distributions() -> anyhow::Result<()> {
             percentage: Decimal::percent(100),
         }],
         remainder_addr: user2.to_string(),
+        fixed_amounts: vec![],
+        remainder_shares: vec![],
     };
     arena
         .arena_payment_registry
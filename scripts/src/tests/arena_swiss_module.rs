@@ -0,0 +1,154 @@
+use std::collections::BTreeSet;
+
+use arena_interface::competition::msg::QueryBaseFns as _;
+use arena_interface::competition::state::CompetitionStatus;
+use arena_interface::escrow::{ExecuteMsgFns as _, QueryMsgFns as _};
+use arena_swiss_module::msg::{
+    ExecuteExtFns as _, MatchResultMsg, SwissInstantiateExt, SwissQueryExtFns as _,
+};
+use arena_swiss_module::state::{MatchResult, SwissTiebreaker};
+use cosmwasm_std::{coins, Decimal, Uint128, Uint64};
+use cw_orch::{anyhow, prelude::*};
+
+use crate::tests::helpers::{create_swiss, setup_arena, setup_voting_module};
+
+use super::{DENOM, PREFIX};
+
+fn swiss_ext(rounds: Uint64) -> SwissInstantiateExt {
+    SwissInstantiateExt {
+        match_win_points: Uint64::new(3),
+        match_draw_points: Uint64::new(1),
+        match_lose_points: Uint64::zero(),
+        rounds,
+        distribution: vec![Decimal::percent(60), Decimal::percent(40)],
+        tiebreakers: vec![SwissTiebreaker::Buchholz, SwissTiebreaker::SonnebornBerger],
+    }
+}
+
+#[test]
+fn test_create_swiss() -> anyhow::Result<()> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+
+    let teams: Vec<_> = (0..5)
+        .map(|i| mock.addr_make_with_balance(format!("team{}", i), coins(10000, DENOM)))
+        .collect::<Result<_, _>>()?;
+
+    arena.arena_swiss_module.set_sender(&admin);
+
+    create_swiss(&mock, &arena, &teams, swiss_ext(Uint64::new(3)))?;
+
+    let swiss = arena.arena_swiss_module.competition(Uint128::one())?;
+    assert_eq!(swiss.name, "Test Swiss");
+    assert_eq!(swiss.extension.current_round, Uint64::one());
+
+    // The first round pairs the top half against the bottom half and the lowest seed sits out
+    let round = arena
+        .arena_swiss_module
+        .round(Uint64::one(), Uint128::one())?;
+    assert_eq!(round.matches.len(), 2);
+    assert_eq!(round.bye, Some(teams[4].clone()));
+    assert_eq!(round.matches[0].team_1, teams[0]);
+    assert_eq!(round.matches[0].team_2, teams[2]);
+
+    // Error - more rounds than a round-robin would need
+    let result = create_swiss(&mock, &arena, &teams, swiss_ext(Uint64::new(6)));
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_process_swiss_rounds() -> anyhow::Result<()> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+    setup_voting_module(
+        &mock,
+        &arena,
+        vec![cw4::Member {
+            addr: admin.to_string(),
+            weight: 1u64,
+        }],
+    )?;
+
+    let teams: Vec<_> = (0..5)
+        .map(|i| mock.addr_make_with_balance(format!("team{}", i), coins(10000, DENOM)))
+        .collect::<Result<_, _>>()?;
+
+    arena.arena_swiss_module.set_sender(&admin);
+
+    let escrow_addr = create_swiss(&mock, &arena, &teams, swiss_ext(Uint64::new(3)))?;
+    arena.arena_escrow.set_address(&escrow_addr);
+
+    // Fund the escrow
+    for team in &teams {
+        arena.arena_escrow.set_sender(team);
+        arena.arena_escrow.receive_native(&coins(1000, DENOM))?;
+    }
+
+    let mut played = BTreeSet::new();
+    let mut byes = BTreeSet::new();
+    for round_number in 1..=3u64 {
+        let round = arena
+            .arena_swiss_module
+            .round(Uint64::new(round_number), Uint128::one())?;
+
+        // No member sits out twice and no pairing is repeated
+        let bye = round.bye.clone().unwrap();
+        assert!(byes.insert(bye));
+        for m in &round.matches {
+            let pair = if m.team_1 < m.team_2 {
+                (m.team_1.clone(), m.team_2.clone())
+            } else {
+                (m.team_2.clone(), m.team_1.clone())
+            };
+            assert!(played.insert(pair), "Rematch in round {}", round_number);
+        }
+
+        arena.arena_swiss_module.process_match(
+            round
+                .matches
+                .iter()
+                .map(|m| MatchResultMsg {
+                    match_number: m.match_number,
+                    match_result: MatchResult::Team1,
+                })
+                .collect(),
+            Uint128::one(),
+        )?;
+    }
+
+    // Standings are sorted by points, then tiebreakers
+    let standings = arena.arena_swiss_module.standings(Uint128::one(), None)?;
+    assert_eq!(standings.len(), 5);
+    assert!(standings.windows(2).all(|x| x[0].points > x[1].points
+        || (x[0].points == x[1].points && x[0].buchholz >= x[1].buchholz)));
+    assert_eq!(
+        standings.iter().map(|x| x.byes.u64()).sum::<u64>(),
+        3 // One bye per round
+    );
+
+    // The competition was processed after the last round
+    let swiss = arena.arena_swiss_module.competition(Uint128::one())?;
+    assert_eq!(swiss.status, CompetitionStatus::Inactive);
+    assert!(arena.arena_swiss_module.result(Uint128::one())?.is_some());
+
+    // The top of the standings received the largest share
+    let first = arena
+        .arena_escrow
+        .balance(standings[0].member.to_string())?
+        .unwrap();
+    assert!(first.native.is_some());
+
+    // Error - the competition can no longer be processed
+    let result = arena.arena_swiss_module.process_match(
+        vec![MatchResultMsg {
+            match_number: Uint128::one(),
+            match_result: MatchResult::Team2,
+        }],
+        Uint128::one(),
+    );
+    assert!(result.is_err());
+
+    Ok(())
+}
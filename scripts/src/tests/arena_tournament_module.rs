@@ -1,18 +1,25 @@
 use std::str::FromStr;
 
 use arena_interface::{
-    competition::msg::EscrowContractInfo,
+    competition::{
+        msg::{EscrowContractInfo, QueryBaseFns as _},
+        state::CompetitionStatus,
+    },
     core::QueryExtFns as _,
     escrow::{ExecuteMsgFns as _, QueryMsgFns as _},
     group::{self, AddMemberMsg, GroupContractInfo},
 };
 use arena_tournament_module::{
     msg::{
-        ExecuteExtFns as _, ExecuteMsg, MatchResultMsg, QueryExtFns as _, TournamentInstantiateExt,
+        ExecuteExtFns as _, ExecuteMsg, GroupMatchResultMsg, MatchResultMsg, QueryExtFns as _,
+        TournamentInstantiateExt,
+    },
+    state::{
+        BracketStage, EliminationType, GroupMatchResult, MatchResult, PlacementMode, Seeding,
+        SeriesConfig, StageBestOf,
     },
-    state::{EliminationType, MatchResult},
 };
-use cosmwasm_std::{coins, to_json_binary, Decimal, Timestamp, Uint128};
+use cosmwasm_std::{coins, to_json_binary, Decimal, HexBinary, Timestamp, Uint128, Uint64};
 use cw_balance::{BalanceUnchecked, MemberBalanceUnchecked};
 use cw_orch::{environment::ChainState, prelude::*};
 use dao_interface::state::ModuleInstantiateInfo;
@@ -103,7 +110,7 @@ pub fn test_tournament_instantiate() -> Result<(), CwOrchError> {
             &arena,
             None,
             &teams,
-            EliminationType::DoubleElimination {},
+            EliminationType::DoubleElimination,
             vec![
                 Decimal::from_ratio(75u128, 100u128),
                 Decimal::from_ratio(10u128, 100u128),
@@ -614,7 +621,7 @@ pub fn test_double_elimination_tournament_with_rebuttal() -> Result<(), CwOrchEr
             &arena,
             None,
             &teams,
-            EliminationType::DoubleElimination {},
+            EliminationType::DoubleElimination,
             vec![
                 Decimal::from_ratio(65u128, 100u128),
                 Decimal::from_ratio(25u128, 100u128),
@@ -844,7 +851,7 @@ pub fn test_double_elimination_tournament() -> Result<(), CwOrchError> {
             &arena,
             None,
             &teams,
-            EliminationType::DoubleElimination {},
+            EliminationType::DoubleElimination,
             vec![
                 Decimal::from_ratio(65u128, 100u128),
                 Decimal::from_ratio(25u128, 100u128),
@@ -1212,7 +1219,7 @@ pub fn test_double_elimination_many_teams() -> Result<(), CwOrchError> {
             &arena,
             None,
             &teams,
-            EliminationType::DoubleElimination {},
+            EliminationType::DoubleElimination,
             vec![
                 Decimal::from_ratio(65u128, 100u128),
                 Decimal::from_ratio(25u128, 100u128),
@@ -1245,7 +1252,7 @@ pub fn test_match_updates() -> Result<(), CwOrchError> {
             &arena,
             None,
             &teams,
-            EliminationType::DoubleElimination {},
+            EliminationType::DoubleElimination,
             vec![
                 Decimal::from_ratio(65u128, 100u128),
                 Decimal::from_ratio(25u128, 100u128),
@@ -1379,6 +1386,371 @@ pub fn test_match_updates() -> Result<(), CwOrchError> {
     Ok(())
 }
 
+#[test]
+pub fn test_best_of_series() -> Result<(), CwOrchError> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+
+    // Set teams
+    let mut teams = vec![];
+    for i in 0..4 {
+        teams.push(mock.addr_make_with_balance(format!("team {}", i), coins(100_000u128, DENOM))?);
+    }
+
+    arena.arena_tournament_module.set_sender(&admin);
+
+    // Best-of-3 semifinals and a best-of-5 final
+    let mut msg = create_competition_msg(
+        &arena,
+        None,
+        &teams,
+        EliminationType::SingleElimination {
+            play_third_place_match: false,
+        },
+        vec![
+            Decimal::from_ratio(75u128, 100u128),
+            Decimal::from_ratio(25u128, 100u128),
+        ],
+        mock.block_info()?.time.plus_seconds(86400),
+    )?;
+    if let ExecuteMsg::CreateCompetition {
+        instantiate_extension,
+        ..
+    } = &mut msg
+    {
+        instantiate_extension.series = Some(SeriesConfig {
+            best_of: 2,
+            stage_overrides: vec![],
+        });
+    }
+
+    // Error - a series must have an odd number of games
+    let result = arena.arena_tournament_module.execute(&msg, None);
+    assert!(result.is_err());
+
+    if let ExecuteMsg::CreateCompetition {
+        instantiate_extension,
+        ..
+    } = &mut msg
+    {
+        instantiate_extension.series = Some(SeriesConfig {
+            best_of: 3,
+            stage_overrides: vec![StageBestOf {
+                stage: BracketStage::Final,
+                best_of: 5,
+            }],
+        });
+    }
+    let response = arena.arena_tournament_module.execute(&msg, None)?;
+    mock.next_block()?;
+
+    // Get and set escrow addr
+    let escrow_addr = response.events.iter().find_map(|event| {
+        event
+            .attributes
+            .iter()
+            .find(|attr| attr.key == "escrow_addr")
+            .map(|attr| attr.value.clone())
+    });
+    arena
+        .arena_escrow
+        .set_address(&Addr::unchecked(escrow_addr.unwrap()));
+
+    // Fund tournament
+    for team in teams.iter() {
+        arena.arena_escrow.set_sender(team);
+        arena
+            .arena_escrow
+            .receive_native(&coins(10_000u128, DENOM))?;
+    }
+
+    let bracket = arena
+        .arena_tournament_module
+        .bracket(Uint128::one(), None)?;
+    assert_eq!(bracket.len(), 3);
+    assert_eq!(bracket[0].best_of, Some(3));
+    assert_eq!(bracket[1].best_of, Some(3));
+    assert_eq!(bracket[2].best_of, Some(5));
+
+    // A single game does not decide the series
+    arena.arena_tournament_module.process_match(
+        vec![MatchResultMsg {
+            match_number: Uint128::one(),
+            match_result: MatchResult::Team1,
+        }],
+        Uint128::one(),
+    )?;
+    let semifinal = arena
+        .arena_tournament_module
+        .r#match(Uint128::one(), Uint128::one())?;
+    assert_eq!(semifinal.result, None);
+    assert_eq!(semifinal.games, Some(vec![MatchResult::Team1]));
+
+    let final_match = arena
+        .arena_tournament_module
+        .r#match(Uint128::new(3), Uint128::one())?;
+    assert!(final_match.team_1.is_none() && final_match.team_2.is_none());
+
+    // Games accumulate until a team wins the majority
+    arena.arena_tournament_module.process_match(
+        vec![
+            MatchResultMsg {
+                match_number: Uint128::one(),
+                match_result: MatchResult::Team2,
+            },
+            MatchResultMsg {
+                match_number: Uint128::one(),
+                match_result: MatchResult::Team1,
+            },
+            MatchResultMsg {
+                match_number: Uint128::new(2),
+                match_result: MatchResult::Team2,
+            },
+            MatchResultMsg {
+                match_number: Uint128::new(2),
+                match_result: MatchResult::Team2,
+            },
+        ],
+        Uint128::one(),
+    )?;
+    let semifinal = arena
+        .arena_tournament_module
+        .r#match(Uint128::one(), Uint128::one())?;
+    assert_eq!(semifinal.result, Some(MatchResult::Team1));
+    assert_eq!(
+        semifinal.games,
+        Some(vec![
+            MatchResult::Team1,
+            MatchResult::Team2,
+            MatchResult::Team1
+        ])
+    );
+
+    let final_match = arena
+        .arena_tournament_module
+        .r#match(Uint128::new(3), Uint128::one())?;
+    assert!(final_match.team_1.is_some() && final_match.team_2.is_some());
+
+    // Error - the series is already decided
+    let result = arena.arena_tournament_module.process_match(
+        vec![MatchResultMsg {
+            match_number: Uint128::one(),
+            match_result: MatchResult::Team2,
+        }],
+        Uint128::one(),
+    );
+    assert!(result.is_err());
+
+    // Play the final to 3 wins
+    for _ in 0..2 {
+        arena.arena_tournament_module.process_match(
+            vec![MatchResultMsg {
+                match_number: Uint128::new(3),
+                match_result: MatchResult::Team1,
+            }],
+            Uint128::one(),
+        )?;
+        let balances = arena.arena_escrow.balances(None, None)?;
+        assert_eq!(balances.len(), 4); // Not distributed yet
+    }
+    arena.arena_tournament_module.process_match(
+        vec![MatchResultMsg {
+            match_number: Uint128::new(3),
+            match_result: MatchResult::Team1,
+        }],
+        Uint128::one(),
+    )?;
+
+    // Check distribution
+    let balances = arena.arena_escrow.balances(None, None)?;
+    assert_eq!(balances.len(), 2);
+
+    Ok(())
+}
+
+#[test]
+pub fn test_double_elimination_without_bracket_reset() -> Result<(), CwOrchError> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+    arena.arena_tournament_module.set_sender(&admin);
+
+    play_double_elimination_until_grand_final(&mock, &mut arena, Some(false))?;
+
+    // The loser's bracket champion wins the grand final
+    let grand_final = arena
+        .arena_tournament_module
+        .r#match(Uint128::new(18), Uint128::one())?;
+    arena.arena_tournament_module.process_match(
+        vec![MatchResultMsg {
+            match_number: Uint128::new(18),
+            match_result: MatchResult::Team2,
+        }],
+        Uint128::one(),
+    )?;
+    mock.next_block()?;
+
+    // No reset match is created
+    let bracket = arena
+        .arena_tournament_module
+        .bracket(Uint128::one(), None)?;
+    assert_eq!(bracket.len(), 18);
+
+    // The tournament is distributed immediately
+    let balances = arena.arena_escrow.balances(None, None)?;
+    assert_eq!(balances.len(), 3);
+    let winner_balance = arena
+        .arena_escrow
+        .balance(grand_final.team_2.unwrap().to_string())?
+        .unwrap();
+    assert_eq!(
+        winner_balance.native.unwrap()[0].amount,
+        Uint128::new(61750) // 100k * .95 (Arena tax) * .65 (user share)
+    );
+
+    Ok(())
+}
+
+#[test]
+pub fn test_bracket_reset_removed_on_grand_final_update() -> Result<(), CwOrchError> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+    arena.arena_tournament_module.set_sender(&admin);
+
+    // Double elimination plays a bracket reset if it is not configured
+    play_double_elimination_until_grand_final(&mock, &mut arena, None)?;
+
+    // The loser's bracket champion wins the grand final, so a reset is created
+    arena.arena_tournament_module.process_match(
+        vec![MatchResultMsg {
+            match_number: Uint128::new(18),
+            match_result: MatchResult::Team2,
+        }],
+        Uint128::one(),
+    )?;
+    mock.next_block()?;
+
+    let bracket = arena
+        .arena_tournament_module
+        .bracket(Uint128::one(), None)?;
+    assert_eq!(bracket.len(), 19);
+    let grand_final = arena
+        .arena_tournament_module
+        .r#match(Uint128::new(18), Uint128::one())?;
+    assert_eq!(grand_final.next_match_winner, Some(Uint128::new(19)));
+
+    // Correcting the grand final result removes the reset and finishes the tournament
+    arena.arena_tournament_module.process_match(
+        vec![MatchResultMsg {
+            match_number: Uint128::new(18),
+            match_result: MatchResult::Team1,
+        }],
+        Uint128::one(),
+    )?;
+    mock.next_block()?;
+
+    let bracket = arena
+        .arena_tournament_module
+        .bracket(Uint128::one(), None)?;
+    assert_eq!(bracket.len(), 18);
+
+    let balances = arena.arena_escrow.balances(None, None)?;
+    assert_eq!(balances.len(), 3);
+    let winner_balance = arena
+        .arena_escrow
+        .balance(grand_final.team_1.unwrap().to_string())?
+        .unwrap();
+    assert_eq!(
+        winner_balance.native.unwrap()[0].amount,
+        Uint128::new(61750) // 100k * .95 (Arena tax) * .65 (user share)
+    );
+
+    Ok(())
+}
+
+/// Creates and funds a 10 team double elimination tournament, then processes every match before the grand final
+fn play_double_elimination_until_grand_final(
+    mock: &MockBech32,
+    arena: &mut Arena<MockBech32>,
+    bracket_reset: Option<bool>,
+) -> Result<(), CwOrchError> {
+    let mut teams = vec![];
+    for i in 0..10 {
+        teams.push(mock.addr_make_with_balance(format!("team {}", i), coins(100_000u128, DENOM))?);
+    }
+
+    let mut msg = create_competition_msg(
+        arena,
+        None,
+        &teams,
+        EliminationType::DoubleElimination,
+        vec![
+            Decimal::from_ratio(65u128, 100u128),
+            Decimal::from_ratio(25u128, 100u128),
+            Decimal::from_ratio(10u128, 100u128),
+        ],
+        mock.block_info()?.time.plus_seconds(86400),
+    )?;
+    if let ExecuteMsg::CreateCompetition {
+        instantiate_extension,
+        ..
+    } = &mut msg
+    {
+        instantiate_extension.bracket_reset = bracket_reset;
+    }
+
+    let response = arena.arena_tournament_module.execute(&msg, None)?;
+    mock.next_block()?;
+
+    let escrow_addr = response
+        .events
+        .iter()
+        .find_map(|event| {
+            event
+                .attributes
+                .iter()
+                .find(|attr| attr.key == "escrow_addr")
+                .map(|attr| attr.value.clone())
+        })
+        .unwrap();
+    arena
+        .arena_escrow
+        .set_address(&Addr::unchecked(escrow_addr));
+
+    for team in teams.iter() {
+        arena.arena_escrow.set_sender(team);
+        arena
+            .arena_escrow
+            .receive_native(&coins(10_000u128, DENOM))?;
+    }
+
+    // Process each round in an order where every match already has both teams
+    for round in [
+        vec![2, 4, 5, 6],
+        vec![1, 3],
+        vec![7, 8],
+        vec![11, 12],
+        vec![9, 10],
+        vec![13, 14],
+        vec![15],
+        vec![16],
+        vec![17],
+    ] {
+        arena.arena_tournament_module.process_match(
+            round
+                .into_iter()
+                .map(|match_number| MatchResultMsg {
+                    match_number: Uint128::new(match_number),
+                    match_result: MatchResult::Team1,
+                })
+                .collect(),
+            Uint128::one(),
+        )?;
+        mock.next_block()?;
+    }
+
+    Ok(())
+}
+
 fn create_competition_msg<Chain: ChainState>(
     arena: &Arena<Chain>,
     category_id: Option<Uint128>,
@@ -1400,6 +1772,11 @@ fn create_competition_msg<Chain: ChainState>(
         instantiate_extension: TournamentInstantiateExt {
             elimination_type,
             distribution,
+            series: None,
+            carried_distribution: None,
+            seeding: None,
+            placements: None,
+            bracket_reset: None,
         },
         group_contract: GroupContractInfo::New {
             info: ModuleInstantiateInfo {
@@ -1435,6 +1812,7 @@ fn create_competition_msg<Chain: ChainState>(
                     })
                     .collect(),
                 is_enrollment: false,
+                claim_deadline: None,
             })
             .unwrap(),
             label: "Arena Escrow".to_string(),
@@ -1442,3 +1820,927 @@ fn create_competition_msg<Chain: ChainState>(
         },
     })
 }
+
+#[test]
+pub fn test_correct_match() -> Result<(), CwOrchError> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+
+    let mut teams = vec![];
+    for i in 0..4 {
+        teams.push(mock.addr_make_with_balance(format!("team {}", i), coins(100_000u128, DENOM))?);
+    }
+
+    arena.arena_tournament_module.set_sender(&admin);
+    let response = arena.arena_tournament_module.execute(
+        &create_competition_msg(
+            &arena,
+            Some(Uint128::one()),
+            &teams,
+            EliminationType::SingleElimination {
+                play_third_place_match: true,
+            },
+            vec![Decimal::one()],
+            mock.block_info()?.time.plus_seconds(86400),
+        )?,
+        None,
+    )?;
+    mock.next_block()?;
+
+    let escrow_addr = response.events.iter().find_map(|event| {
+        event
+            .attributes
+            .iter()
+            .find(|attr| attr.key == "escrow_addr")
+            .map(|attr| attr.value.clone())
+    });
+    arena
+        .arena_escrow
+        .set_address(&Addr::unchecked(escrow_addr.unwrap()));
+    for team in teams.iter() {
+        arena.arena_escrow.set_sender(team);
+        arena
+            .arena_escrow
+            .receive_native(&coins(10_000u128, DENOM))?;
+    }
+
+    // Unprocessed matches cannot be corrected
+    let result = arena.arena_tournament_module.correct_match(
+        Uint128::one(),
+        MatchResult::Team2,
+        Uint128::one(),
+    );
+    assert!(result.is_err());
+
+    arena.arena_tournament_module.process_match(
+        vec![MatchResultMsg {
+            match_number: Uint128::one(),
+            match_result: MatchResult::Team1,
+        }],
+        Uint128::one(),
+    )?;
+
+    // Only the host and the DAO can correct a match
+    arena.arena_tournament_module.set_sender(&teams[0]);
+    let result = arena.arena_tournament_module.correct_match(
+        Uint128::one(),
+        MatchResult::Team2,
+        Uint128::one(),
+    );
+    assert!(result.is_err());
+
+    // Correcting the match swaps the advanced winner and loser
+    arena.arena_tournament_module.set_sender(&admin);
+    arena.arena_tournament_module.correct_match(
+        Uint128::one(),
+        MatchResult::Team2,
+        Uint128::one(),
+    )?;
+
+    let first_match = arena
+        .arena_tournament_module
+        .r#match(Uint128::one(), Uint128::one())?;
+    assert_eq!(first_match.result, Some(MatchResult::Team2));
+    let final_match = arena
+        .arena_tournament_module
+        .r#match(first_match.next_match_winner.unwrap(), Uint128::one())?;
+    let third_place_match = arena
+        .arena_tournament_module
+        .r#match(first_match.next_match_loser.unwrap(), Uint128::one())?;
+    assert!(final_match.team_1 == first_match.team_2 || final_match.team_2 == first_match.team_2);
+    assert!(
+        third_place_match.team_1 == first_match.team_1
+            || third_place_match.team_2 == first_match.team_1
+    );
+    assert!(![&final_match.team_1, &final_match.team_2].contains(&&first_match.team_1));
+    assert!(![&third_place_match.team_1, &third_place_match.team_2].contains(&&first_match.team_2));
+
+    // The original win was reversed, so the corrected winner has the higher rating
+    let rating = |member: &Addr| -> Result<Decimal, CwOrchError> {
+        Ok(arena
+            .arena_core
+            .rating(member.to_string(), Uint128::one())?
+            .unwrap()
+            .value)
+    };
+    assert!(
+        rating(first_match.team_2.as_ref().unwrap())?
+            > rating(first_match.team_1.as_ref().unwrap())?
+    );
+
+    // The match cannot be corrected once a dependent match has been played
+    arena.arena_tournament_module.process_match(
+        vec![MatchResultMsg {
+            match_number: Uint128::new(2),
+            match_result: MatchResult::Team1,
+        }],
+        Uint128::one(),
+    )?;
+    arena.arena_tournament_module.process_match(
+        vec![MatchResultMsg {
+            match_number: third_place_match.match_number,
+            match_result: MatchResult::Team1,
+        }],
+        Uint128::one(),
+    )?;
+    let result = arena.arena_tournament_module.correct_match(
+        Uint128::one(),
+        MatchResult::Team1,
+        Uint128::one(),
+    );
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[test]
+pub fn test_match_dispute() -> Result<(), CwOrchError> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+
+    let mut teams = vec![];
+    for i in 0..4 {
+        teams.push(mock.addr_make_with_balance(format!("team {}", i), coins(100_000u128, DENOM))?);
+    }
+
+    // The disputing team is the only voter, so its proposal passes with its automatic vote
+    setup_voting_module(
+        &mock,
+        &arena,
+        vec![cw4::Member {
+            addr: teams[0].to_string(),
+            weight: 1u64,
+        }],
+    )?;
+
+    arena.arena_tournament_module.set_sender(&admin);
+    let response = arena.arena_tournament_module.execute(
+        &create_competition_msg(
+            &arena,
+            None,
+            &teams,
+            EliminationType::SingleElimination {
+                play_third_place_match: false,
+            },
+            vec![Decimal::one()],
+            mock.block_info()?.time.plus_seconds(86400),
+        )?,
+        None,
+    )?;
+    mock.next_block()?;
+
+    let escrow_addr = response.events.iter().find_map(|event| {
+        event
+            .attributes
+            .iter()
+            .find(|attr| attr.key == "escrow_addr")
+            .map(|attr| attr.value.clone())
+    });
+    arena
+        .arena_escrow
+        .set_address(&Addr::unchecked(escrow_addr.unwrap()));
+    for team in teams.iter() {
+        arena.arena_escrow.set_sender(team);
+        arena
+            .arena_escrow
+            .receive_native(&coins(10_000u128, DENOM))?;
+    }
+
+    let bracket = arena
+        .arena_tournament_module
+        .bracket(Uint128::one(), None)?;
+    let disputed_match = bracket
+        .iter()
+        .find(|m| m.team_1.as_ref() == Some(&teams[0]) || m.team_2.as_ref() == Some(&teams[0]))
+        .unwrap()
+        .clone();
+    let other_match = bracket
+        .iter()
+        .find(|m| m.match_number != disputed_match.match_number && m.team_1.is_some())
+        .unwrap()
+        .clone();
+    let final_match_number = disputed_match.next_match_winner.unwrap();
+    let proposed_result = if disputed_match.team_1.as_ref() == Some(&teams[0]) {
+        MatchResult::Team1
+    } else {
+        MatchResult::Team2
+    };
+
+    // Only the match's teams can dispute it
+    let result = arena
+        .arena_tournament_module
+        .call_as(other_match.team_1.as_ref().unwrap())
+        .dispute_match(
+            "The result was misreported".to_string(),
+            vec![],
+            disputed_match.match_number,
+            MatchResult::Team1,
+            "Disputed match".to_string(),
+            Uint128::one(),
+            &[],
+        );
+    assert!(result.is_err());
+
+    arena
+        .arena_tournament_module
+        .call_as(&teams[0])
+        .dispute_match(
+            "The result was misreported".to_string(),
+            vec!["https://example.com/replay".to_string()],
+            disputed_match.match_number,
+            proposed_result.clone(),
+            "Disputed match".to_string(),
+            Uint128::one(),
+            &[],
+        )?;
+
+    let dispute = arena
+        .arena_tournament_module
+        .match_dispute(disputed_match.match_number, Uint128::one())?
+        .unwrap();
+    assert_eq!(dispute.originator, teams[0]);
+    assert_eq!(dispute.evidence_ids, vec![Uint128::zero()]);
+
+    // The tournament is not jailed, and only the disputed match and its dependents are frozen
+    let tournament = arena.arena_tournament_module.competition(Uint128::one())?;
+    assert!(matches!(
+        tournament.status,
+        CompetitionStatus::Active { .. }
+    ));
+
+    let result = arena.arena_tournament_module.process_match(
+        vec![MatchResultMsg {
+            match_number: disputed_match.match_number,
+            match_result: MatchResult::Team1,
+        }],
+        Uint128::one(),
+    );
+    assert!(result.is_err());
+
+    arena.arena_tournament_module.process_match(
+        vec![MatchResultMsg {
+            match_number: other_match.match_number,
+            match_result: MatchResult::Team1,
+        }],
+        Uint128::one(),
+    )?;
+
+    let result = arena.arena_tournament_module.process_match(
+        vec![MatchResultMsg {
+            match_number: final_match_number,
+            match_result: MatchResult::Team1,
+        }],
+        Uint128::one(),
+    );
+    assert!(result.is_err());
+
+    // Only the DAO can resolve the dispute
+    let result = arena.arena_tournament_module.execute(
+        &ExecuteMsg::Extension {
+            msg: arena_tournament_module::msg::ExecuteExt::ResolveDispute {
+                tournament_id: Uint128::one(),
+                match_number: disputed_match.match_number,
+                match_result: proposed_result.clone(),
+            },
+        },
+        None,
+    );
+    assert!(result.is_err());
+
+    // Execute the dispute's proposal
+    mock.wait_blocks(100)?;
+    mock.call_as(&teams[0]).execute(
+        &dao_proposal_single::msg::ExecuteMsg::Execute { proposal_id: 1 },
+        &[],
+        &arena
+            .dao_dao
+            .dao_core
+            .proposal_modules(None, None)?
+            .iter()
+            .find(|x| x.prefix == "B")
+            .expect("Could not find the Arena Core's proposal module")
+            .address,
+    )?;
+
+    let resolved_match = arena
+        .arena_tournament_module
+        .r#match(disputed_match.match_number, Uint128::one())?;
+    assert_eq!(resolved_match.result, Some(proposed_result));
+    assert!(arena
+        .arena_tournament_module
+        .match_dispute(disputed_match.match_number, Uint128::one())?
+        .is_none());
+
+    // The dispute's evidence is kept with the tournament's evidence
+    let evidence = arena
+        .arena_tournament_module
+        .evidence(Uint128::one(), None, None)?;
+    assert_eq!(evidence.len(), 1);
+    assert_eq!(evidence[0].submit_user, teams[0]);
+    assert_eq!(evidence[0].content, "https://example.com/replay");
+
+    let final_match = arena
+        .arena_tournament_module
+        .r#match(final_match_number, Uint128::one())?;
+    assert!(
+        final_match.team_1.as_ref() == Some(&teams[0])
+            || final_match.team_2.as_ref() == Some(&teams[0])
+    );
+
+    // The rest of the tournament continues
+    arena.arena_tournament_module.process_match(
+        vec![MatchResultMsg {
+            match_number: final_match_number,
+            match_result: MatchResult::Team1,
+        }],
+        Uint128::one(),
+    )?;
+    let tournament = arena.arena_tournament_module.competition(Uint128::one())?;
+    assert_eq!(tournament.status, CompetitionStatus::Inactive);
+
+    Ok(())
+}
+
+#[test]
+pub fn test_rejected_match_dispute() -> Result<(), CwOrchError> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+
+    let mut teams = vec![];
+    for i in 0..2 {
+        teams.push(mock.addr_make_with_balance(format!("team {}", i), coins(100_000u128, DENOM))?);
+    }
+
+    // The opponent is the only voter, so it can reject the dispute's proposal
+    setup_voting_module(
+        &mock,
+        &arena,
+        vec![cw4::Member {
+            addr: teams[1].to_string(),
+            weight: 1u64,
+        }],
+    )?;
+
+    arena.arena_tournament_module.set_sender(&admin);
+    let response = arena.arena_tournament_module.execute(
+        &create_competition_msg(
+            &arena,
+            None,
+            &teams,
+            EliminationType::SingleElimination {
+                play_third_place_match: false,
+            },
+            vec![Decimal::one()],
+            mock.block_info()?.time.plus_seconds(86400),
+        )?,
+        None,
+    )?;
+    mock.next_block()?;
+
+    let escrow_addr = response.events.iter().find_map(|event| {
+        event
+            .attributes
+            .iter()
+            .find(|attr| attr.key == "escrow_addr")
+            .map(|attr| attr.value.clone())
+    });
+    arena
+        .arena_escrow
+        .set_address(&Addr::unchecked(escrow_addr.unwrap()));
+    for team in teams.iter() {
+        arena.arena_escrow.set_sender(team);
+        arena
+            .arena_escrow
+            .receive_native(&coins(10_000u128, DENOM))?;
+    }
+
+    arena
+        .arena_tournament_module
+        .call_as(&teams[0])
+        .dispute_match(
+            "The result was misreported".to_string(),
+            vec![],
+            Uint128::one(),
+            MatchResult::Team1,
+            "Disputed match".to_string(),
+            Uint128::one(),
+            &[],
+        )?;
+
+    // The proposal is rejected, so it cannot resolve the dispute
+    let proposal_module = arena
+        .dao_dao
+        .dao_core
+        .proposal_modules(None, None)?
+        .into_iter()
+        .find(|x| x.prefix == "B")
+        .expect("Could not find the Arena Core's proposal module")
+        .address;
+    mock.call_as(&teams[1]).execute(
+        &dao_proposal_single::msg::ExecuteMsg::Vote {
+            proposal_id: 1,
+            vote: dao_voting::voting::Vote::No,
+            rationale: None,
+        },
+        &[],
+        &proposal_module,
+    )?;
+    mock.wait_blocks(100)?;
+    let result = mock.call_as(&teams[0]).execute(
+        &dao_proposal_single::msg::ExecuteMsg::Execute { proposal_id: 1 },
+        &[],
+        &proposal_module,
+    );
+    assert!(result.is_err());
+
+    // The match stays frozen until the dispute is dismissed
+    let result = arena.arena_tournament_module.process_match(
+        vec![MatchResultMsg {
+            match_number: Uint128::one(),
+            match_result: MatchResult::Team2,
+        }],
+        Uint128::one(),
+    );
+    assert!(result.is_err());
+
+    // Only the host and the DAO can dismiss the dispute
+    let result = arena
+        .arena_tournament_module
+        .call_as(&teams[0])
+        .dismiss_dispute(Uint128::one(), Uint128::one());
+    assert!(result.is_err());
+
+    arena
+        .arena_tournament_module
+        .dismiss_dispute(Uint128::one(), Uint128::one())?;
+    assert!(arena
+        .arena_tournament_module
+        .match_dispute(Uint128::one(), Uint128::one())?
+        .is_none());
+
+    arena.arena_tournament_module.process_match(
+        vec![MatchResultMsg {
+            match_number: Uint128::one(),
+            match_result: MatchResult::Team2,
+        }],
+        Uint128::one(),
+    )?;
+    let tournament = arena.arena_tournament_module.competition(Uint128::one())?;
+    assert_eq!(tournament.status, CompetitionStatus::Inactive);
+
+    Ok(())
+}
+
+#[test]
+pub fn test_tournament_seeding() -> Result<(), CwOrchError> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+
+    let mut teams = vec![];
+    for i in 0..8 {
+        teams.push(mock.addr_make_with_balance(format!("team {}", i), coins(100_000u128, DENOM))?);
+    }
+    let seeds = teams.iter().rev().cloned().collect_vec();
+
+    arena.arena_tournament_module.set_sender(&admin);
+    let mut msg = create_competition_msg(
+        &arena,
+        None,
+        &teams,
+        EliminationType::SingleElimination {
+            play_third_place_match: false,
+        },
+        vec![Decimal::one()],
+        mock.block_info()?.time.plus_seconds(86400),
+    )?;
+
+    // Manual seeding must contain every team once
+    if let ExecuteMsg::CreateCompetition {
+        instantiate_extension,
+        ..
+    } = &mut msg
+    {
+        instantiate_extension.seeding = Some(Seeding::Manual {
+            teams: seeds[1..].iter().map(|x| x.to_string()).collect(),
+        });
+    }
+    let result = arena.arena_tournament_module.execute(&msg, None);
+    assert!(result.is_err());
+
+    if let ExecuteMsg::CreateCompetition {
+        instantiate_extension,
+        ..
+    } = &mut msg
+    {
+        instantiate_extension.seeding = Some(Seeding::Manual {
+            teams: seeds.iter().map(|x| x.to_string()).collect(),
+        });
+    }
+    arena.arena_tournament_module.execute(&msg, None)?;
+
+    // Seeds 1 and 8, 4 and 5, 2 and 7, and 3 and 6 play in the first round
+    let bracket = arena
+        .arena_tournament_module
+        .bracket(Uint128::one(), None)?;
+    let first_round = bracket
+        .iter()
+        .filter(|m| m.team_1.is_some() && m.team_2.is_some())
+        .map(|m| {
+            let seed = |team: &Option<Addr>| seeds.iter().position(|x| Some(x) == team.as_ref());
+            let (seed_1, seed_2) = (seed(&m.team_1).unwrap(), seed(&m.team_2).unwrap());
+            (seed_1.min(seed_2), seed_1.max(seed_2), m.next_match_winner)
+        })
+        .collect_vec();
+    assert_eq!(first_round.len(), 4);
+    for pair in [(0, 7), (3, 4), (1, 6), (2, 5)] {
+        assert!(first_round.iter().any(|x| (x.0, x.1) == pair));
+    }
+
+    // Seeds 1 and 2 are in different halves of the bracket
+    let semifinal = |seed: usize| {
+        first_round
+            .iter()
+            .find(|x| x.0 == seed || x.1 == seed)
+            .unwrap()
+            .2
+    };
+    assert_eq!(semifinal(0), semifinal(3));
+    assert_eq!(semifinal(1), semifinal(2));
+    assert_ne!(semifinal(0), semifinal(1));
+
+    // Random seeding waits for the arena DAO's randomness
+    if let ExecuteMsg::CreateCompetition {
+        instantiate_extension,
+        ..
+    } = &mut msg
+    {
+        instantiate_extension.seeding = Some(Seeding::Random {});
+    }
+    arena.arena_tournament_module.execute(&msg, None)?;
+
+    let bracket = arena
+        .arena_tournament_module
+        .bracket(Uint128::new(2), None)?;
+    assert!(bracket.is_empty());
+
+    let randomness =
+        HexBinary::from_hex("8990e7a9aaed2ffed73dbd7092123d6f289930540d7651336225dc172e51b2ce")?;
+
+    // Only the DAO can submit the randomness
+    let result = arena
+        .arena_tournament_module
+        .submit_randomness(randomness.clone(), Uint128::new(2));
+    assert!(result.is_err());
+
+    let dao = arena.dao_dao.dao_core.address()?;
+    arena
+        .arena_tournament_module
+        .call_as(&dao)
+        .submit_randomness(randomness.clone(), Uint128::new(2))?;
+
+    // The randomness can only be submitted once
+    let result = arena
+        .arena_tournament_module
+        .call_as(&dao)
+        .submit_randomness(randomness, Uint128::new(2));
+    assert!(result.is_err());
+
+    // Random seeding uses every team
+    let bracket = arena
+        .arena_tournament_module
+        .bracket(Uint128::new(2), None)?;
+    let seeded_teams = bracket
+        .iter()
+        .flat_map(|m| [m.team_1.clone(), m.team_2.clone()])
+        .flatten()
+        .sorted()
+        .collect_vec();
+    assert_eq!(seeded_teams, teams.iter().cloned().sorted().collect_vec());
+
+    // Rating seeding requires a category
+    if let ExecuteMsg::CreateCompetition {
+        instantiate_extension,
+        ..
+    } = &mut msg
+    {
+        instantiate_extension.seeding = Some(Seeding::Rating);
+    }
+    let result = arena.arena_tournament_module.execute(&msg, None);
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[test]
+pub fn test_single_elimination_placements() -> Result<(), CwOrchError> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+
+    let mut teams = vec![];
+    for i in 0..8 {
+        teams.push(mock.addr_make_with_balance(format!("team {}", i), coins(100_000u128, DENOM))?);
+    }
+
+    // Placement matches decide 5th through 8th, and same-round losers split their places evenly
+    let cases = [
+        (
+            PlacementMode::Full,
+            true,
+            vec![40u128, 20, 10, 10, 5, 5, 5, 5],
+            12,
+            vec![30400u128, 15200, 7600, 7600, 3800, 3800, 3800, 3800],
+        ),
+        (
+            PlacementMode::SharedTiers,
+            false,
+            vec![50u128, 20, 12, 8, 4, 3, 2, 1],
+            7,
+            vec![38000u128, 15200, 7600, 7600, 1900, 1900, 1900, 1900],
+        ),
+    ];
+
+    for (i, (placements, play_third_place_match, distribution, total_matches, payouts)) in
+        cases.into_iter().enumerate()
+    {
+        let tournament_id = Uint128::new(i as u128 + 1);
+
+        arena.arena_tournament_module.set_sender(&admin);
+        let mut msg = create_competition_msg(
+            &arena,
+            None,
+            &teams,
+            EliminationType::SingleElimination {
+                play_third_place_match,
+            },
+            distribution
+                .into_iter()
+                .map(|x| Decimal::from_ratio(x, 100u128))
+                .collect(),
+            mock.block_info()?.time.plus_seconds(86400),
+        )?;
+        if let ExecuteMsg::CreateCompetition {
+            instantiate_extension,
+            ..
+        } = &mut msg
+        {
+            instantiate_extension.placements = Some(placements);
+        }
+        let response = arena.arena_tournament_module.execute(&msg, None)?;
+        mock.next_block()?;
+
+        let escrow_addr = response.events.iter().find_map(|event| {
+            event
+                .attributes
+                .iter()
+                .find(|attr| attr.key == "escrow_addr")
+                .map(|attr| attr.value.clone())
+        });
+        arena
+            .arena_escrow
+            .set_address(&Addr::unchecked(escrow_addr.unwrap()));
+
+        for team in teams.iter() {
+            arena.arena_escrow.set_sender(team);
+            arena
+                .arena_escrow
+                .receive_native(&coins(10_000u128, DENOM))?;
+        }
+
+        let bracket = arena.arena_tournament_module.bracket(tournament_id, None)?;
+        assert_eq!(bracket.len(), total_matches);
+
+        // Process every populated match until the bracket is complete
+        arena.arena_tournament_module.set_sender(&admin);
+        loop {
+            let match_results = arena
+                .arena_tournament_module
+                .bracket(tournament_id, None)?
+                .into_iter()
+                .filter(|m| m.team_1.is_some() && m.team_2.is_some() && m.result.is_none())
+                .map(|m| MatchResultMsg {
+                    match_number: m.match_number,
+                    match_result: MatchResult::Team1,
+                })
+                .collect_vec();
+            if match_results.is_empty() {
+                break;
+            }
+
+            arena
+                .arena_tournament_module
+                .process_match(match_results, tournament_id)?;
+            mock.next_block()?;
+        }
+
+        // 80k * .95 (Arena tax) split by placement
+        let balances = arena.arena_escrow.balances(None, None)?;
+        assert_eq!(
+            balances
+                .iter()
+                .map(|x| x.balance.native.as_ref().unwrap()[0].amount.u128())
+                .sorted()
+                .rev()
+                .collect_vec(),
+            payouts
+        );
+    }
+
+    let competition = arena.arena_tournament_module.competition(Uint128::one())?;
+    assert_eq!(competition.status, CompetitionStatus::Inactive);
+
+    Ok(())
+}
+
+#[test]
+pub fn test_round_robin_groups() -> Result<(), CwOrchError> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+
+    let mut teams = vec![];
+    for i in 0..8 {
+        teams.push(mock.addr_make_with_balance(format!("team {}", i), coins(100_000u128, DENOM))?);
+    }
+
+    arena.arena_tournament_module.set_sender(&admin);
+
+    // Each group must have more teams than it advances
+    let result = arena.arena_tournament_module.execute(
+        &create_competition_msg(
+            &arena,
+            None,
+            &teams,
+            EliminationType::RoundRobinGroups {
+                group_size: Uint64::new(4),
+                advance_per_group: Uint64::new(4),
+                then: Box::new(EliminationType::SingleElimination {
+                    play_third_place_match: false,
+                }),
+            },
+            vec![Decimal::one()],
+            mock.block_info()?.time.plus_seconds(86400),
+        )?,
+        None,
+    );
+    assert!(result.is_err());
+
+    // 2 groups of 4, and the top 2 of each group play a 4 team bracket
+    let response = arena.arena_tournament_module.execute(
+        &create_competition_msg(
+            &arena,
+            None,
+            &teams,
+            EliminationType::RoundRobinGroups {
+                group_size: Uint64::new(4),
+                advance_per_group: Uint64::new(2),
+                then: Box::new(EliminationType::SingleElimination {
+                    play_third_place_match: false,
+                }),
+            },
+            vec![
+                Decimal::from_ratio(70u128, 100u128),
+                Decimal::from_ratio(30u128, 100u128),
+            ],
+            mock.block_info()?.time.plus_seconds(86400),
+        )?,
+        None,
+    )?;
+    mock.next_block()?;
+
+    let escrow_addr = response.events.iter().find_map(|event| {
+        event
+            .attributes
+            .iter()
+            .find(|attr| attr.key == "escrow_addr")
+            .map(|attr| attr.value.clone())
+    });
+    arena
+        .arena_escrow
+        .set_address(&Addr::unchecked(escrow_addr.unwrap()));
+    for team in teams.iter() {
+        arena.arena_escrow.set_sender(team);
+        arena
+            .arena_escrow
+            .receive_native(&coins(10_000u128, DENOM))?;
+    }
+
+    let tournament = arena.arena_tournament_module.competition(Uint128::one())?;
+    assert_eq!(tournament.extension.total_matches, Uint128::new(3));
+    assert_eq!(
+        tournament.extension.group_stage.unwrap().group_matches,
+        Uint128::new(12)
+    );
+
+    // Seeds are drawn into the groups with a snake draw
+    let groups = arena.arena_tournament_module.groups(Uint128::one())?;
+    assert_eq!(groups.len(), 2);
+    assert_eq!(
+        groups[0].members,
+        vec![
+            teams[0].clone(),
+            teams[3].clone(),
+            teams[4].clone(),
+            teams[7].clone()
+        ]
+    );
+    assert!(arena
+        .arena_tournament_module
+        .bracket(Uint128::one(), None)?
+        .is_empty());
+
+    // The bracket cannot be played before the group stage is complete
+    arena.arena_tournament_module.set_sender(&admin);
+    let result = arena.arena_tournament_module.process_match(
+        vec![MatchResultMsg {
+            match_number: Uint128::one(),
+            match_result: MatchResult::Team1,
+        }],
+        Uint128::one(),
+    );
+    assert!(result.is_err());
+
+    // The higher seed wins every group match, except for a draw in each group's first match
+    for group in groups.iter() {
+        let seed = |team: &Addr| group.members.iter().position(|x| x == team).unwrap();
+        let match_results = arena
+            .arena_tournament_module
+            .group_matches(group.group, Uint128::one(), None)?
+            .into_iter()
+            .map(|m| GroupMatchResultMsg {
+                match_number: m.match_number,
+                match_result: if m.match_number == Uint128::one() {
+                    GroupMatchResult::Draw
+                } else if seed(&m.team_1) < seed(&m.team_2) {
+                    GroupMatchResult::Team1
+                } else {
+                    GroupMatchResult::Team2
+                },
+            })
+            .collect_vec();
+        assert_eq!(match_results.len(), 6);
+
+        arena.arena_tournament_module.process_group_match(
+            group.group,
+            match_results,
+            Uint128::one(),
+        )?;
+        mock.next_block()?;
+    }
+
+    // The group standings follow the seeds
+    let standings = arena
+        .arena_tournament_module
+        .group_standings(Uint64::one(), Uint128::one())?;
+    assert_eq!(standings[0].member, teams[0]);
+    assert_eq!(standings[1].member, teams[3]);
+
+    // Each group's winner plays the other group's runner-up
+    let bracket = arena
+        .arena_tournament_module
+        .bracket(Uint128::one(), None)?;
+    assert_eq!(bracket.len(), 3);
+    for m in bracket.iter().filter(|m| m.next_match_winner.is_some()) {
+        let group_of = |team: &Option<Addr>| {
+            groups
+                .iter()
+                .position(|x| x.members.contains(team.as_ref().unwrap()))
+        };
+        assert_ne!(group_of(&m.team_1), group_of(&m.team_2));
+    }
+
+    // The group stage cannot be changed once the bracket is seeded
+    let result = arena.arena_tournament_module.process_group_match(
+        Uint64::one(),
+        vec![GroupMatchResultMsg {
+            match_number: Uint128::one(),
+            match_result: GroupMatchResult::Team1,
+        }],
+        Uint128::one(),
+    );
+    assert!(result.is_err());
+
+    arena.arena_tournament_module.process_match(
+        vec![
+            MatchResultMsg {
+                match_number: Uint128::one(),
+                match_result: MatchResult::Team1,
+            },
+            MatchResultMsg {
+                match_number: Uint128::new(2),
+                match_result: MatchResult::Team1,
+            },
+        ],
+        Uint128::one(),
+    )?;
+    arena.arena_tournament_module.process_match(
+        vec![MatchResultMsg {
+            match_number: Uint128::new(3),
+            match_result: MatchResult::Team1,
+        }],
+        Uint128::one(),
+    )?;
+
+    let tournament = arena.arena_tournament_module.competition(Uint128::one())?;
+    assert_eq!(tournament.status, CompetitionStatus::Inactive);
+
+    Ok(())
+}
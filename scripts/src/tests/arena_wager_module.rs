@@ -6,20 +6,26 @@ use arena_interface::competition::stats::{
     MemberStatsMsg, StatAggregationType, StatMsg, StatType, StatValue, StatValueType,
 };
 use arena_interface::core::QueryExtFns;
-use arena_interface::escrow::{ExecuteMsgFns as _, QueryMsgFns as _};
+use arena_interface::escrow::{
+    ClaimDeadline, ExecuteMsgFns as _, QueryMsgFns as _, Vesting, VestingSchedule,
+};
 use arena_interface::fees::FeeInformation;
 use arena_interface::group::{self, GroupContractInfo};
 use arena_interface::registry::ExecuteMsgFns as _;
-use arena_wager_module::msg::WagerInstantiateExt;
+use arena_wager_module::msg::{
+    ExecuteExtFns as _, QueryExtFns as _, WagerInstantiateExt, WagerStake,
+};
 use cosmwasm_std::{coins, to_json_binary, Addr, Coin, Decimal, Uint128};
 use cw_balance::{
     BalanceUnchecked, BalanceVerified, Distribution, MemberBalanceUnchecked, MemberPercentage,
+    NftAllocation,
 };
 use cw_orch::{anyhow, prelude::*};
 use dao_interface::state::ModuleInstantiateInfo;
 use dao_interface::CoreQueryMsgFns;
 
 use crate::tests::helpers::{setup_arena, setup_voting_module, teams_to_members};
+use crate::Arena;
 
 use super::{DENOM, PREFIX};
 
@@ -60,6 +66,7 @@ fn test_create_wager() -> anyhow::Result<()> {
                     },
                 ],
                 is_enrollment: false,
+                claim_deadline: None,
             })?,
             label: "Wager Escrow".to_string(),
             additional_layered_fees: None,
@@ -75,7 +82,11 @@ fn test_create_wager() -> anyhow::Result<()> {
                 label: "Arena Group".to_string(),
             },
         },
-        WagerInstantiateExt {},
+        WagerInstantiateExt {
+            stakes: None,
+            sides: None,
+            refund_grace_period: None,
+        },
         "Test Wager".to_string(),
         None,
         Some(Uint128::one()),
@@ -141,6 +152,7 @@ fn test_process_wager() -> anyhow::Result<()> {
                     },
                 ],
                 is_enrollment: false,
+                claim_deadline: None,
             })?,
             label: "Wager Escrow".to_string(),
             additional_layered_fees: None,
@@ -156,7 +168,11 @@ fn test_process_wager() -> anyhow::Result<()> {
                 label: "Arena Group".to_string(),
             },
         },
-        WagerInstantiateExt {},
+        WagerInstantiateExt {
+            stakes: None,
+            sides: None,
+            refund_grace_period: None,
+        },
         "Test Wager".to_string(),
         None,
         Some(Uint128::one()),
@@ -196,7 +212,11 @@ fn test_process_wager() -> anyhow::Result<()> {
                 percentage: Decimal::one(),
             }],
             remainder_addr: user1.to_string(),
+            fixed_amounts: vec![],
+            remainder_shares: vec![],
         }),
+        None,
+        None,
     )?;
 
     // Check the result
@@ -252,6 +272,7 @@ fn test_escrow_receive_extra() -> anyhow::Result<()> {
             msg: to_json_binary(&arena_interface::escrow::InstantiateMsg {
                 dues: vec![],
                 is_enrollment: false,
+                claim_deadline: None,
             })?,
             label: "Wager Escrow".to_string(),
             additional_layered_fees: None,
@@ -267,7 +288,11 @@ fn test_escrow_receive_extra() -> anyhow::Result<()> {
                 label: "Arena Group".to_string(),
             },
         },
-        WagerInstantiateExt {},
+        WagerInstantiateExt {
+            stakes: None,
+            sides: None,
+            refund_grace_period: None,
+        },
         "Test Wager".to_string(),
         None,
         Some(Uint128::one()),
@@ -339,6 +364,7 @@ fn test_wager_with_additional_fees() -> anyhow::Result<()> {
                     },
                 ],
                 is_enrollment: false,
+                claim_deadline: None,
             })?,
             label: "Wager Escrow".to_string(),
             additional_layered_fees: Some(vec![FeeInformation {
@@ -359,7 +385,11 @@ fn test_wager_with_additional_fees() -> anyhow::Result<()> {
                 label: "Arena Group".to_string(),
             },
         },
-        WagerInstantiateExt {},
+        WagerInstantiateExt {
+            stakes: None,
+            sides: None,
+            refund_grace_period: None,
+        },
         "Fee Wager".to_string(),
         None,
         Some(Uint128::one()),
@@ -399,7 +429,11 @@ fn test_wager_with_additional_fees() -> anyhow::Result<()> {
                 percentage: Decimal::one(),
             }],
             remainder_addr: user1.to_string(),
+            fixed_amounts: vec![],
+            remainder_shares: vec![],
         }),
+        None,
+        None,
     )?;
 
     // Withdraw
@@ -441,6 +475,8 @@ fn test_wager_with_preset_distributions() -> anyhow::Result<()> {
             },
         ],
         remainder_addr: user1.to_string(),
+        fixed_amounts: vec![],
+        remainder_shares: vec![],
     };
     arena
         .arena_payment_registry
@@ -459,6 +495,8 @@ fn test_wager_with_preset_distributions() -> anyhow::Result<()> {
             },
         ],
         remainder_addr: user2.to_string(),
+        fixed_amounts: vec![],
+        remainder_shares: vec![],
     };
     arena
         .arena_payment_registry
@@ -493,6 +531,7 @@ fn test_wager_with_preset_distributions() -> anyhow::Result<()> {
                     },
                 ],
                 is_enrollment: false,
+                claim_deadline: None,
             })?,
             label: "Wager Escrow".to_string(),
             additional_layered_fees: None,
@@ -508,7 +547,11 @@ fn test_wager_with_preset_distributions() -> anyhow::Result<()> {
                 label: "Arena Group".to_string(),
             },
         },
-        WagerInstantiateExt {},
+        WagerInstantiateExt {
+            stakes: None,
+            sides: None,
+            refund_grace_period: None,
+        },
         "Preset Distribution Wager".to_string(),
         None,
         Some(Uint128::one()),
@@ -561,7 +604,11 @@ fn test_wager_with_preset_distributions() -> anyhow::Result<()> {
                 },
             ],
             remainder_addr: user1.to_string(),
+            fixed_amounts: vec![],
+            remainder_shares: vec![],
         }),
+        None,
+        None,
     )?;
 
     // Check escrow balances
@@ -640,6 +687,7 @@ fn test_wager_with_draw() -> anyhow::Result<()> {
                     },
                 ],
                 is_enrollment: false,
+                claim_deadline: None,
             })?,
             label: "Wager Escrow".to_string(),
             additional_layered_fees: None,
@@ -655,7 +703,11 @@ fn test_wager_with_draw() -> anyhow::Result<()> {
                 label: "Arena Group".to_string(),
             },
         },
-        WagerInstantiateExt {},
+        WagerInstantiateExt {
+            stakes: None,
+            sides: None,
+            refund_grace_period: None,
+        },
         "Wager".to_string(),
         None,
         Some(Uint128::one()),
@@ -696,7 +748,7 @@ fn test_wager_with_draw() -> anyhow::Result<()> {
     // Process the wager
     arena
         .arena_wager_module
-        .process_competition(Uint128::one(), None)?;
+        .process_competition(Uint128::one(), None, None, None)?;
 
     // Check escrow balances
     let user1_balance = arena.arena_escrow.balance(user1.to_string())?;
@@ -766,6 +818,7 @@ fn test_wager_with_malicious_host() -> anyhow::Result<()> {
                     },
                 ],
                 is_enrollment: false,
+                claim_deadline: None,
             })?,
             label: "Wager Escrow".to_string(),
             additional_layered_fees: None,
@@ -781,7 +834,11 @@ fn test_wager_with_malicious_host() -> anyhow::Result<()> {
                 label: "Arena Group".to_string(),
             },
         },
-        WagerInstantiateExt {},
+        WagerInstantiateExt {
+            stakes: None,
+            sides: None,
+            refund_grace_period: None,
+        },
         "Wager".to_string(),
         None,
         Some(Uint128::one()),
@@ -829,7 +886,33 @@ fn test_wager_with_malicious_host() -> anyhow::Result<()> {
                 percentage: Decimal::one(),
             }],
             remainder_addr: admin.to_string(),
+            fixed_amounts: vec![],
+            remainder_shares: vec![],
+        }),
+        None,
+        None,
+    );
+
+    assert!(result.is_err());
+
+    // The host cannot allocate an NFT that the escrow does not hold
+    let result = arena.arena_wager_module.process_competition(
+        Uint128::one(),
+        Some(Distribution {
+            member_percentages: vec![MemberPercentage {
+                addr: user1.to_string(),
+                percentage: Decimal::one(),
+            }],
+            remainder_addr: user1.to_string(),
+            fixed_amounts: vec![],
+            remainder_shares: vec![],
         }),
+        Some(vec![NftAllocation {
+            addr: user1.to_string(),
+            collection: mock.addr_make("trophy").to_string(),
+            token_id: "1".to_string(),
+        }]),
+        None,
     );
 
     assert!(result.is_err());
@@ -859,6 +942,8 @@ fn test_wager_with_updated_distribution_after_activation() -> anyhow::Result<()>
             },
         ],
         remainder_addr: user1.to_string(),
+        fixed_amounts: vec![],
+        remainder_shares: vec![],
     };
     arena
         .arena_payment_registry
@@ -893,6 +978,7 @@ fn test_wager_with_updated_distribution_after_activation() -> anyhow::Result<()>
                     },
                 ],
                 is_enrollment: false,
+                claim_deadline: None,
             })?,
             label: "Wager Escrow".to_string(),
             additional_layered_fees: None,
@@ -908,7 +994,11 @@ fn test_wager_with_updated_distribution_after_activation() -> anyhow::Result<()>
                 label: "Arena Group".to_string(),
             },
         },
-        WagerInstantiateExt {},
+        WagerInstantiateExt {
+            stakes: None,
+            sides: None,
+            refund_grace_period: None,
+        },
         "Updated Distribution Wager".to_string(),
         None,
         Some(Uint128::one()),
@@ -959,6 +1049,8 @@ fn test_wager_with_updated_distribution_after_activation() -> anyhow::Result<()>
             },
         ],
         remainder_addr: user1.to_string(),
+        fixed_amounts: vec![],
+        remainder_shares: vec![],
     };
     arena
         .arena_payment_registry
@@ -975,7 +1067,11 @@ fn test_wager_with_updated_distribution_after_activation() -> anyhow::Result<()>
                 percentage: Decimal::percent(100),
             }],
             remainder_addr: user1.to_string(),
+            fixed_amounts: vec![],
+            remainder_shares: vec![],
         }),
+        None,
+        None,
     )?;
 
     // Check escrow balances
@@ -1057,6 +1153,7 @@ fn test_jailed_wager_resolved_by_dao() -> anyhow::Result<()> {
                     },
                 ],
                 is_enrollment: false,
+                claim_deadline: None,
             })?,
             label: "Wager Escrow".to_string(),
             additional_layered_fees: None,
@@ -1072,7 +1169,11 @@ fn test_jailed_wager_resolved_by_dao() -> anyhow::Result<()> {
                 label: "Arena Group".to_string(),
             },
         },
-        WagerInstantiateExt {},
+        WagerInstantiateExt {
+            stakes: None,
+            sides: None,
+            refund_grace_period: None,
+        },
         "Test Wager".to_string(),
         None,
         Some(Uint128::one()),
@@ -1116,6 +1217,8 @@ fn test_jailed_wager_resolved_by_dao() -> anyhow::Result<()> {
                 percentage: Decimal::one(),
             }],
             remainder_addr: user1.to_string(),
+            fixed_amounts: vec![],
+            remainder_shares: vec![],
         }),
         &[],
     );
@@ -1135,6 +1238,8 @@ fn test_jailed_wager_resolved_by_dao() -> anyhow::Result<()> {
                 percentage: Decimal::one(),
             }],
             remainder_addr: user1.to_string(),
+            fixed_amounts: vec![],
+            remainder_shares: vec![],
         }),
         &[],
     )?;
@@ -1150,6 +1255,8 @@ fn test_jailed_wager_resolved_by_dao() -> anyhow::Result<()> {
                 percentage: Decimal::one(),
             }],
             remainder_addr: user1.to_string(),
+            fixed_amounts: vec![],
+            remainder_shares: vec![],
         }),
         &[],
     )?;
@@ -1224,6 +1331,7 @@ fn test_wager_with_stats() -> anyhow::Result<()> {
                     },
                 ],
                 is_enrollment: false,
+                claim_deadline: None,
             })?,
             label: "Wager Escrow".to_string(),
             additional_layered_fees: None,
@@ -1239,7 +1347,11 @@ fn test_wager_with_stats() -> anyhow::Result<()> {
                 label: "Arena Group".to_string(),
             },
         },
-        WagerInstantiateExt {},
+        WagerInstantiateExt {
+            stakes: None,
+            sides: None,
+            refund_grace_period: None,
+        },
         "Test Wager with Stats".to_string(),
         None,
         Some(Uint128::one()),
@@ -1370,6 +1482,7 @@ fn test_wager_with_aggregate_stats() -> anyhow::Result<()> {
                     },
                 }],
                 is_enrollment: false,
+                claim_deadline: None,
             })?,
             label: "Wager Escrow".to_string(),
             additional_layered_fees: None,
@@ -1385,7 +1498,11 @@ fn test_wager_with_aggregate_stats() -> anyhow::Result<()> {
                 label: "Arena Group".to_string(),
             },
         },
-        WagerInstantiateExt {},
+        WagerInstantiateExt {
+            stakes: None,
+            sides: None,
+            refund_grace_period: None,
+        },
         "Test Wager with Aggregate Stats".to_string(),
         None,
         Some(Uint128::one()),
@@ -1482,3 +1599,1318 @@ fn test_wager_with_aggregate_stats() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_wager_settled_by_matching_claims() -> anyhow::Result<()> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+
+    let user1 = mock.addr_make_with_balance("user1", coins(10000, DENOM))?;
+    let user2 = mock.addr_make_with_balance("user2", coins(10000, DENOM))?;
+
+    arena.arena_wager_module.set_sender(&admin);
+
+    // Create a wager
+    let res = arena.arena_wager_module.create_competition(
+        mock.block_info()?.time.plus_seconds(86400),
+        "A test wager".to_string(),
+        86400,
+        EscrowContractInfo::New {
+            code_id: arena.arena_escrow.code_id()?,
+            msg: to_json_binary(&arena_interface::escrow::InstantiateMsg {
+                dues: vec![
+                    MemberBalanceUnchecked {
+                        addr: user1.to_string(),
+                        balance: BalanceUnchecked {
+                            native: Some(vec![Coin::new(1000, DENOM)]),
+                            cw20: None,
+                            cw721: None,
+                        },
+                    },
+                    MemberBalanceUnchecked {
+                        addr: user2.to_string(),
+                        balance: BalanceUnchecked {
+                            native: Some(vec![Coin::new(1000, DENOM)]),
+                            cw20: None,
+                            cw721: None,
+                        },
+                    },
+                ],
+                is_enrollment: false,
+                claim_deadline: None,
+            })?,
+            label: "Wager Escrow".to_string(),
+            additional_layered_fees: None,
+        },
+        GroupContractInfo::New {
+            info: ModuleInstantiateInfo {
+                code_id: arena.arena_group.code_id()?,
+                msg: to_json_binary(&group::InstantiateMsg {
+                    members: teams_to_members(&[user1.clone(), user2.clone()]),
+                })?,
+                admin: None,
+                funds: vec![],
+                label: "Arena Group".to_string(),
+            },
+        },
+        WagerInstantiateExt {
+            stakes: None,
+            sides: None,
+            refund_grace_period: None,
+        },
+        "Test Wager".to_string(),
+        None,
+        Some(Uint128::one()),
+        None,
+        Some(vec!["Wager Rule".to_string()]),
+        None,
+    )?;
+
+    let escrow_addr = res
+        .events
+        .iter()
+        .find_map(|event| {
+            event
+                .attributes
+                .iter()
+                .find(|attr| attr.key == "escrow_addr")
+                .map(|attr| attr.value.clone())
+        })
+        .unwrap();
+
+    arena
+        .arena_escrow
+        .set_address(&Addr::unchecked(escrow_addr));
+
+    // Fund the escrow
+    arena.arena_escrow.set_sender(&user1);
+    arena.arena_escrow.receive_native(&coins(1000, DENOM))?;
+    arena.arena_escrow.set_sender(&user2);
+    arena.arena_escrow.receive_native(&coins(1000, DENOM))?;
+
+    let claim = Distribution {
+        member_percentages: vec![MemberPercentage {
+            addr: user1.to_string(),
+            percentage: Decimal::one(),
+        }],
+        remainder_addr: user1.to_string(),
+        fixed_amounts: vec![],
+        remainder_shares: vec![],
+    };
+
+    // Error - only members can submit a claim
+    let result = arena
+        .arena_wager_module
+        .submit_claim(Uint128::one(), Some(claim.clone()), &[]);
+    assert!(result.is_err());
+
+    arena.arena_wager_module.call_as(&user1).submit_claim(
+        Uint128::one(),
+        Some(claim.clone()),
+        &[],
+    )?;
+
+    let claims = arena.arena_wager_module.claims(Uint128::one())?;
+    assert_eq!(claims.len(), 1);
+    assert_eq!(claims[0].addr, user1);
+
+    // The matching claim processes the wager without the host, even with another remainder address
+    arena.arena_wager_module.call_as(&user2).submit_claim(
+        Uint128::one(),
+        Some(Distribution {
+            remainder_addr: user2.to_string(),
+            ..claim
+        }),
+        &[],
+    )?;
+
+    let wager = arena.arena_wager_module.competition(Uint128::one())?;
+    assert_eq!(wager.status, CompetitionStatus::Inactive);
+
+    let user1_balance = arena.arena_escrow.balance(user1.to_string())?;
+    assert_eq!(
+        user1_balance.unwrap().native.unwrap()[0].amount,
+        Uint128::new(1900) // 2k * .95 (Arena tax)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_wager_jailed_by_conflicting_claims() -> anyhow::Result<()> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+    setup_voting_module(
+        &mock,
+        &arena,
+        vec![cw4::Member {
+            addr: admin.to_string(),
+            weight: 1u64,
+        }],
+    )?;
+
+    let user1 = mock.addr_make_with_balance("user1", coins(10000, DENOM))?;
+    let user2 = mock.addr_make_with_balance("user2", coins(10000, DENOM))?;
+
+    arena.arena_wager_module.set_sender(&admin);
+
+    // Create a wager
+    let res = arena.arena_wager_module.create_competition(
+        mock.block_info()?.time.plus_seconds(86400),
+        "A test wager".to_string(),
+        86400,
+        EscrowContractInfo::New {
+            code_id: arena.arena_escrow.code_id()?,
+            msg: to_json_binary(&arena_interface::escrow::InstantiateMsg {
+                dues: vec![
+                    MemberBalanceUnchecked {
+                        addr: user1.to_string(),
+                        balance: BalanceUnchecked {
+                            native: Some(vec![Coin::new(1000, DENOM)]),
+                            cw20: None,
+                            cw721: None,
+                        },
+                    },
+                    MemberBalanceUnchecked {
+                        addr: user2.to_string(),
+                        balance: BalanceUnchecked {
+                            native: Some(vec![Coin::new(1000, DENOM)]),
+                            cw20: None,
+                            cw721: None,
+                        },
+                    },
+                ],
+                is_enrollment: false,
+                claim_deadline: None,
+            })?,
+            label: "Wager Escrow".to_string(),
+            additional_layered_fees: None,
+        },
+        GroupContractInfo::New {
+            info: ModuleInstantiateInfo {
+                code_id: arena.arena_group.code_id()?,
+                msg: to_json_binary(&group::InstantiateMsg {
+                    members: teams_to_members(&[user1.clone(), user2.clone()]),
+                })?,
+                admin: None,
+                funds: vec![],
+                label: "Arena Group".to_string(),
+            },
+        },
+        WagerInstantiateExt {
+            stakes: None,
+            sides: None,
+            refund_grace_period: None,
+        },
+        "Test Wager".to_string(),
+        None,
+        Some(Uint128::one()),
+        None,
+        Some(vec!["Wager Rule".to_string()]),
+        None,
+    )?;
+
+    let escrow_addr = res
+        .events
+        .iter()
+        .find_map(|event| {
+            event
+                .attributes
+                .iter()
+                .find(|attr| attr.key == "escrow_addr")
+                .map(|attr| attr.value.clone())
+        })
+        .unwrap();
+
+    arena
+        .arena_escrow
+        .set_address(&Addr::unchecked(escrow_addr));
+
+    // Fund the escrow
+    arena.arena_escrow.set_sender(&user1);
+    arena.arena_escrow.receive_native(&coins(1000, DENOM))?;
+    arena.arena_escrow.set_sender(&user2);
+    arena.arena_escrow.receive_native(&coins(1000, DENOM))?;
+    let activation_height = mock.block_info()?.height;
+
+    arena.arena_wager_module.call_as(&user1).submit_claim(
+        Uint128::one(),
+        Some(Distribution {
+            member_percentages: vec![MemberPercentage {
+                addr: user1.to_string(),
+                percentage: Decimal::one(),
+            }],
+            remainder_addr: user1.to_string(),
+            fixed_amounts: vec![],
+            remainder_shares: vec![],
+        }),
+        &[],
+    )?;
+
+    // The conflicting claim jails the wager before expiration
+    arena.arena_wager_module.call_as(&user2).submit_claim(
+        Uint128::one(),
+        Some(Distribution {
+            member_percentages: vec![MemberPercentage {
+                addr: user2.to_string(),
+                percentage: Decimal::one(),
+            }],
+            remainder_addr: user2.to_string(),
+            fixed_amounts: vec![],
+            remainder_shares: vec![],
+        }),
+        &[],
+    )?;
+
+    let wager = arena.arena_wager_module.competition(Uint128::one())?;
+    assert_eq!(
+        wager.status,
+        CompetitionStatus::Jailed { activation_height }
+    );
+
+    // Both claims are kept, and stored as the members' evidence
+    let claims = arena.arena_wager_module.claims(Uint128::one())?;
+    assert_eq!(claims.len(), 2);
+
+    let evidence = arena
+        .arena_wager_module
+        .evidence(Uint128::one(), None, None)?;
+    assert_eq!(evidence.len(), 2);
+    assert_eq!(evidence[0].submit_user, user1);
+    assert_eq!(evidence[1].submit_user, user2);
+
+    // Error - claims cannot be submitted after the wager is jailed
+    let result = arena
+        .arena_wager_module
+        .call_as(&user1)
+        .submit_claim(Uint128::one(), None, &[]);
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+/// Creates a wager where the favorite's due is 3 times the underdog's
+fn create_staked_wager(
+    mock: &MockBech32,
+    arena: &Arena<MockBech32>,
+    favorite: &Addr,
+    underdog: &Addr,
+    favorite_stake: Uint128,
+) -> anyhow::Result<Addr> {
+    let res = arena.arena_wager_module.create_competition(
+        mock.block_info()?.time.plus_seconds(86400),
+        "A test wager with 3:1 odds".to_string(),
+        86400,
+        EscrowContractInfo::New {
+            code_id: arena.arena_escrow.code_id()?,
+            msg: to_json_binary(&arena_interface::escrow::InstantiateMsg {
+                dues: vec![
+                    MemberBalanceUnchecked {
+                        addr: favorite.to_string(),
+                        balance: BalanceUnchecked {
+                            native: Some(vec![Coin::new(3000, DENOM)]),
+                            cw20: None,
+                            cw721: None,
+                        },
+                    },
+                    MemberBalanceUnchecked {
+                        addr: underdog.to_string(),
+                        balance: BalanceUnchecked {
+                            native: Some(vec![Coin::new(1000, DENOM)]),
+                            cw20: None,
+                            cw721: None,
+                        },
+                    },
+                ],
+                is_enrollment: false,
+                claim_deadline: None,
+            })?,
+            label: "Wager Escrow".to_string(),
+            additional_layered_fees: None,
+        },
+        GroupContractInfo::New {
+            info: ModuleInstantiateInfo {
+                code_id: arena.arena_group.code_id()?,
+                msg: to_json_binary(&group::InstantiateMsg {
+                    members: teams_to_members(&[favorite.clone(), underdog.clone()]),
+                })?,
+                admin: None,
+                funds: vec![],
+                label: "Arena Group".to_string(),
+            },
+        },
+        WagerInstantiateExt {
+            stakes: Some(vec![
+                WagerStake {
+                    addr: favorite.to_string(),
+                    stake: favorite_stake,
+                },
+                WagerStake {
+                    addr: underdog.to_string(),
+                    stake: Uint128::one(),
+                },
+            ]),
+            sides: None,
+            refund_grace_period: None,
+        },
+        "Test Wager".to_string(),
+        None,
+        None,
+        None,
+        Some(vec!["Wager Rule".to_string()]),
+        None,
+    )?;
+
+    let escrow_addr = res
+        .events
+        .iter()
+        .find_map(|event| {
+            event
+                .attributes
+                .iter()
+                .find(|attr| attr.key == "escrow_addr")
+                .map(|attr| attr.value.clone())
+        })
+        .unwrap();
+
+    Ok(Addr::unchecked(escrow_addr))
+}
+
+#[test]
+fn test_wager_with_stakes() -> anyhow::Result<()> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+
+    let user1 = mock.addr_make_with_balance("user1", coins(10000, DENOM))?;
+    let user2 = mock.addr_make_with_balance("user2", coins(10000, DENOM))?;
+    let user3 = mock.addr_make("user3");
+
+    arena.arena_wager_module.set_sender(&admin);
+
+    // Error - every member needs a stake
+    let result = arena.arena_wager_module.create_competition(
+        mock.block_info()?.time.plus_seconds(86400),
+        "A test wager".to_string(),
+        86400,
+        EscrowContractInfo::New {
+            code_id: arena.arena_escrow.code_id()?,
+            msg: to_json_binary(&arena_interface::escrow::InstantiateMsg {
+                dues: vec![],
+                is_enrollment: false,
+                claim_deadline: None,
+            })?,
+            label: "Wager Escrow".to_string(),
+            additional_layered_fees: None,
+        },
+        GroupContractInfo::New {
+            info: ModuleInstantiateInfo {
+                code_id: arena.arena_group.code_id()?,
+                msg: to_json_binary(&group::InstantiateMsg {
+                    members: teams_to_members(&[user1.clone(), user2.clone(), user3.clone()]),
+                })?,
+                admin: None,
+                funds: vec![],
+                label: "Arena Group".to_string(),
+            },
+        },
+        WagerInstantiateExt {
+            stakes: Some(vec![WagerStake {
+                addr: user1.to_string(),
+                stake: Uint128::one(),
+            }]),
+            sides: None,
+            refund_grace_period: None,
+        },
+        "Test Wager".to_string(),
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    assert!(result.is_err());
+
+    // Error - the stakes must be proportional to the escrow's dues
+    let result = create_staked_wager(&mock, &arena, &user1, &user2, Uint128::new(2));
+    assert!(result.is_err());
+
+    let escrow_addr = create_staked_wager(&mock, &arena, &user1, &user2, Uint128::new(3))?;
+    arena.arena_escrow.set_address(&escrow_addr);
+    arena.arena_escrow.set_sender(&user1);
+    arena.arena_escrow.receive_native(&coins(3000, DENOM))?;
+    arena.arena_escrow.set_sender(&user2);
+    arena.arena_escrow.receive_native(&coins(1000, DENOM))?;
+
+    let wager = arena.arena_wager_module.competition(Uint128::one())?;
+    assert_eq!(wager.extension.stakes.unwrap().len(), 2);
+
+    // Error - the winner must be a member
+    let result = arena
+        .arena_wager_module
+        .process_winner(Uint128::one(), Some(user3.to_string()));
+    assert!(result.is_err());
+
+    // The underdog takes the whole pot minus fees
+    arena
+        .arena_wager_module
+        .process_winner(Uint128::one(), Some(user2.to_string()))?;
+
+    let user2_balance = arena.arena_escrow.balance(user2.to_string())?;
+    assert_eq!(
+        user2_balance.unwrap().native.unwrap()[0].amount,
+        Uint128::new(3800) // 4k * .95 (Arena tax)
+    );
+    assert!(arena.arena_escrow.balance(user1.to_string())?.is_none());
+
+    Ok(())
+}
+
+#[test]
+fn test_wager_with_stakes_draw() -> anyhow::Result<()> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+
+    let user1 = mock.addr_make_with_balance("user1", coins(10000, DENOM))?;
+    let user2 = mock.addr_make_with_balance("user2", coins(10000, DENOM))?;
+
+    arena.arena_wager_module.set_sender(&admin);
+
+    let escrow_addr = create_staked_wager(&mock, &arena, &user1, &user2, Uint128::new(3))?;
+    arena.arena_escrow.set_address(&escrow_addr);
+    arena.arena_escrow.set_sender(&user1);
+    arena.arena_escrow.receive_native(&coins(3000, DENOM))?;
+    arena.arena_escrow.set_sender(&user2);
+    arena.arena_escrow.receive_native(&coins(1000, DENOM))?;
+
+    // A draw refunds the pot in proportion to the stakes
+    arena
+        .arena_wager_module
+        .process_winner(Uint128::one(), None)?;
+
+    let user1_balance = arena.arena_escrow.balance(user1.to_string())?;
+    assert_eq!(
+        user1_balance.unwrap().native.unwrap()[0].amount,
+        Uint128::new(2850) // 4k * .95 (Arena tax) * .75 (user share)
+    );
+    let user2_balance = arena.arena_escrow.balance(user2.to_string())?;
+    assert_eq!(
+        user2_balance.unwrap().native.unwrap()[0].amount,
+        Uint128::new(950) // 4k * .95 (Arena tax) * .25 (user share)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_wager_with_sides() -> anyhow::Result<()> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+
+    let users = (1..=4)
+        .map(|i| mock.addr_make_with_balance(format!("user{}", i), coins(10000, DENOM)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    arena.arena_wager_module.set_sender(&admin);
+
+    let res = arena.arena_wager_module.create_competition(
+        mock.block_info()?.time.plus_seconds(86400),
+        "A three-way wager".to_string(),
+        86400,
+        EscrowContractInfo::New {
+            code_id: arena.arena_escrow.code_id()?,
+            msg: to_json_binary(&arena_interface::escrow::InstantiateMsg {
+                dues: users
+                    .iter()
+                    .map(|user| MemberBalanceUnchecked {
+                        addr: user.to_string(),
+                        balance: BalanceUnchecked {
+                            native: Some(vec![Coin::new(1000, DENOM)]),
+                            cw20: None,
+                            cw721: None,
+                        },
+                    })
+                    .collect(),
+                is_enrollment: false,
+                claim_deadline: None,
+            })?,
+            label: "Wager Escrow".to_string(),
+            additional_layered_fees: None,
+        },
+        GroupContractInfo::New {
+            info: ModuleInstantiateInfo {
+                code_id: arena.arena_group.code_id()?,
+                msg: to_json_binary(&group::InstantiateMsg {
+                    members: teams_to_members(&users),
+                })?,
+                admin: None,
+                funds: vec![],
+                label: "Arena Group".to_string(),
+            },
+        },
+        WagerInstantiateExt {
+            stakes: None,
+            sides: Some(vec![
+                "Home".to_string(),
+                "Draw".to_string(),
+                "Away".to_string(),
+            ]),
+            refund_grace_period: None,
+        },
+        "Test Wager".to_string(),
+        None,
+        None,
+        None,
+        None,
+        None,
+    )?;
+
+    let escrow_addr = res
+        .events
+        .iter()
+        .find_map(|event| {
+            event
+                .attributes
+                .iter()
+                .find(|attr| attr.key == "escrow_addr")
+                .map(|attr| attr.value.clone())
+        })
+        .unwrap();
+    arena
+        .arena_escrow
+        .set_address(&Addr::unchecked(escrow_addr));
+
+    // Error - the side does not exist
+    arena.arena_wager_module.set_sender(&users[0]);
+    let result = arena
+        .arena_wager_module
+        .choose_side(3, Uint128::one(), &coins(1000, DENOM));
+    assert!(result.is_err());
+
+    // Error - a side must be backed with funds
+    let result = arena.arena_wager_module.choose_side(0, Uint128::one(), &[]);
+    assert!(result.is_err());
+
+    // Users 1 and 2 back home, user 3 backs the draw, and user 4 backs away
+    for (user, side) in users.iter().zip([0, 0, 1, 2]) {
+        arena.arena_wager_module.set_sender(user);
+        arena
+            .arena_wager_module
+            .choose_side(side, Uint128::one(), &coins(1000, DENOM))?;
+
+        if side == 0 && user == &users[0] {
+            // Error - the stake cannot be moved to another side
+            let result =
+                arena
+                    .arena_wager_module
+                    .choose_side(1, Uint128::one(), &coins(500, DENOM));
+            assert!(result.is_err());
+
+            // User 1 adds to their stake
+            arena
+                .arena_wager_module
+                .choose_side(0, Uint128::one(), &coins(500, DENOM))?;
+        }
+    }
+
+    // Error - sides are locked once the wager is funded
+    arena.arena_wager_module.set_sender(&users[3]);
+    let result = arena
+        .arena_wager_module
+        .choose_side(0, Uint128::one(), &coins(1000, DENOM));
+    assert!(result.is_err());
+
+    let side_pools = arena.arena_wager_module.side_pools(Uint128::one())?;
+    assert_eq!(side_pools.len(), 3);
+    assert_eq!(side_pools[0].label, "Home");
+    assert_eq!(side_pools[0].backers.len(), 2);
+    assert_eq!(side_pools[0].total_stake, Uint128::new(2500));
+    assert_eq!(side_pools[2].backers, vec![users[3].clone()]);
+
+    // Error - only the host can process the wager
+    let result = arena
+        .arena_wager_module
+        .process_side(Uint128::one(), Some(0));
+    assert!(result.is_err());
+
+    arena.arena_wager_module.set_sender(&admin);
+    arena
+        .arena_wager_module
+        .process_side(Uint128::one(), Some(0))?;
+
+    // The home backers split the pot in proportion to their deposits
+    // 4.5k * .95 (Arena tax) * .6 and .4 (user shares)
+    for (user, amount) in users[..2].iter().zip([2565u128, 1710]) {
+        let balance = arena.arena_escrow.balance(user.to_string())?;
+        assert_eq!(
+            balance.unwrap().native.unwrap()[0].amount,
+            Uint128::new(amount)
+        );
+    }
+    for user in &users[2..] {
+        assert!(arena.arena_escrow.balance(user.to_string())?.is_none());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_expire_wager() -> anyhow::Result<()> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+
+    let user1 = mock.addr_make_with_balance("user1", coins(10000, DENOM))?;
+    let user2 = mock.addr_make_with_balance("user2", coins(10000, DENOM))?;
+    let sponsor = mock.addr_make_with_balance("sponsor", coins(10000, DENOM))?;
+    let random = mock.addr_make("random");
+
+    arena.arena_wager_module.set_sender(&admin);
+
+    let res = arena.arena_wager_module.create_competition(
+        mock.block_info()?.time.plus_seconds(86400),
+        "A test wager".to_string(),
+        86400,
+        EscrowContractInfo::New {
+            code_id: arena.arena_escrow.code_id()?,
+            msg: to_json_binary(&arena_interface::escrow::InstantiateMsg {
+                dues: vec![
+                    MemberBalanceUnchecked {
+                        addr: user1.to_string(),
+                        balance: BalanceUnchecked {
+                            native: Some(vec![Coin::new(1000, DENOM)]),
+                            cw20: None,
+                            cw721: None,
+                        },
+                    },
+                    MemberBalanceUnchecked {
+                        addr: user2.to_string(),
+                        balance: BalanceUnchecked {
+                            native: Some(vec![Coin::new(1000, DENOM)]),
+                            cw20: None,
+                            cw721: None,
+                        },
+                    },
+                ],
+                is_enrollment: false,
+                claim_deadline: None,
+            })?,
+            label: "Wager Escrow".to_string(),
+            additional_layered_fees: None,
+        },
+        GroupContractInfo::New {
+            info: ModuleInstantiateInfo {
+                code_id: arena.arena_group.code_id()?,
+                msg: to_json_binary(&group::InstantiateMsg {
+                    members: teams_to_members(&[user1.clone(), user2.clone()]),
+                })?,
+                admin: None,
+                funds: vec![],
+                label: "Arena Group".to_string(),
+            },
+        },
+        WagerInstantiateExt {
+            stakes: None,
+            sides: None,
+            refund_grace_period: Some(3600),
+        },
+        "Test Wager".to_string(),
+        None,
+        None,
+        None,
+        None,
+        None,
+    )?;
+
+    let escrow_addr = res
+        .events
+        .iter()
+        .find_map(|event| {
+            event
+                .attributes
+                .iter()
+                .find(|attr| attr.key == "escrow_addr")
+                .map(|attr| attr.value.clone())
+        })
+        .unwrap();
+    arena
+        .arena_escrow
+        .set_address(&Addr::unchecked(escrow_addr));
+
+    arena
+        .arena_escrow
+        .call_as(&sponsor)
+        .sponsor_native(&coins(500, DENOM))?;
+    arena.arena_escrow.set_sender(&user1);
+    arena.arena_escrow.receive_native(&coins(1000, DENOM))?;
+    arena.arena_escrow.set_sender(&user2);
+    arena.arena_escrow.receive_native(&coins(1000, DENOM))?;
+
+    // Error - the wager has not expired
+    arena.arena_wager_module.set_sender(&random);
+    let result = arena.arena_wager_module.expire(Uint128::one());
+    assert!(result.is_err());
+
+    // Error - the grace period has not passed
+    mock.wait_seconds(86400 * 2 + 1)?;
+    let result = arena.arena_wager_module.expire(Uint128::one());
+    assert!(result.is_err());
+
+    // Anyone can refund the wager after the grace period
+    mock.wait_seconds(3600)?;
+    arena.arena_wager_module.expire(Uint128::one())?;
+
+    let wager = arena.arena_wager_module.competition(Uint128::one())?;
+    assert_eq!(wager.status, CompetitionStatus::Refunded);
+
+    // The sponsor reclaims the sponsorship once the escrow is unlocked
+    arena
+        .arena_escrow
+        .call_as(&sponsor)
+        .sponsor_withdraw(None, None)?;
+    assert_eq!(mock.query_balance(&sponsor, DENOM)?, Uint128::new(10000));
+    assert!(arena.arena_escrow.total_sponsorship()?.is_none());
+
+    // Error - the wager can no longer be processed
+    arena.arena_wager_module.set_sender(&admin);
+    let result = arena
+        .arena_wager_module
+        .process_winner(Uint128::one(), Some(user1.to_string()));
+    assert!(result.is_err());
+
+    // Members withdraw their full balances
+    arena.arena_escrow.set_sender(&user1);
+    arena.arena_escrow.withdraw(None, None)?;
+    arena.arena_escrow.set_sender(&user2);
+    arena.arena_escrow.withdraw(None, None)?;
+
+    assert_eq!(mock.query_balance(&user1, DENOM)?, Uint128::new(10000));
+    assert_eq!(mock.query_balance(&user2, DENOM)?, Uint128::new(10000));
+
+    Ok(())
+}
+
+#[test]
+fn test_vested_distribution() -> anyhow::Result<()> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+
+    let user1 = mock.addr_make_with_balance("user1", coins(10000, DENOM))?;
+    let user2 = mock.addr_make_with_balance("user2", coins(10000, DENOM))?;
+
+    arena.arena_wager_module.set_sender(&admin);
+
+    // Create a wager
+    let res = arena.arena_wager_module.create_competition(
+        mock.block_info()?.time.plus_seconds(86400),
+        "A test wager".to_string(),
+        86400,
+        EscrowContractInfo::New {
+            code_id: arena.arena_escrow.code_id()?,
+            msg: to_json_binary(&arena_interface::escrow::InstantiateMsg {
+                dues: vec![
+                    MemberBalanceUnchecked {
+                        addr: user1.to_string(),
+                        balance: BalanceUnchecked {
+                            native: Some(vec![Coin::new(1000, DENOM)]),
+                            cw20: None,
+                            cw721: None,
+                        },
+                    },
+                    MemberBalanceUnchecked {
+                        addr: user2.to_string(),
+                        balance: BalanceUnchecked {
+                            native: Some(vec![Coin::new(1000, DENOM)]),
+                            cw20: None,
+                            cw721: None,
+                        },
+                    },
+                ],
+                is_enrollment: false,
+                claim_deadline: None,
+            })?,
+            label: "Wager Escrow".to_string(),
+            additional_layered_fees: None,
+        },
+        GroupContractInfo::New {
+            info: ModuleInstantiateInfo {
+                code_id: arena.arena_group.code_id()?,
+                msg: to_json_binary(&group::InstantiateMsg {
+                    members: teams_to_members(&[user1.clone(), user2.clone()]),
+                })?,
+                admin: None,
+                funds: vec![],
+                label: "Arena Group".to_string(),
+            },
+        },
+        WagerInstantiateExt {
+            stakes: None,
+            sides: None,
+            refund_grace_period: None,
+        },
+        "Test Wager".to_string(),
+        None,
+        Some(Uint128::one()),
+        None,
+        Some(vec!["Wager Rule".to_string()]),
+        None,
+    )?;
+
+    let escrow_addr = res
+        .events
+        .iter()
+        .find_map(|event| {
+            event
+                .attributes
+                .iter()
+                .find(|attr| attr.key == "escrow_addr")
+                .map(|attr| attr.value.clone())
+        })
+        .unwrap();
+
+    arena
+        .arena_escrow
+        .set_address(&Addr::unchecked(escrow_addr));
+
+    // Fund the escrow
+    arena.arena_escrow.set_sender(&user1);
+    arena.arena_escrow.receive_native(&coins(1000, DENOM))?;
+    arena.arena_escrow.set_sender(&user2);
+    arena.arena_escrow.receive_native(&coins(1000, DENOM))?;
+
+    // Error - the vesting schedule must be valid
+    let result = arena.arena_wager_module.process_competition(
+        Uint128::one(),
+        None,
+        None,
+        Some(Vesting::Global(VestingSchedule {
+            cliff: 100,
+            duration: 0,
+        })),
+    );
+    assert!(result.is_err());
+
+    // The host processes the wager for user1, vesting the prize over 1000 seconds
+    arena.arena_wager_module.process_competition(
+        Uint128::one(),
+        Some(Distribution {
+            member_percentages: vec![MemberPercentage {
+                addr: user1.to_string(),
+                percentage: Decimal::one(),
+            }],
+            remainder_addr: user1.to_string(),
+            fixed_amounts: vec![],
+            remainder_shares: vec![],
+        }),
+        None,
+        Some(Vesting::Global(VestingSchedule {
+            cliff: 100,
+            duration: 1000,
+        })),
+    )?;
+
+    // Nothing is claimable before the cliff
+    let claimable = arena.arena_escrow.claimable(user1.to_string())?;
+    assert!(claimable.claimable.is_empty());
+    assert!(claimable.vested.is_empty());
+    assert_eq!(
+        claimable.unvested,
+        BalanceVerified {
+            native: Some(coins(1900, DENOM)), // 2k * .95 (Arena tax)
+            cw20: None,
+            cw721: None,
+        }
+    );
+
+    // Half of the prize is released halfway through the schedule
+    mock.wait_seconds(500)?;
+    let claimable = arena.arena_escrow.claimable(user1.to_string())?;
+    assert_eq!(
+        claimable.claimable,
+        BalanceVerified {
+            native: Some(coins(950, DENOM)),
+            cw20: None,
+            cw721: None,
+        }
+    );
+
+    arena.arena_escrow.call_as(&user1).withdraw(None, None)?;
+    assert_eq!(mock.query_balance(&user1, DENOM)?, Uint128::new(9950));
+
+    // The withdrawn part still counts as vested
+    let claimable = arena.arena_escrow.claimable(user1.to_string())?;
+    assert!(claimable.claimable.is_empty());
+    assert_eq!(
+        claimable.vested,
+        BalanceVerified {
+            native: Some(coins(950, DENOM)),
+            cw20: None,
+            cw721: None,
+        }
+    );
+
+    // The rest is released once the schedule ends
+    mock.wait_seconds(500)?;
+    arena.arena_escrow.call_as(&user1).withdraw(None, None)?;
+    assert_eq!(mock.query_balance(&user1, DENOM)?, Uint128::new(10900));
+    assert_eq!(mock.query_balance(&user2, DENOM)?, Uint128::new(9000));
+
+    let claimable = arena.arena_escrow.claimable(user1.to_string())?;
+    assert!(claimable.claimable.is_empty());
+    assert!(claimable.unvested.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn test_sponsored_wager() -> anyhow::Result<()> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+    setup_voting_module(
+        &mock,
+        &arena,
+        vec![cw4::Member {
+            addr: admin.to_string(),
+            weight: 1u64,
+        }],
+    )?;
+
+    let user1 = mock.addr_make_with_balance("user1", coins(10000, DENOM))?;
+    let user2 = mock.addr_make_with_balance("user2", coins(10000, DENOM))?;
+    let sponsor = mock.addr_make_with_balance("sponsor", coins(10000, DENOM))?;
+
+    arena.arena_wager_module.set_sender(&admin);
+
+    // Create a wager
+    let res = arena.arena_wager_module.create_competition(
+        mock.block_info()?.time.plus_seconds(86400),
+        "A test wager".to_string(),
+        86400,
+        EscrowContractInfo::New {
+            code_id: arena.arena_escrow.code_id()?,
+            msg: to_json_binary(&arena_interface::escrow::InstantiateMsg {
+                dues: vec![
+                    MemberBalanceUnchecked {
+                        addr: user1.to_string(),
+                        balance: BalanceUnchecked {
+                            native: Some(vec![Coin::new(1000, DENOM)]),
+                            cw20: None,
+                            cw721: None,
+                        },
+                    },
+                    MemberBalanceUnchecked {
+                        addr: user2.to_string(),
+                        balance: BalanceUnchecked {
+                            native: Some(vec![Coin::new(1000, DENOM)]),
+                            cw20: None,
+                            cw721: None,
+                        },
+                    },
+                ],
+                is_enrollment: false,
+                claim_deadline: None,
+            })?,
+            label: "Wager Escrow".to_string(),
+            additional_layered_fees: None,
+        },
+        GroupContractInfo::New {
+            info: ModuleInstantiateInfo {
+                code_id: arena.arena_group.code_id()?,
+                msg: to_json_binary(&group::InstantiateMsg {
+                    members: teams_to_members(&[user1.clone(), user2.clone()]),
+                })?,
+                admin: None,
+                funds: vec![],
+                label: "Arena Group".to_string(),
+            },
+        },
+        WagerInstantiateExt {
+            stakes: None,
+            sides: None,
+            refund_grace_period: None,
+        },
+        "Test Wager".to_string(),
+        None,
+        Some(Uint128::one()),
+        None,
+        Some(vec!["Wager Rule".to_string()]),
+        None,
+    )?;
+
+    let escrow_addr = res
+        .events
+        .iter()
+        .find_map(|event| {
+            event
+                .attributes
+                .iter()
+                .find(|attr| attr.key == "escrow_addr")
+                .map(|attr| attr.value.clone())
+        })
+        .unwrap();
+
+    arena
+        .arena_escrow
+        .set_address(&Addr::unchecked(escrow_addr));
+
+    // Sponsor the wager and fund user1's dues
+    arena
+        .arena_escrow
+        .call_as(&sponsor)
+        .sponsor_native(&coins(500, DENOM))?;
+    arena
+        .arena_escrow
+        .call_as(&user1)
+        .receive_native(&coins(1000, DENOM))?;
+
+    // The sponsorship is tracked separately from the total balance
+    assert_eq!(
+        arena.arena_escrow.total_balance()?,
+        Some(BalanceVerified {
+            native: Some(coins(1000, DENOM)),
+            cw20: None,
+            cw721: None,
+        })
+    );
+    assert_eq!(
+        arena.arena_escrow.sponsorship(sponsor.to_string())?,
+        Some(BalanceVerified {
+            native: Some(coins(500, DENOM)),
+            cw20: None,
+            cw721: None,
+        })
+    );
+
+    // Members only withdraw their own balance, and the sponsor can reclaim theirs
+    arena.arena_escrow.call_as(&user1).withdraw(None, None)?;
+    assert_eq!(mock.query_balance(&user1, DENOM)?, Uint128::new(10000));
+
+    arena
+        .arena_escrow
+        .call_as(&sponsor)
+        .sponsor_withdraw(None, None)?;
+    assert_eq!(mock.query_balance(&sponsor, DENOM)?, Uint128::new(10000));
+    assert!(arena.arena_escrow.total_sponsorship()?.is_none());
+
+    // Sponsor again and fully fund the escrow
+    arena
+        .arena_escrow
+        .call_as(&sponsor)
+        .sponsor_native(&coins(500, DENOM))?;
+    arena
+        .arena_escrow
+        .call_as(&user1)
+        .receive_native(&coins(1000, DENOM))?;
+    arena
+        .arena_escrow
+        .call_as(&user2)
+        .receive_native(&coins(1000, DENOM))?;
+
+    // Sponsorships cannot be withdrawn while the escrow is locked
+    let result = arena
+        .arena_escrow
+        .call_as(&sponsor)
+        .sponsor_withdraw(None, None);
+    assert!(result.is_err());
+
+    // Process the wager
+    arena.arena_wager_module.process_competition(
+        Uint128::one(),
+        Some(Distribution {
+            member_percentages: vec![MemberPercentage {
+                addr: user1.to_string(),
+                percentage: Decimal::one(),
+            }],
+            remainder_addr: user1.to_string(),
+            fixed_amounts: vec![],
+            remainder_shares: vec![],
+        }),
+        None,
+        None,
+    )?;
+
+    // The sponsorship was added to the prize pool
+    assert!(arena
+        .arena_escrow
+        .sponsorship(sponsor.to_string())?
+        .is_none());
+    arena.arena_escrow.call_as(&user1).withdraw(None, None)?;
+    assert_eq!(mock.query_balance(&user1, DENOM)?, Uint128::new(11375)); // Initial 10000 - 1000 stake + 2375 winnings (after 5% tax)
+    assert_eq!(mock.query_balance(&user2, DENOM)?, Uint128::new(9000));
+    assert_eq!(mock.query_balance(&sponsor, DENOM)?, Uint128::new(9500));
+
+    Ok(())
+}
+
+#[test]
+fn test_sweep_unclaimed() -> anyhow::Result<()> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+    setup_voting_module(
+        &mock,
+        &arena,
+        vec![cw4::Member {
+            addr: admin.to_string(),
+            weight: 1u64,
+        }],
+    )?;
+
+    let user1 = mock.addr_make_with_balance("user1", coins(10000, DENOM))?;
+    let user2 = mock.addr_make_with_balance("user2", coins(10000, DENOM))?;
+
+    arena.arena_wager_module.set_sender(&admin);
+
+    // Error - the claim deadline must have a duration
+    let result = arena.arena_escrow.instantiate(
+        &arena_interface::escrow::InstantiateMsg {
+            dues: vec![],
+            is_enrollment: false,
+            claim_deadline: Some(ClaimDeadline {
+                duration: 0,
+                receiver: None,
+            }),
+        },
+        None,
+        None,
+    );
+    assert!(result.is_err());
+
+    // Create a wager
+    let res = arena.arena_wager_module.create_competition(
+        mock.block_info()?.time.plus_seconds(86400),
+        "A test wager".to_string(),
+        86400,
+        EscrowContractInfo::New {
+            code_id: arena.arena_escrow.code_id()?,
+            msg: to_json_binary(&arena_interface::escrow::InstantiateMsg {
+                dues: vec![
+                    MemberBalanceUnchecked {
+                        addr: user1.to_string(),
+                        balance: BalanceUnchecked {
+                            native: Some(vec![Coin::new(1000, DENOM)]),
+                            cw20: None,
+                            cw721: None,
+                        },
+                    },
+                    MemberBalanceUnchecked {
+                        addr: user2.to_string(),
+                        balance: BalanceUnchecked {
+                            native: Some(vec![Coin::new(1000, DENOM)]),
+                            cw20: None,
+                            cw721: None,
+                        },
+                    },
+                ],
+                is_enrollment: false,
+                claim_deadline: Some(ClaimDeadline {
+                    duration: 1000,
+                    receiver: None,
+                }),
+            })?,
+            label: "Wager Escrow".to_string(),
+            additional_layered_fees: None,
+        },
+        GroupContractInfo::New {
+            info: ModuleInstantiateInfo {
+                code_id: arena.arena_group.code_id()?,
+                msg: to_json_binary(&group::InstantiateMsg {
+                    members: teams_to_members(&[user1.clone(), user2.clone()]),
+                })?,
+                admin: None,
+                funds: vec![],
+                label: "Arena Group".to_string(),
+            },
+        },
+        WagerInstantiateExt {
+            stakes: None,
+            sides: None,
+            refund_grace_period: None,
+        },
+        "Test Wager".to_string(),
+        None,
+        Some(Uint128::one()),
+        None,
+        Some(vec!["Wager Rule".to_string()]),
+        None,
+    )?;
+
+    let escrow_addr = res
+        .events
+        .iter()
+        .find_map(|event| {
+            event
+                .attributes
+                .iter()
+                .find(|attr| attr.key == "escrow_addr")
+                .map(|attr| attr.value.clone())
+        })
+        .unwrap();
+
+    arena
+        .arena_escrow
+        .set_address(&Addr::unchecked(escrow_addr));
+
+    // Fund the escrow
+    arena.arena_escrow.set_sender(&user1);
+    arena.arena_escrow.receive_native(&coins(1000, DENOM))?;
+    arena.arena_escrow.set_sender(&user2);
+    arena.arena_escrow.receive_native(&coins(1000, DENOM))?;
+
+    // Process the wager, vesting the prize over 500 seconds
+    arena.arena_wager_module.process_competition(
+        Uint128::one(),
+        Some(Distribution {
+            member_percentages: vec![MemberPercentage {
+                addr: user1.to_string(),
+                percentage: Decimal::one(),
+            }],
+            remainder_addr: user1.to_string(),
+            fixed_amounts: vec![],
+            remainder_shares: vec![],
+        }),
+        None,
+        Some(Vesting::Global(VestingSchedule {
+            cliff: 0,
+            duration: 500,
+        })),
+    )?;
+
+    // The claim deadline starts once the vesting schedule ends
+    assert_eq!(
+        arena.arena_escrow.claim_expiration()?,
+        Some(mock.block_info()?.time.plus_seconds(1500))
+    );
+
+    // Nothing can be swept before the claim deadline
+    mock.wait_seconds(1000)?;
+    let result = arena.arena_escrow.call_as(&user2).sweep_unclaimed();
+    assert!(result.is_err());
+
+    // Winners cannot withdraw after the claim deadline
+    mock.wait_seconds(500)?;
+    let result = arena.arena_escrow.call_as(&user1).withdraw(None, None);
+    assert!(result.is_err());
+
+    // Anyone can sweep the unclaimed balances to the arena DAO
+    let dao_balance = mock.query_balance(&arena.dao_dao.dao_core.address()?, DENOM)?;
+    arena.arena_escrow.call_as(&user2).sweep_unclaimed()?;
+
+    assert_eq!(
+        mock.query_balance(&arena.dao_dao.dao_core.address()?, DENOM)?,
+        dao_balance + Uint128::new(1900)
+    );
+    assert!(arena.arena_escrow.total_balance()?.is_none());
+
+    // The swept amounts are recorded
+    let swept = arena.arena_escrow.swept(None, None)?;
+    assert_eq!(swept.len(), 1);
+    assert_eq!(swept[0].addr, user1);
+    assert_eq!(
+        swept[0].balance,
+        BalanceVerified {
+            native: Some(coins(1900, DENOM)),
+            cw20: None,
+            cw721: None,
+        }
+    );
+
+    Ok(())
+}
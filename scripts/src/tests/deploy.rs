@@ -121,6 +121,62 @@ impl<Chain: CwEnv> cw_orch::contract::Deploy<Chain> for Arena<Chain> {
                                             label: "League Module".to_string(),
                                             funds: vec![],
                                         },
+                                        dao_interface::state::ModuleInstantiateInfo {
+                                            code_id: arena.arena_swiss_module.code_id()?,
+                                            msg: to_json_binary(
+                                                &arena_swiss_module::msg::InstantiateMsg {
+                                                    key: "Swiss".to_string(),
+                                                    description: "Swiss-system competitions"
+                                                        .to_string(),
+                                                    extension: Empty {},
+                                                },
+                                            )?,
+                                            admin: Some(dao_interface::state::Admin::CoreModule {}),
+                                            label: "Swiss Module".to_string(),
+                                            funds: vec![],
+                                        },
+                                        dao_interface::state::ModuleInstantiateInfo {
+                                            code_id: arena.arena_hybrid_module.code_id()?,
+                                            msg: to_json_binary(
+                                                &arena_hybrid_module::msg::InstantiateMsg {
+                                                    key: "Hybrid".to_string(),
+                                                    description:
+                                                        "Group stages into elimination playoffs"
+                                                            .to_string(),
+                                                    extension: Empty {},
+                                                },
+                                            )?,
+                                            admin: Some(dao_interface::state::Admin::CoreModule {}),
+                                            label: "Hybrid Module".to_string(),
+                                            funds: vec![],
+                                        },
+                                        dao_interface::state::ModuleInstantiateInfo {
+                                            code_id: arena.arena_ffa_module.code_id()?,
+                                            msg: to_json_binary(
+                                                &arena_ffa_module::msg::InstantiateMsg {
+                                                    key: "FFA".to_string(),
+                                                    description: "Free-for-all placement matches"
+                                                        .to_string(),
+                                                    extension: Empty {},
+                                                },
+                                            )?,
+                                            admin: Some(dao_interface::state::Admin::CoreModule {}),
+                                            label: "FFA Module".to_string(),
+                                            funds: vec![],
+                                        },
+                                        dao_interface::state::ModuleInstantiateInfo {
+                                            code_id: arena.arena_ladder_module.code_id()?,
+                                            msg: to_json_binary(
+                                                &arena_ladder_module::msg::InstantiateMsg {
+                                                    key: "Ladders".to_string(),
+                                                    description: "Challenge ladders".to_string(),
+                                                    extension: Empty {},
+                                                },
+                                            )?,
+                                            admin: Some(dao_interface::state::Admin::CoreModule {}),
+                                            label: "Ladder Module".to_string(),
+                                            funds: vec![],
+                                        },
                                     ]),
                                     rulesets: None,
                                     categories: Some(vec![
@@ -230,6 +286,18 @@ impl<Chain: CwEnv> cw_orch::contract::Deploy<Chain> for Arena<Chain> {
         arena
             .arena_league_module
             .set_address(competition_module_map.get("Leagues").unwrap());
+        arena
+            .arena_swiss_module
+            .set_address(competition_module_map.get("Swiss").unwrap());
+        arena
+            .arena_hybrid_module
+            .set_address(competition_module_map.get("Hybrid").unwrap());
+        arena
+            .arena_ffa_module
+            .set_address(competition_module_map.get("FFA").unwrap());
+        arena
+            .arena_ladder_module
+            .set_address(competition_module_map.get("Ladders").unwrap());
 
         // Instantiate the enrollment module
         arena.arena_competition_enrollment.instantiate(
@@ -271,6 +339,10 @@ impl<Chain: CwEnv> cw_orch::contract::Deploy<Chain> for Arena<Chain> {
             Box::new(&mut self.arena_tournament_module),
             Box::new(&mut self.arena_wager_module),
             Box::new(&mut self.arena_league_module),
+            Box::new(&mut self.arena_swiss_module),
+            Box::new(&mut self.arena_hybrid_module),
+            Box::new(&mut self.arena_ffa_module),
+            Box::new(&mut self.arena_ladder_module),
             Box::new(&mut self.arena_escrow),
             Box::new(&mut self.arena_competition_enrollment),
             Box::new(&mut self.arena_token_gateway),
@@ -1,9 +1,16 @@
 use std::collections::HashMap;
 
+use crate::tests::DENOM;
 use crate::Arena;
-use arena_interface::group::AddMemberMsg;
+use arena_ffa_module::msg::FfaInstantiateExt;
+use arena_hybrid_module::msg::HybridInstantiateExt;
+use arena_interface::competition::msg::{EscrowContractInfo, ExecuteBaseFns as _};
+use arena_interface::group::{self, AddMemberMsg, GroupContractInfo};
+use arena_ladder_module::msg::LadderInstantiateExt;
+use arena_swiss_module::msg::SwissInstantiateExt;
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::to_json_binary;
+use cosmwasm_std::{to_json_binary, Coin, Event, Uint128};
+use cw_balance::{BalanceUnchecked, MemberBalanceUnchecked};
 use cw_orch::{anyhow, prelude::*};
 use dao_interface::{
     state::{Admin, ModuleInstantiateInfo},
@@ -106,6 +113,159 @@ pub fn teams_to_members(teams: &[Addr]) -> Option<Vec<AddMemberMsg>> {
     )
 }
 
+pub fn create_ffa(
+    mock: &MockBech32,
+    arena: &Arena<MockBech32>,
+    teams: &[Addr],
+    extension: FfaInstantiateExt,
+) -> anyhow::Result<Addr> {
+    let res = arena.arena_ffa_module.create_competition(
+        mock.block_info()?.time.plus_seconds(86400),
+        "A test free-for-all competition".to_string(),
+        86400,
+        escrow_info(arena, teams, "FFA Escrow")?,
+        group_info(arena, teams)?,
+        extension,
+        "Test FFA".to_string(),
+        None,
+        None,
+        None,
+        Some(vec!["FFA Rule".to_string()]),
+        None,
+    )?;
+
+    escrow_addr(&res.events)
+}
+
+pub fn create_hybrid(
+    mock: &MockBech32,
+    arena: &Arena<MockBech32>,
+    teams: &[Addr],
+    extension: HybridInstantiateExt,
+) -> anyhow::Result<Addr> {
+    let res = arena.arena_hybrid_module.create_competition(
+        mock.block_info()?.time.plus_seconds(86400),
+        "A test hybrid competition".to_string(),
+        86400,
+        escrow_info(arena, teams, "Hybrid Escrow")?,
+        group_info(arena, teams)?,
+        extension,
+        "Test Hybrid".to_string(),
+        None,
+        None,
+        None,
+        Some(vec!["Hybrid Rule".to_string()]),
+        None,
+    )?;
+
+    escrow_addr(&res.events)
+}
+
+pub fn create_ladder(
+    mock: &MockBech32,
+    arena: &Arena<MockBech32>,
+    teams: &[Addr],
+    extension: LadderInstantiateExt,
+) -> anyhow::Result<Addr> {
+    let res = arena.arena_ladder_module.create_competition(
+        mock.block_info()?.time.plus_seconds(86400),
+        "A test challenge ladder".to_string(),
+        86400,
+        escrow_info(arena, teams, "Ladder Escrow")?,
+        group_info(arena, teams)?,
+        extension,
+        "Test Ladder".to_string(),
+        None,
+        None,
+        None,
+        Some(vec!["Ladder Rule".to_string()]),
+        None,
+    )?;
+
+    escrow_addr(&res.events)
+}
+
+pub fn create_swiss(
+    mock: &MockBech32,
+    arena: &Arena<MockBech32>,
+    teams: &[Addr],
+    extension: SwissInstantiateExt,
+) -> anyhow::Result<Addr> {
+    let res = arena.arena_swiss_module.create_competition(
+        mock.block_info()?.time.plus_seconds(86400),
+        "A test Swiss competition".to_string(),
+        86400,
+        escrow_info(arena, teams, "Swiss Escrow")?,
+        group_info(arena, teams)?,
+        extension,
+        "Test Swiss".to_string(),
+        None,
+        Some(Uint128::one()),
+        None,
+        Some(vec!["Swiss Rule".to_string()]),
+        None,
+    )?;
+
+    escrow_addr(&res.events)
+}
+
+/// A new escrow where each team owes 1000 of the test denom
+fn escrow_info(
+    arena: &Arena<MockBech32>,
+    teams: &[Addr],
+    label: &str,
+) -> anyhow::Result<EscrowContractInfo> {
+    Ok(EscrowContractInfo::New {
+        code_id: arena.arena_escrow.code_id()?,
+        msg: to_json_binary(&arena_interface::escrow::InstantiateMsg {
+            dues: teams
+                .iter()
+                .map(|team| MemberBalanceUnchecked {
+                    addr: team.to_string(),
+                    balance: BalanceUnchecked {
+                        native: Some(vec![Coin::new(1000, DENOM)]),
+                        cw20: None,
+                        cw721: None,
+                    },
+                })
+                .collect(),
+            is_enrollment: false,
+            claim_deadline: None,
+        })?,
+        label: label.to_string(),
+        additional_layered_fees: None,
+    })
+}
+
+fn group_info(arena: &Arena<MockBech32>, teams: &[Addr]) -> anyhow::Result<GroupContractInfo> {
+    Ok(GroupContractInfo::New {
+        info: ModuleInstantiateInfo {
+            code_id: arena.arena_group.code_id()?,
+            msg: to_json_binary(&group::InstantiateMsg {
+                members: teams_to_members(teams),
+            })?,
+            admin: None,
+            funds: vec![],
+            label: "Arena Group".to_string(),
+        },
+    })
+}
+
+fn escrow_addr(events: &[Event]) -> anyhow::Result<Addr> {
+    let escrow_addr = events
+        .iter()
+        .find_map(|event| {
+            event
+                .attributes
+                .iter()
+                .find(|attr| attr.key == "escrow_addr")
+                .map(|attr| attr.value.clone())
+        })
+        .ok_or_else(|| anyhow::anyhow!("Escrow address not found in events"))?;
+
+    Ok(Addr::unchecked(escrow_addr))
+}
+
 #[cw_serde]
 pub struct PayrollFactory {
     pub address: String,
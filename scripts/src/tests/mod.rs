@@ -1,7 +1,11 @@
 pub mod arena_competition_enrollment;
 pub mod arena_core;
+pub mod arena_ffa_module;
+pub mod arena_hybrid_module;
+pub mod arena_ladder_module;
 pub mod arena_league_module;
 pub mod arena_payment_registry;
+pub mod arena_swiss_module;
 #[cfg(feature = "abc")]
 pub mod arena_token_gateway;
 pub mod arena_tournament_module;